@@ -1,12 +1,18 @@
 use crate::{
     error::SytemapRegistryError,
     events::SytemapRegistryEvents,
-    types::{PropertyInfo, PropertyInfoPayload, TokenMetadata},
+    types::{
+        DisputeRecord, DocumentType, EstateSummary, IndexInconsistency, IndexIssue,
+        IntegrityReport, KycStatus, Lease, Lien, Lineage, LineageLink, MintProposal, PaymentPlan,
+        PaymentSchedule, PaymentState, PaymentStatus, PropertyDocument, PropertyInfo,
+        PropertyInfoPayload, PropertyPage, PropertyStatus, Role, TokenMetadata,
+    },
     util::*,
 };
 
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, log, panic_with_error, Address, Env, String, Vec,
+    contract, contractimpl, contractmeta, log, panic_with_error, token, Address, BytesN, Env,
+    String, Vec,
 };
 
 contractmeta!(key = "stye", val = "Sytemap Coin for land tokenization");
@@ -17,9 +23,10 @@ pub struct SytemapRegistry;
 
 #[contractimpl]
 impl SytemapRegistry {
-    /// Initialize the contract with token metadata.
+    /// Initialize the contract with its admin and token metadata.
     pub fn initialize(
         env: Env,
+        admin: Address,
         sytemap_name: String,
         sytemap_symbol: String,
         metadata_uri: String,
@@ -33,107 +40,1330 @@ impl SytemapRegistry {
         save_token_metadata(
             &env,
             TokenMetadata {
-                sytemap_name,
-                sytemap_symbol,
-                metadata_uri,
+                sytemap_name: sytemap_name.clone(),
+                sytemap_symbol: sytemap_symbol.clone(),
+                metadata_uri: metadata_uri.clone(),
             },
         );
+        save_admin(&env, &admin);
+
+        SytemapRegistryEvents::initialized(&env, admin, sytemap_name, sytemap_symbol, metadata_uri);
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, SytemapRegistryError> {
+        get_admin(&env)
+    }
+
+    /// Point the registry at a new metadata URI.
+    pub fn update_metadata_uri(env: Env, metadata_uri: String) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_admin(&env)?;
+
+        let mut token_metadata = get_token_metadata(&env)?;
+        let old_metadata_uri = token_metadata.metadata_uri;
+        token_metadata.metadata_uri = metadata_uri.clone();
+        save_token_metadata(&env, token_metadata);
+
+        SytemapRegistryEvents::metadata_uri_updated(&env, old_metadata_uri, metadata_uri);
+
+        Ok(())
+    }
+
+    /// Grant a role to an account. Only the admin may call this, even while paused.
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), SytemapRegistryError> {
+        require_admin(&env)?;
+
+        set_role(&env, role, &account, true);
+        SytemapRegistryEvents::role_granted(&env, role, account);
+
+        Ok(())
+    }
+
+    /// Revoke a role from an account. Only the admin may call this, even while paused.
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), SytemapRegistryError> {
+        require_admin(&env)?;
+
+        set_role(&env, role, &account, false);
+        SytemapRegistryEvents::role_revoked(&env, role, account);
+
+        Ok(())
+    }
+
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        has_role(&env, role, &account)
+    }
+
+    /// Bind the account that acts for the estate company named `estate_company_name` on
+    /// its plots, replacing any earlier one. Only the admin may call this, even while
+    /// paused.
+    pub fn set_estate_company_account(
+        env: Env,
+        estate_company_name: String,
+        account: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        require_admin(&env)?;
+
+        save_estate_company_account(&env, &estate_company_name, &account);
+        SytemapRegistryEvents::estate_company_bound(&env, estate_company_name, account);
+
+        Ok(())
+    }
+
+    pub fn get_estate_company_account(env: Env, estate_company_name: String) -> Option<Address> {
+        get_estate_company_account(&env, &estate_company_name)
+    }
+
+    /// Mint a property directly with the admin key. Once a mint approval threshold is
+    /// configured, titles can only be created through `propose_mint`.
+    pub fn safe_mint_new_property_info(
+        env: Env,
+        payload: PropertyInfoPayload,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_admin(&env)?;
+        if get_mint_approval_threshold(&env) > 0 {
+            panic_with_error!(&env, SytemapRegistryError::MintApprovalRequired);
+        }
+
+        mint_new_property(&env, payload)
+    }
+
+    /// Mint several properties in one call with the admin key. The batch is all or
+    /// nothing: if any payload cannot be minted, none of them are. Every plot writes up
+    /// to seven ledger entries of its own, so a transaction only fits a few plots.
+    pub fn batch_mint_new_property_info(
+        env: Env,
+        payloads: Vec<PropertyInfoPayload>,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_admin(&env)?;
+        if get_mint_approval_threshold(&env) > 0 {
+            panic_with_error!(&env, SytemapRegistryError::MintApprovalRequired);
+        }
+
+        for payload in payloads.iter() {
+            mint_new_property(&env, payload)?;
+        }
+        Ok(())
+    }
+
+    /// Configure how many distinct verifiers must approve a mint proposal and how long,
+    /// up to `MAX_MINT_PROPOSAL_TTL`, a proposal stays open. A threshold of 0 allows
+    /// direct minting by the admin.
+    pub fn set_mint_approval_config(
+        env: Env,
+        threshold: u32,
+        proposal_ttl: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_admin(&env)?;
+        if proposal_ttl > MAX_MINT_PROPOSAL_TTL {
+            return Err(SytemapRegistryError::InvalidMintProposalTtl);
+        }
+
+        save_mint_approval_config(&env, threshold, proposal_ttl);
+
+        SytemapRegistryEvents::mint_approval_config_updated(&env, threshold, proposal_ttl);
+
+        Ok(())
+    }
+
+    /// Submit a property for minting. The title is created once enough verifiers approve.
+    /// Only an estate company or a verifier may propose, and at most `MAX_PENDING_MINTS`
+    /// proposals may be open at once.
+    pub fn propose_mint(
+        env: Env,
+        proposer: Address,
+        payload: PropertyInfoPayload,
+    ) -> Result<u64, SytemapRegistryError> {
+        ensure_estate_not_paused(&env, &payload.estate_name)?;
+        proposer.require_auth();
+        if !has_role(&env, Role::EstateCompany, &proposer)
+            && !has_role(&env, Role::Verifier, &proposer)
+        {
+            panic_with_error!(&env, SytemapRegistryError::Unauthorized);
+        }
+        ensure_mintable(&env, &payload)?;
+
+        let mut pending_mints = get_pending_mint_ids(&env);
+        for proposal_id in pending_mints.iter() {
+            let proposal = get_mint_proposal(&env, proposal_id)?;
+            if proposal.payload.property_verification_no == payload.property_verification_no
+                && proposal.expires_at > env.ledger().timestamp()
+            {
+                panic_with_error!(&env, SytemapRegistryError::MintProposalAlreadyExists);
+            }
+        }
+        prune_expired_mint_proposals(&env, &mut pending_mints);
+        if pending_mints.len() >= MAX_PENDING_MINTS {
+            panic_with_error!(&env, SytemapRegistryError::TooManyPendingMints);
+        }
+
+        let proposal_id = get_next_mint_proposal_id(&env);
+        let created_at = env.ledger().timestamp();
+        let expires_at = created_at
+            .checked_add(get_mint_proposal_ttl(&env))
+            .ok_or(SytemapRegistryError::InvalidMintProposalTtl)?;
+        let proposal = MintProposal {
+            proposal_id,
+            proposer: proposer.clone(),
+            payload,
+            approvals: Vec::new(&env),
+            created_at,
+            expires_at,
+        };
+        save_mint_proposal(&env, &proposal);
+        pending_mints.push_back(proposal_id);
+        save_pending_mint_ids(&env, &pending_mints);
+
+        SytemapRegistryEvents::mint_proposed(
+            &env,
+            proposal_id,
+            proposer,
+            proposal.payload.property_verification_no,
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Approve a pending mint proposal as a verifier. Returns true when this approval
+    /// reached the threshold and the property was minted.
+    pub fn approve_mint(
+        env: Env,
+        proposal_id: u64,
+        verifier: Address,
+    ) -> Result<bool, SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_role(&env, Role::Verifier, &verifier)?;
+
+        let mut proposal = get_mint_proposal(&env, proposal_id)?;
+        if proposal.expires_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, SytemapRegistryError::MintProposalExpired);
+        }
+        if proposal.approvals.contains(&verifier) {
+            panic_with_error!(&env, SytemapRegistryError::AlreadyApproved);
+        }
+        proposal.approvals.push_back(verifier.clone());
+
+        SytemapRegistryEvents::mint_approved(
+            &env,
+            proposal_id,
+            proposal.payload.property_verification_no,
+            verifier,
+            proposal.approvals.len(),
+        );
+
+        if proposal.approvals.len() < get_mint_approval_threshold(&env) {
+            save_mint_proposal(&env, &proposal);
+            return Ok(false);
+        }
+
+        remove_mint_proposal(&env, proposal_id);
+        let mut pending_mints = get_pending_mint_ids(&env);
+        if let Some(index) = pending_mints.first_index_of(proposal_id) {
+            pending_mints.remove(index);
+        }
+        save_pending_mint_ids(&env, &pending_mints);
+
+        mint_new_property(&env, proposal.payload)?;
+
+        Ok(true)
+    }
+
+    /// Mint proposals that have not expired yet.
+    pub fn get_pending_mints(env: Env) -> Result<Vec<MintProposal>, SytemapRegistryError> {
+        let now = env.ledger().timestamp();
+        let mut proposals = Vec::new(&env);
+        for proposal_id in get_pending_mint_ids(&env).iter() {
+            let proposal = get_mint_proposal(&env, proposal_id)?;
+            if proposal.expires_at > now {
+                proposals.push_back(proposal);
+            }
+        }
+        Ok(proposals)
+    }
+
+    pub fn change_property_price_by_owner(
+        env: Env,
+        property_verification_no: u64,
+        new_price: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        if new_price == 0 {
+            panic_with_error!(&env, SytemapRegistryError::InvalidPrice);
+        }
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        ensure_not_frozen(&property_info)?;
+
+        let old_price = property_info.price_of_plot;
+        property_info.price_of_plot = new_price;
+        save_property_info(&env, property_verification_no, &property_info)?;
+
+        // Emit event
+        SytemapRegistryEvents::property_price_changed(
+            &env,
+            property_info.buyer_wallet_id,
+            property_verification_no,
+            old_price,
+            new_price,
+        );
+
+        Ok(())
+    }
+
+    /// Transfer a fully owned property to a new owner.
+    pub fn transfer_property_by_owner(
+        env: Env,
+        property_verification_no: u64,
+        new_owner: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let owner = property_info.buyer_wallet_id.clone();
+        owner.require_auth();
+
+        ensure_property_movable(&env, &property_info)?;
+        ensure_recipient_verified(&env, &property_info, &new_owner)?;
+        consume_lien_consents(&env, property_verification_no, &new_owner)?;
+
+        property_info.buyer_wallet_id = new_owner.clone();
+        save_property_info(&env, property_verification_no, &property_info)?;
+
+        SytemapRegistryEvents::property_ownership_transferred(
+            &env,
+            property_verification_no,
+            owner,
+            new_owner,
+        );
+
+        Ok(())
+    }
+
+    /// Sell a property to `buyer` on an installment plan.
+    ///
+    /// The current owner keeps the title and the property is flagged as in payment
+    /// until `total` has been paid into escrow through `pay_installment`.
+    pub fn create_payment_plan(
+        env: Env,
+        property_verification_no: u64,
+        buyer: Address,
+        total: i128,
+        schedule: PaymentSchedule,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let seller = property_info.buyer_wallet_id.clone();
+        seller.require_auth();
+
+        ensure_property_movable(&env, &property_info)?;
+        if let Ok(existing_plan) = get_payment_plan(&env, property_verification_no) {
+            if !existing_plan.completed {
+                panic_with_error!(&env, SytemapRegistryError::PaymentPlanAlreadyExists);
+            }
+        }
+        if total <= 0
+            || total > MAX_PAYMENT_PLAN_TOTAL
+            || schedule.installment_count == 0
+            || total < schedule.installment_count as i128
+            || (schedule.installment_count > 1 && schedule.interval == 0)
+            || schedule.grace_period_days > MAX_PAYMENT_PERIOD_DAYS
+            || schedule.notice_period_days > MAX_PAYMENT_PERIOD_DAYS
+            || schedule.refund_percentage > 100
+            || buyer == seller
+        {
+            panic_with_error!(&env, SytemapRegistryError::InvalidPaymentPlan);
+        }
+        ensure_recipient_verified(&env, &property_info, &buyer)?;
+        consume_lien_consents(&env, property_verification_no, &buyer)?;
+
+        let plan = PaymentPlan {
+            property_verification_no,
+            seller: seller.clone(),
+            buyer: buyer.clone(),
+            token: schedule.token,
+            total_amount: total,
+            amount_paid: 0,
+            installment_count: schedule.installment_count,
+            interval: schedule.interval,
+            first_due_date: schedule.first_due_date,
+            grace_period_days: schedule.grace_period_days,
+            notice_period_days: schedule.notice_period_days,
+            refund_percentage: schedule.refund_percentage,
+            created_at: env.ledger().timestamp(),
+            defaulted_at: None,
+            completed: false,
+        };
+        // The last installment has to fall due at a time the ledger can express
+        if installment_due_date(&plan, plan.installment_count as u64 - 1).is_none() {
+            panic_with_error!(&env, SytemapRegistryError::InvalidPaymentPlan);
+        }
+        save_payment_plan(&env, &plan);
+
+        update_property_status(&env, &mut property_info, PropertyStatus::InPayment)?;
+
+        SytemapRegistryEvents::payment_plan_created(
+            &env,
+            property_verification_no,
+            seller,
+            buyer,
+            total,
+            plan.installment_count,
+        );
+
+        Ok(())
+    }
+
+    /// Pay an installment into escrow. Once the plan is fully paid the escrow is
+    /// released to the seller and ownership passes to the buyer.
+    pub fn pay_installment(
+        env: Env,
+        property_verification_no: u64,
+        amount: i128,
+    ) -> Result<PaymentStatus, SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut plan = get_payment_plan(&env, property_verification_no)?;
+        plan.buyer.require_auth();
+        ensure_not_frozen(&get_property_info_by_pvn(&env, property_verification_no)?)?;
+
+        if amount <= 0 || amount > plan.total_amount - plan.amount_paid {
+            panic_with_error!(&env, SytemapRegistryError::InvalidPaymentAmount);
+        }
+
+        let token_client = token::Client::new(&env, &plan.token);
+        token_client.transfer(&plan.buyer, &env.current_contract_address(), &amount);
+
+        plan.amount_paid += amount;
+        SytemapRegistryEvents::installment_paid(
+            &env,
+            property_verification_no,
+            plan.buyer.clone(),
+            amount,
+            plan.amount_paid,
+            plan.total_amount - plan.amount_paid,
+        );
+
+        if plan.amount_paid == plan.total_amount {
+            plan.completed = true;
+            plan.defaulted_at = None;
+            token_client.transfer(
+                &env.current_contract_address(),
+                &plan.seller,
+                &plan.total_amount,
+            );
+
+            let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+            property_info.buyer_wallet_id = plan.buyer.clone();
+            let settled_status = settled_status(&env, property_verification_no);
+            update_property_status(&env, &mut property_info, settled_status)?;
+
+            SytemapRegistryEvents::payment_plan_completed(
+                &env,
+                property_verification_no,
+                plan.buyer.clone(),
+                plan.total_amount,
+            );
+            SytemapRegistryEvents::property_ownership_transferred(
+                &env,
+                property_verification_no,
+                plan.seller.clone(),
+                plan.buyer.clone(),
+            );
+        } else if plan.defaulted_at.is_some()
+            && plan.amount_paid >= amount_due_at(&plan, env.ledger().timestamp())
+        {
+            plan.defaulted_at = None;
+            let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+            update_property_status(&env, &mut property_info, PropertyStatus::InPayment)?;
+
+            SytemapRegistryEvents::payment_default_cured(
+                &env,
+                property_verification_no,
+                plan.buyer.clone(),
+                plan.amount_paid,
+            );
+        }
+        save_payment_plan(&env, &plan);
+
+        compute_payment_status(&env, &plan)
+    }
+
+    /// Declare an installment plan in default once its earliest unpaid installment
+    /// is overdue by more than the grace period. Anyone may call this.
+    pub fn declare_payment_default(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<PaymentStatus, SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut plan = get_payment_plan(&env, property_verification_no)?;
+        ensure_not_frozen(&get_property_info_by_pvn(&env, property_verification_no)?)?;
+        let status = compute_payment_status(&env, &plan)?;
+
+        if status.state != PaymentState::Overdue || status.days_overdue < plan.grace_period_days {
+            panic_with_error!(&env, SytemapRegistryError::PaymentNotOverdue);
+        }
+
+        plan.defaulted_at = Some(env.ledger().timestamp());
+        save_payment_plan(&env, &plan);
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        update_property_status(&env, &mut property_info, PropertyStatus::InDefault)?;
+
+        SytemapRegistryEvents::payment_default_declared(
+            &env,
+            property_verification_no,
+            plan.buyer.clone(),
+            status.days_overdue,
+            status.outstanding,
+        );
+
+        compute_payment_status(&env, &plan)
+    }
+
+    /// Repossess a plot in default once the notice period has elapsed.
+    ///
+    /// The configured refund percentage of the escrowed installments goes back to the
+    /// buyer, the rest is released to the estate company, and the plan is closed.
+    pub fn repossess(env: Env, property_verification_no: u64) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let plan = get_payment_plan(&env, property_verification_no)?;
+        plan.seller.require_auth();
+        ensure_not_frozen(&get_property_info_by_pvn(&env, property_verification_no)?)?;
+
+        let defaulted_at = match plan.defaulted_at {
+            Some(defaulted_at) => defaulted_at,
+            None => panic_with_error!(&env, SytemapRegistryError::PropertyNotInDefault),
+        };
+        let notice_ends_at = plan
+            .notice_period_days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|notice_period| defaulted_at.checked_add(notice_period))
+            .ok_or(SytemapRegistryError::InvalidPaymentPlan)?;
+        if env.ledger().timestamp() < notice_ends_at {
+            panic_with_error!(&env, SytemapRegistryError::NoticePeriodNotElapsed);
+        }
+
+        let refunded = plan
+            .amount_paid
+            .checked_mul(plan.refund_percentage as i128)
+            .and_then(|refund| refund.checked_div(100))
+            .ok_or(SytemapRegistryError::InvalidPaymentPlan)?;
+        let retained = plan.amount_paid - refunded;
+        let token_client = token::Client::new(&env, &plan.token);
+        if refunded > 0 {
+            token_client.transfer(&env.current_contract_address(), &plan.buyer, &refunded);
+        }
+        if retained > 0 {
+            token_client.transfer(&env.current_contract_address(), &plan.seller, &retained);
+        }
+        remove_payment_plan(&env, property_verification_no);
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let settled_status = settled_status(&env, property_verification_no);
+        update_property_status(&env, &mut property_info, settled_status)?;
+
+        SytemapRegistryEvents::property_repossessed(
+            &env,
+            property_verification_no,
+            plan.seller,
+            plan.buyer,
+            refunded,
+            retained,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_payment_status(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<PaymentStatus, SytemapRegistryError> {
+        let plan = get_payment_plan(&env, property_verification_no)?;
+        compute_payment_status(&env, &plan)
+    }
+
+    /// Register a lien against a property. Both the owner and the lienholder must sign.
+    pub fn register_lien(
+        env: Env,
+        property_verification_no: u64,
+        lienholder: Address,
+        amount: i128,
+        expiry: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        property_info.buyer_wallet_id.require_auth();
+        lienholder.require_auth();
+
+        ensure_property_movable(&env, &property_info)?;
+        if amount <= 0 || expiry <= env.ledger().timestamp() {
+            panic_with_error!(&env, SytemapRegistryError::InvalidLien);
+        }
+
+        let mut liens = get_active_liens(&env, property_verification_no);
+        if liens.iter().any(|lien| lien.lienholder == lienholder) {
+            panic_with_error!(&env, SytemapRegistryError::LienAlreadyExists);
+        }
+        liens.push_back(Lien {
+            lienholder: lienholder.clone(),
+            amount,
+            expiry,
+            registered_at: env.ledger().timestamp(),
+            consented_transferee: None,
+        });
+        save_liens(&env, property_verification_no, &liens);
+        update_property_status(&env, &mut property_info, PropertyStatus::Encumbered)?;
+
+        SytemapRegistryEvents::lien_registered(
+            &env,
+            property_verification_no,
+            lienholder,
+            amount,
+            expiry,
+        );
+
+        Ok(())
+    }
+
+    /// Release the lien held by `lienholder` on a property.
+    pub fn release_lien(
+        env: Env,
+        property_verification_no: u64,
+        lienholder: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        lienholder.require_auth();
+
+        let mut liens = get_liens(&env, property_verification_no);
+        let index = match liens.iter().position(|lien| lien.lienholder == lienholder) {
+            Some(index) => index as u32,
+            None => panic_with_error!(&env, SytemapRegistryError::LienNotFound),
+        };
+        liens.remove(index);
+        save_liens(&env, property_verification_no, &liens);
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        if property_info.status == PropertyStatus::Encumbered {
+            let settled_status = settled_status(&env, property_verification_no);
+            update_property_status(&env, &mut property_info, settled_status)?;
+        }
+
+        SytemapRegistryEvents::lien_released(&env, property_verification_no, lienholder);
+
+        Ok(())
+    }
+
+    /// Drop the liens on a property whose expiry has passed, settling it back to
+    /// `Allocated` once none are left. Anyone may call this.
+    pub fn clear_expired_liens(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+
+        let now = env.ledger().timestamp();
+        let liens = get_liens(&env, property_verification_no);
+        save_liens(
+            &env,
+            property_verification_no,
+            &get_active_liens(&env, property_verification_no),
+        );
+        if property_info.status == PropertyStatus::Encumbered {
+            let settled_status = settled_status(&env, property_verification_no);
+            update_property_status(&env, &mut property_info, settled_status)?;
+        }
+
+        for lien in liens.iter().filter(|lien| lien.expiry <= now) {
+            SytemapRegistryEvents::lien_expired(
+                &env,
+                property_verification_no,
+                lien.lienholder,
+                lien.expiry,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Allow an encumbered property to move to `transferee`. The consent is used up by
+    /// the next transfer or sale of the property.
+    pub fn consent_to_lien_transfer(
+        env: Env,
+        property_verification_no: u64,
+        lienholder: Address,
+        transferee: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        lienholder.require_auth();
+
+        let mut liens = get_active_liens(&env, property_verification_no);
+        let index = match liens.iter().position(|lien| lien.lienholder == lienholder) {
+            Some(index) => index as u32,
+            None => panic_with_error!(&env, SytemapRegistryError::LienNotFound),
+        };
+        let mut lien = liens.get_unchecked(index);
+        lien.consented_transferee = Some(transferee.clone());
+        liens.set(index, lien);
+        save_liens(&env, property_verification_no, &liens);
+
+        SytemapRegistryEvents::lien_transfer_consented(
+            &env,
+            property_verification_no,
+            lienholder,
+            transferee,
+        );
+
+        Ok(())
+    }
+
+    /// Liens that currently encumber a property.
+    pub fn get_encumbrances(env: Env, property_verification_no: u64) -> Vec<Lien> {
+        get_active_liens(&env, property_verification_no)
+    }
+
+    /// Lease a property to a tenant from `start` to `end`, both ledger timestamps. The
+    /// owner and the tenant must both sign, and the tenant pays the rent for the whole term
+    /// to the owner up front. The lease stays with the property if it changes hands.
+    pub fn create_lease(
+        env: Env,
+        property_verification_no: u64,
+        tenant: Address,
+        start: u64,
+        end: u64,
+        rent: i128,
+        token: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let owner = property_info.buyer_wallet_id.clone();
+        owner.require_auth();
+        tenant.require_auth();
+
+        ensure_property_movable(&env, &property_info)?;
+        if rent <= 0 || start >= end || end <= env.ledger().timestamp() || tenant == owner {
+            panic_with_error!(&env, SytemapRegistryError::InvalidLease);
+        }
+        if get_lease(&env, property_verification_no).is_some() {
+            panic_with_error!(&env, SytemapRegistryError::LeaseAlreadyExists);
+        }
+
+        token::Client::new(&env, &token).transfer(&tenant, &owner, &rent);
+        let lease = Lease {
+            tenant,
+            start,
+            end,
+            rent,
+            token,
+            granted_by: owner,
+            created_at: env.ledger().timestamp(),
+        };
+        save_lease(&env, property_verification_no, &lease);
+
+        SytemapRegistryEvents::lease_created(&env, property_verification_no, &lease);
+
+        Ok(())
+    }
+
+    /// End a lease before its term is over. Only the tenant may call this, as the right to
+    /// use the plot is theirs. Rent already paid is not refunded.
+    pub fn terminate_lease(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let lease =
+            get_lease(&env, property_verification_no).ok_or(SytemapRegistryError::LeaseNotFound)?;
+        lease.tenant.require_auth();
+
+        remove_lease(&env, property_verification_no);
+
+        SytemapRegistryEvents::lease_terminated(&env, property_verification_no, lease.tenant);
+
+        Ok(())
+    }
+
+    /// The lease in force on a property at the current ledger time, if any. A lease whose
+    /// term has not started yet is not in force.
+    pub fn get_active_lease(env: Env, property_verification_no: u64) -> Option<Lease> {
+        get_lease(&env, property_verification_no)
+            .filter(|lease| lease.start <= env.ledger().timestamp())
+    }
+
+    /// Lock a property as collateral for `locker_contract`, typically a lending protocol.
+    /// Requires the owner and the locker to sign.
+    pub fn lock_as_collateral(
+        env: Env,
+        property_verification_no: u64,
+        locker_contract: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        property_info.buyer_wallet_id.require_auth();
+        locker_contract.require_auth();
+
+        ensure_property_movable(&env, &property_info)?;
+        save_collateral_locker(&env, property_verification_no, &locker_contract);
+
+        SytemapRegistryEvents::property_locked(
+            &env,
+            property_verification_no,
+            property_info.buyer_wallet_id,
+            locker_contract,
+        );
+
+        Ok(())
+    }
+
+    /// Release a collateral lock. Only the locker may call this.
+    pub fn unlock(env: Env, property_verification_no: u64) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let locker = get_collateral_locker(&env, property_verification_no)?;
+        locker.require_auth();
+
+        remove_collateral_locker(&env, property_verification_no);
+
+        SytemapRegistryEvents::property_unlocked(&env, property_verification_no, locker);
+
+        Ok(())
+    }
+
+    /// Seize a locked property on default and transfer it to `new_owner`.
+    /// Only the locker may call this.
+    pub fn liquidate_to(
+        env: Env,
+        property_verification_no: u64,
+        new_owner: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let locker = get_collateral_locker(&env, property_verification_no)?;
+        locker.require_auth();
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        ensure_owned_status(&property_info)?;
+        ensure_recipient_verified(&env, &property_info, &new_owner)?;
+        consume_lien_consents(&env, property_verification_no, &new_owner)?;
+        remove_collateral_locker(&env, property_verification_no);
+
+        let old_owner = property_info.buyer_wallet_id.clone();
+        property_info.buyer_wallet_id = new_owner.clone();
+        save_property_info(&env, property_verification_no, &property_info)?;
+
+        SytemapRegistryEvents::property_liquidated(
+            &env,
+            property_verification_no,
+            locker,
+            new_owner.clone(),
+        );
+        SytemapRegistryEvents::property_ownership_transferred(
+            &env,
+            property_verification_no,
+            old_owner,
+            new_owner,
+        );
+
+        Ok(())
+    }
+
+    pub fn is_locked(env: Env, property_verification_no: u64) -> bool {
+        get_collateral_locker(&env, property_verification_no).is_ok()
+    }
+
+    /// Freeze a disputed property so that it cannot be repriced, sold or moved.
+    /// Only an arbitrator may call this.
+    pub fn freeze_property(
+        env: Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        case_ref: String,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_role(&env, Role::Arbitrator, &arbitrator)?;
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        ensure_not_frozen(&property_info)?;
+
+        save_dispute(
+            &env,
+            property_verification_no,
+            &DisputeRecord {
+                case_ref: case_ref.clone(),
+                arbitrator: arbitrator.clone(),
+                frozen_at: env.ledger().timestamp(),
+                previous_status: property_info.status,
+            },
+        );
+        update_property_status(&env, &mut property_info, PropertyStatus::Frozen)?;
+
+        SytemapRegistryEvents::property_frozen(
+            &env,
+            property_verification_no,
+            arbitrator,
+            case_ref,
+        );
+
+        Ok(())
+    }
+
+    /// Lift the freeze on a property and restore the status it had before.
+    /// Only an arbitrator may call this.
+    pub fn unfreeze_property(
+        env: Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        resolution: String,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_role(&env, Role::Arbitrator, &arbitrator)?;
+
+        let dispute = get_dispute(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+
+        remove_dispute(&env, property_verification_no);
+        set_property_status(&env, &mut property_info, dispute.previous_status)?;
+
+        SytemapRegistryEvents::property_unfrozen(
+            &env,
+            property_verification_no,
+            arbitrator,
+            dispute.case_ref,
+            resolution,
+        );
+
+        Ok(())
+    }
+
+    /// Enforce an arbitration outcome by moving a frozen property to `new_owner`, who must
+    /// pass the KYC level of the estate like any other recipient. A collateral lock and any
+    /// lien consents given for the old owner are dropped, while liens and leases stay with
+    /// the property. The property stays frozen until the arbitrator lifts the freeze.
+    pub fn resolve_dispute_transfer(
+        env: Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        new_owner: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_role(&env, Role::Arbitrator, &arbitrator)?;
+
+        let dispute = get_dispute(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        ensure_recipient_verified(&env, &property_info, &new_owner)?;
+
+        let old_owner = property_info.buyer_wallet_id.clone();
+        property_info.buyer_wallet_id = new_owner.clone();
+        save_property_info(&env, property_verification_no, &property_info)?;
+
+        // The old owner's pledge and the consents given for their sale do not bind the new one
+        if let Ok(locker) = get_collateral_locker(&env, property_verification_no) {
+            remove_collateral_locker(&env, property_verification_no);
+            SytemapRegistryEvents::property_unlocked(&env, property_verification_no, locker);
+        }
+        clear_lien_consents(&env, property_verification_no);
+
+        // The new owner also takes over the seller side of an open installment plan
+        if let Ok(mut plan) = get_payment_plan(&env, property_verification_no) {
+            if !plan.completed {
+                plan.seller = new_owner.clone();
+                save_payment_plan(&env, &plan);
+            }
+        }
+
+        SytemapRegistryEvents::dispute_transfer_resolved(
+            &env,
+            property_verification_no,
+            arbitrator,
+            dispute.case_ref,
+            old_owner.clone(),
+            new_owner.clone(),
+        );
+        SytemapRegistryEvents::property_ownership_transferred(
+            &env,
+            property_verification_no,
+            old_owner,
+            new_owner,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_dispute(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<DisputeRecord, SytemapRegistryError> {
+        get_dispute(&env, property_verification_no)
+    }
+
+    /// Send a property for (re)survey. Only the owner may call this.
+    pub fn submit_for_survey(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        property_info.buyer_wallet_id.require_auth();
+
+        ensure_not_frozen(&property_info)?;
+        if get_collateral_locker(&env, property_verification_no).is_ok() {
+            panic_with_error!(&env, SytemapRegistryError::PropertyLocked);
+        }
+        update_property_status(&env, &mut property_info, PropertyStatus::PendingSurvey)
+    }
+
+    /// Confirm the survey of a property. Only a verifier may call this.
+    pub fn verify_property(
+        env: Env,
+        property_verification_no: u64,
+        verifier: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_role(&env, Role::Verifier, &verifier)?;
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        if property_info.status != PropertyStatus::PendingSurvey {
+            panic_with_error!(&env, SytemapRegistryError::InvalidStateTransition);
+        }
+        update_property_status(&env, &mut property_info, PropertyStatus::Verified)
+    }
+
+    /// Send a property that failed its survey back to draft. Only a verifier may call this.
+    pub fn reject_survey(
+        env: Env,
+        property_verification_no: u64,
+        verifier: Address,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_role(&env, Role::Verifier, &verifier)?;
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        if property_info.status != PropertyStatus::PendingSurvey {
+            panic_with_error!(&env, SytemapRegistryError::InvalidStateTransition);
+        }
+        update_property_status(&env, &mut property_info, PropertyStatus::Draft)
+    }
+
+    /// Allocate a verified property to its owner. Only the admin may call this.
+    pub fn allocate_property(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_admin(&env)?;
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        if property_info.status != PropertyStatus::Verified {
+            panic_with_error!(&env, SytemapRegistryError::InvalidStateTransition);
+        }
+        let settled_status = settled_status(&env, property_verification_no);
+        update_property_status(&env, &mut property_info, settled_status)
+    }
+
+    /// Cancel a title for good. Only the admin may call this, and a frozen title has to
+    /// be unfrozen by the arbitrator first.
+    pub fn revoke_property(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        require_admin(&env)?;
+
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        update_property_status(&env, &mut property_info, PropertyStatus::Revoked)
+    }
+
+    /// Split a plot into child plots minted to its owner and retire the plot itself. The
+    /// owner and a verifier must both sign. The verifier's sign-off stands in for mint
+    /// approvals, as the plot was approved when it was minted.
+    pub fn subdivide(
+        env: Env,
+        property_verification_no: u64,
+        verifier: Address,
+        child_payloads: Vec<PropertyInfoPayload>,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let owner = property_info.buyer_wallet_id.clone();
+        owner.require_auth();
+        require_role(&env, Role::Verifier, &verifier)?;
+
+        ensure_unencumbered(&env, &property_info)?;
+        ensure_subdivision_fits(&property_info, &child_payloads)?;
+
+        let parent_pvns = Vec::from_array(&env, [property_verification_no]);
+        let mut child_pvns = Vec::new(&env);
+        for payload in child_payloads.iter() {
+            let child_pvn = payload.property_verification_no;
+            mint_new_property(&env, payload)?;
+            save_parent_pvns(&env, child_pvn, &parent_pvns);
+            child_pvns.push_back(child_pvn);
+        }
+        save_child_pvns(&env, property_verification_no, &child_pvns);
+        update_property_status(&env, &mut property_info, PropertyStatus::Subdivided)?;
+
+        SytemapRegistryEvents::subdivided(
+            &env,
+            property_verification_no,
+            owner,
+            verifier,
+            child_pvns,
+        );
+
+        Ok(())
+    }
+
+    /// Merge neighbouring plots of one owner into a new plot minted to them and retire the
+    /// source plots. The owner and a verifier must both sign, and none of the plots may be
+    /// encumbered, leased, pledged, disputed or being paid off. The documents anchored to
+    /// the sources are carried over to the new plot in the order of `pvns`, so together
+    /// they may not hold more than `MAX_DOCUMENTS_PER_PLOT`.
+    pub fn merge_plots(
+        env: Env,
+        pvns: Vec<u64>,
+        verifier: Address,
+        new_payload: PropertyInfoPayload,
+    ) -> Result<(), SytemapRegistryError> {
+        let mut sources = Vec::new(&env);
+        for pvn in pvns.iter() {
+            ensure_property_not_paused(&env, pvn)?;
+            sources.push_back(get_property_info_by_pvn(&env, pvn)?);
+        }
+        let owner = new_payload.buyer_wallet_id.clone();
+        owner.require_auth();
+        require_role(&env, Role::Verifier, &verifier)?;
+        for source in sources.iter() {
+            ensure_unencumbered(&env, &source)?;
+        }
+        ensure_merge_fits(&pvns, &sources, &new_payload)?;
+
+        let merged_pvn = new_payload.property_verification_no;
+        mint_new_property(&env, new_payload)?;
+        let child_pvns = Vec::from_array(&env, [merged_pvn]);
+        let mut documents = Vec::new(&env);
+        for mut source in sources.iter() {
+            let source_pvn = source.property_verification_no;
+            documents.append(&get_documents(&env, source_pvn));
+            save_child_pvns(&env, source_pvn, &child_pvns);
+            update_property_status(&env, &mut source, PropertyStatus::Merged)?;
+        }
+        if documents.len() > MAX_DOCUMENTS_PER_PLOT {
+            return Err(SytemapRegistryError::TooManyDocuments);
+        }
+        save_documents(&env, merged_pvn, &documents);
+        save_parent_pvns(&env, merged_pvn, &pvns);
+
+        SytemapRegistryEvents::plots_merged(&env, merged_pvn, owner, verifier, pvns);
+
+        Ok(())
+    }
+
+    pub fn get_properties_by_status(
+        env: Env,
+        status: PropertyStatus,
+    ) -> Result<Vec<PropertyInfo>, SytemapRegistryError> {
+        let all_properties = get_all_property_verification_numbers(&env);
+
+        let mut properties = Vec::new(&env);
+        for pvn in all_properties.iter() {
+            if let Ok(property_info) = get_property_info(&env, pvn) {
+                if property_info.status == status {
+                    properties.push_back(property_info);
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Anchor the SHA-256 fingerprint of a land document to a property. Only a verifier
+    /// or the account bound to the estate company of the property may call this. A newer
+    /// document of the same type supersedes the previous one, and a property holds at
+    /// most `MAX_DOCUMENTS_PER_PLOT` documents.
+    pub fn attach_document(
+        env: Env,
+        property_verification_no: u64,
+        attester: Address,
+        doc_type: DocumentType,
+        sha256_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_property_not_paused(&env, property_verification_no)?;
+        attester.require_auth();
+        let property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        let is_estate_company = has_role(&env, Role::EstateCompany, &attester)
+            && get_estate_company_account(&env, &property_info.estate_company_name)
+                == Some(attester.clone());
+        if !has_role(&env, Role::Verifier, &attester) && !is_estate_company {
+            panic_with_error!(&env, SytemapRegistryError::Unauthorized);
+        }
+
+        let mut documents = get_documents(&env, property_verification_no);
+        if documents.len() >= MAX_DOCUMENTS_PER_PLOT {
+            return Err(SytemapRegistryError::TooManyDocuments);
+        }
+        documents.push_back(PropertyDocument {
+            doc_type,
+            sha256_hash: sha256_hash.clone(),
+            uri: uri.clone(),
+            attached_by: attester.clone(),
+            attached_at: env.ledger().timestamp(),
+        });
+        save_documents(&env, property_verification_no, &documents);
+
+        SytemapRegistryEvents::document_attached(
+            &env,
+            property_verification_no,
+            doc_type,
+            sha256_hash,
+            uri,
+            attester,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_documents(env: Env, property_verification_no: u64) -> Vec<PropertyDocument> {
+        get_documents(&env, property_verification_no)
+    }
+
+    /// Check that a document held off-chain matches the latest one anchored for its type.
+    pub fn verify_document(
+        env: Env,
+        property_verification_no: u64,
+        doc_type: DocumentType,
+        sha256_hash: BytesN<32>,
+    ) -> bool {
+        matches_latest_document(&env, property_verification_no, doc_type, &sha256_hash)
+    }
+
+    /// Record the KYC result of an address. Only a compliance officer may call this.
+    pub fn set_kyc_status(
+        env: Env,
+        officer: Address,
+        account: Address,
+        level: u32,
+        expiry: u64,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        require_role(&env, Role::Compliance, &officer)?;
+
+        save_kyc_status(
+            &env,
+            &account,
+            &KycStatus {
+                level,
+                expiry,
+                updated_by: officer.clone(),
+            },
+        );
+        SytemapRegistryEvents::kyc_status_updated(&env, account, level, expiry, officer);
+
+        Ok(())
+    }
+
+    pub fn get_kyc_status(env: Env, account: Address) -> Option<KycStatus> {
+        get_kyc_status(&env, &account)
     }
 
-    pub fn safe_mint_new_property_info(
+    /// Use an external KYC contract as a fallback to the registry's own records, or
+    /// stop using one. Only the admin may call this.
+    pub fn set_kyc_provider(
         env: Env,
-        payload: PropertyInfoPayload,
+        provider: Option<Address>,
     ) -> Result<(), SytemapRegistryError> {
-        let pvn = payload.property_verification_no;
-        let nft_address = payload.nft_address.clone();
-
-        if check_pvn_exists(&env, pvn) {
-            panic_with_error!(&env, SytemapRegistryError::AlreadyMinted);
-        }
-
-        if check_nft_address_exists(&env, nft_address.clone()) {
-            panic_with_error!(&env, SytemapRegistryError::NftAddressAlreadyInUse);
-        }
-        // Generate a new token ID
-        let token_id = get_next_token_id(&env)?;
-        log!(&env, "count: {}", token_id);
-
-        // Capture the current timestamp from the ledger
-        let timestamp = env.ledger().timestamp();
-
-        // Create a new PropertyInfo with the timestamp included
-        let property_info = PropertyInfo {
-            price_of_plot: payload.price_of_plot,
-            property_verification_no: pvn,
-            buyer_wallet_id: payload.buyer_wallet_id,
-            plot_no: payload.plot_no,
-            size_of_plot: payload.size_of_plot,
-            coordinate_of_plot: payload.coordinate_of_plot,
-            token_url: payload.token_url,
-            estate_name: payload.estate_name,
-            plot_url: payload.plot_url,
-            date_of_allocation: payload.date_of_allocation,
-            estate_company_name: payload.estate_company_name,
-            nft_address: payload.nft_address,
-            timestamp, // Add timestamp to property_info
-        };
+        ensure_not_paused(&env)?;
+        require_admin(&env)?;
 
-        // // Save the property info, NFT address to token ID mapping, and property verification number to token ID mapping
-        mint_property_info(&env, pvn, &property_info)?;
-        save_nft_address_to_token_id(&env, nft_address, token_id.into())?;
-        save_pvn_to_token_id(&env, pvn, token_id.into())?;
+        save_kyc_provider(&env, &provider);
+        SytemapRegistryEvents::kyc_provider_updated(&env, provider);
 
-        // Track all properties
-        let mut all_properties = get_all_property_verification_numbers(&env);
-        all_properties.push_back(pvn);
-        update_all_properties(&env, all_properties)?;
+        Ok(())
+    }
 
-        // Emit event
-        SytemapRegistryEvents::property_info_created(
-            &env,
-            property_info.plot_no.clone(),
-            property_info.property_verification_no,
-            token_id,
-            timestamp,
-            property_info.price_of_plot,
-            property_info.buyer_wallet_id,
-            property_info.token_url.clone(),
-            property_info.estate_name.clone(),
-            property_info.size_of_plot.clone(),
-            property_info.plot_url.clone(),
-            property_info.date_of_allocation.clone(),
-            property_info.coordinate_of_plot.clone(),
-            property_info.estate_company_name.clone(),
-        );
+    /// Opt an estate in to KYC checks on recipients with a minimum level, or out with 0.
+    /// Only a compliance officer or the admin may call this. Estate companies are not
+    /// tied to the estates they sell, so they ask compliance to change the level.
+    pub fn set_estate_kyc_level(
+        env: Env,
+        caller: Address,
+        estate_name: String,
+        min_level: u32,
+    ) -> Result<(), SytemapRegistryError> {
+        ensure_not_paused(&env)?;
+        caller.require_auth();
+        if !has_role(&env, Role::Compliance, &caller) && get_admin(&env)? != caller {
+            panic_with_error!(&env, SytemapRegistryError::Unauthorized);
+        }
+
+        save_estate_kyc_level(&env, &estate_name, min_level);
+        SytemapRegistryEvents::estate_kyc_level_updated(&env, estate_name, min_level);
 
         Ok(())
     }
 
-    pub fn change_property_price_by_owner(
+    pub fn get_estate_kyc_level(env: Env, estate_name: String) -> u32 {
+        get_estate_kyc_level(&env, &estate_name)
+    }
+
+    /// Halt every mutating entry point. Only a pauser may call this. The admin's recovery
+    /// calls, `grant_role`, `revoke_role`, `set_estate_company_account` and
+    /// `repair_index`, stay open so that a lost or compromised key can be replaced and a
+    /// broken index fixed before the registry resumes.
+    pub fn pause(env: Env, pauser: Address) -> Result<(), SytemapRegistryError> {
+        require_role(&env, Role::Pauser, &pauser)?;
+
+        save_paused(&env, true);
+        SytemapRegistryEvents::contract_paused(&env, pauser);
+
+        Ok(())
+    }
+
+    /// Resume the registry after a pause. Only a pauser may call this.
+    pub fn unpause(env: Env, pauser: Address) -> Result<(), SytemapRegistryError> {
+        require_role(&env, Role::Pauser, &pauser)?;
+
+        save_paused(&env, false);
+        SytemapRegistryEvents::contract_unpaused(&env, pauser);
+
+        Ok(())
+    }
+
+    /// Halt every mutating entry point for the plots of one estate. Only a pauser may
+    /// call this.
+    pub fn pause_estate(
         env: Env,
-        property_verification_no: u64,
-        new_price: u64,
+        pauser: Address,
+        estate_name: String,
     ) -> Result<(), SytemapRegistryError> {
-        if new_price == 0 {
-            panic_with_error!(&env, SytemapRegistryError::InvalidPrice);
-        }
+        require_role(&env, Role::Pauser, &pauser)?;
 
-        let mut property_info = get_property_info_by_pvn(&env, property_verification_no)?;
+        save_estate_paused(&env, &estate_name, true);
+        SytemapRegistryEvents::estate_paused(&env, estate_name, pauser);
 
-        property_info.price_of_plot = new_price;
-        save_property_info(&env, property_verification_no, &property_info)?;
+        Ok(())
+    }
 
-        // Emit event
-        SytemapRegistryEvents::property_price_changed(
-            &env,
-            property_info.buyer_wallet_id,
-            property_verification_no,
-            new_price,
-        );
+    /// Resume an estate after a pause. Only a pauser may call this.
+    pub fn unpause_estate(
+        env: Env,
+        pauser: Address,
+        estate_name: String,
+    ) -> Result<(), SytemapRegistryError> {
+        require_role(&env, Role::Pauser, &pauser)?;
+
+        save_estate_paused(&env, &estate_name, false);
+        SytemapRegistryEvents::estate_unpaused(&env, estate_name, pauser);
 
         Ok(())
     }
 
+    pub fn is_paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    pub fn is_estate_paused(env: Env, estate_name: String) -> bool {
+        is_estate_paused(&env, &estate_name)
+    }
+
     pub fn get_property_info_details_by_pvn(
         env: Env,
         property_verification_no: u64,
@@ -145,31 +1375,40 @@ impl SytemapRegistry {
         env: Env,
         nft_address: String,
     ) -> Result<PropertyInfo, SytemapRegistryError> {
-        let token_id = get_nft_address_to_token_id(&env, nft_address)?;
-        get_property_info(&env, token_id)
+        let pvn = get_nft_address_to_pvn(&env, nft_address)?;
+        get_property_info(&env, pvn)
+    }
+
+    /// Look up a plot by its estate and the plot number on its beacon.
+    pub fn get_property_by_estate_plot(
+        env: Env,
+        estate_name: String,
+        plot_no: String,
+    ) -> Result<PropertyInfo, SytemapRegistryError> {
+        let pvn = get_estate_plot_pvn(&env, &estate_name, &plot_no)?;
+        get_property_info(&env, pvn)
+    }
+
+    /// Walk the subdivisions and merges a plot took part in, up to the plots it came from
+    /// and down to every plot that came from it.
+    pub fn get_lineage(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<Lineage, SytemapRegistryError> {
+        get_property_info_by_pvn(&env, property_verification_no)?;
+
+        Ok(Lineage {
+            property_verification_no,
+            ancestors: lineage_links(&env, property_verification_no, true),
+            descendants: lineage_links(&env, property_verification_no, false),
+        })
     }
 
     pub fn get_no_of_property_by_address(
         env: Env,
         owner_address: Address,
     ) -> Result<u64, SytemapRegistryError> {
-        let all_properties = get_all_property_verification_numbers(&env);
-
-        let count = all_properties
-            .iter()
-            .filter_map(|pvn| match get_property_info_by_pvn(&env, pvn) {
-                Ok(property_info) => {
-                    if property_info.buyer_wallet_id == owner_address {
-                        Some(())
-                    } else {
-                        None
-                    }
-                }
-                Err(_) => None,
-            })
-            .count() as u64;
-
-        Ok(count)
+        Ok(get_owner_properties(&env, &owner_address).len() as u64)
     }
 
     pub fn get_number_of_property_minted(env: Env) -> Result<u64, SytemapRegistryError> {
@@ -189,20 +1428,16 @@ impl SytemapRegistry {
         env: Env,
         owner_address: Address,
     ) -> Result<soroban_sdk::Vec<PropertyInfo>, SytemapRegistryError> {
-        // Retrieve all property verification numbers using both key and value types
-        let all_properties = get_all_property_verification_numbers(&env);
+        // Retrieve the property verification numbers held by the owner
+        let owner_properties = get_owner_properties(&env, &owner_address);
 
-        // Initialize a soroban_sdk::Vec to store the filtered property details
+        // Initialize a soroban_sdk::Vec to store the property details
         let mut properties = soroban_sdk::Vec::new(&env);
 
-        for pvn in all_properties.iter() {
+        for pvn in owner_properties.iter() {
             // Retrieve the property information using both key and value types
             match get_property_info(&env, pvn) {
-                Ok(property_info) => {
-                    if property_info.buyer_wallet_id == owner_address {
-                        properties.push_back(property_info);
-                    }
-                }
+                Ok(property_info) => properties.push_back(property_info),
                 Err(_) => continue,
             }
         }
@@ -210,6 +1445,69 @@ impl SytemapRegistry {
         Ok(properties)
     }
 
+    /// Page through the properties held by `owner_address`, in the order it came to hold
+    /// them. Pass the returned `next_cursor` back in until it comes back empty.
+    pub fn get_properties_by_owner_page(
+        env: Env,
+        owner_address: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<PropertyPage, SytemapRegistryError> {
+        property_page(
+            &env,
+            &get_owner_properties(&env, &owner_address),
+            cursor,
+            limit,
+        )
+    }
+
+    /// Page through the plots minted in an estate, oldest first. Pass the returned
+    /// `next_cursor` back in until it comes back empty.
+    pub fn get_properties_by_estate(
+        env: Env,
+        estate_name: String,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<PropertyPage, SytemapRegistryError> {
+        let pvns = get_estate_properties(&env, &estate_name);
+        property_page(&env, &pvns, cursor, limit)
+    }
+
+    /// Page through the plots minted by an estate company, oldest first.
+    pub fn get_properties_by_estate_company(
+        env: Env,
+        estate_company_name: String,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<PropertyPage, SytemapRegistryError> {
+        let pvns = get_estate_company_properties(&env, &estate_company_name);
+        property_page(&env, &pvns, cursor, limit)
+    }
+
+    /// Number of plots minted in an estate, revoked ones included.
+    pub fn count_properties_by_estate(env: Env, estate_name: String) -> u64 {
+        get_estate_properties(&env, &estate_name).len() as u64
+    }
+
+    /// Plot count, sales and sold area of an estate.
+    pub fn get_estate_summary(env: Env, estate_name: String) -> EstateSummary {
+        let totals = get_estate_totals(&env, &estate_name);
+        let average_price = match totals.sold_count {
+            0 => 0,
+            sold_count => (totals.total_value / sold_count as u128) as u64,
+        };
+
+        EstateSummary {
+            plot_count: get_estate_properties(&env, &estate_name).len() as u64,
+            estate_name,
+            sold_count: totals.sold_count,
+            total_value: totals.total_value,
+            average_price,
+            area_sold: totals.area_sold,
+            sold_without_area: totals.sold_without_area,
+        }
+    }
+
     pub fn get_all_minted_property_details(
         env: Env,
     ) -> Result<soroban_sdk::Vec<PropertyInfo>, SytemapRegistryError> {
@@ -228,4 +1526,681 @@ impl SytemapRegistry {
 
         Ok(properties)
     }
+
+    /// Cross-check the indices of the properties at positions `cursor` to
+    /// `cursor + limit` of the registry against their records, at most
+    /// `MAX_INTEGRITY_PAGE_SIZE` at a time. Pass the returned `next_cursor` back in until
+    /// it comes back empty.
+    pub fn verify_integrity(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<IntegrityReport, SytemapRegistryError> {
+        if limit == 0 || limit > MAX_INTEGRITY_PAGE_SIZE {
+            return Err(SytemapRegistryError::InvalidPageLimit);
+        }
+
+        let all_properties = get_all_property_verification_numbers(&env);
+        let end = all_properties.len().min(cursor.saturating_add(limit));
+        let mut inconsistencies = Vec::new(&env);
+        for position in cursor..end {
+            let pvn = all_properties.get_unchecked(position);
+            let mut issues = index_issues(&env, pvn, true);
+            // Later listings of a PVN are the duplicates
+            if all_properties.first_index_of(pvn) != Some(position) {
+                issues.push_front(IndexIssue::DuplicateListing);
+            }
+            for issue in issues.iter() {
+                inconsistencies.push_back(IndexInconsistency {
+                    property_verification_no: pvn,
+                    position,
+                    issue,
+                });
+            }
+        }
+
+        Ok(IntegrityReport {
+            checked: end.saturating_sub(cursor),
+            inconsistencies,
+            next_cursor: if end < all_properties.len() {
+                Some(end)
+            } else {
+                None
+            },
+        })
+    }
+
+    /// Rebuild the indices of a property from its record, or drop them if the record is
+    /// gone. Returns the issues that were fixed. Only the admin may call this, even while
+    /// paused.
+    pub fn repair_index(
+        env: Env,
+        property_verification_no: u64,
+    ) -> Result<Vec<IndexIssue>, SytemapRegistryError> {
+        require_admin(&env)?;
+
+        let pvn = property_verification_no;
+        let all_properties = get_all_property_verification_numbers(&env);
+        let listings = all_properties
+            .iter()
+            .filter(|listed| *listed == pvn)
+            .count();
+        let mut issues = index_issues(&env, pvn, listings > 0);
+        if listings > 1 {
+            issues.push_front(IndexIssue::DuplicateListing);
+        }
+        let property_info = get_property_info(&env, pvn);
+        if issues.is_empty() {
+            // A PVN the registry has never heard of is not something to repair
+            property_info?;
+            return Ok(issues);
+        }
+
+        // Keep the first listing of a recorded property, and none of a lost one
+        let mut keep = property_info.is_ok();
+        let mut repaired_properties = Vec::new(&env);
+        for listed in all_properties.iter() {
+            if listed == pvn {
+                if !keep {
+                    continue;
+                }
+                keep = false;
+            }
+            repaired_properties.push_back(listed);
+        }
+        if keep {
+            repaired_properties.push_back(pvn);
+        }
+        update_all_properties(&env, repaired_properties)?;
+
+        match property_info {
+            Ok(property_info) => {
+                repair_token_indices(&env, &property_info)?;
+                repair_estate_indices(&env, &property_info);
+            }
+            Err(_) => remove_pvn_to_token_id(&env, pvn),
+        }
+
+        SytemapRegistryEvents::index_repaired(&env, pvn, issues.clone());
+
+        Ok(issues)
+    }
+}
+
+/// Moves a property to a new status following the transition table, persisting it
+/// and emitting the status change. A frozen property cannot be moved this way.
+fn update_property_status(
+    env: &Env,
+    property_info: &mut PropertyInfo,
+    new_status: PropertyStatus,
+) -> Result<(), SytemapRegistryError> {
+    ensure_not_frozen(property_info)?;
+    set_property_status(env, property_info, new_status)
+}
+
+/// Like `update_property_status` but also lifts a freeze, so only `unfreeze_property`
+/// may call it, with the status recorded when the freeze was placed.
+fn set_property_status(
+    env: &Env,
+    property_info: &mut PropertyInfo,
+    new_status: PropertyStatus,
+) -> Result<(), SytemapRegistryError> {
+    let old_status = property_info.status;
+    if old_status == new_status {
+        return Ok(());
+    }
+    if !is_valid_status_transition(old_status, new_status) {
+        return Err(SytemapRegistryError::InvalidStateTransition);
+    }
+    property_info.status = new_status;
+    save_property_info(env, property_info.property_verification_no, property_info)?;
+
+    SytemapRegistryEvents::property_status_changed(
+        env,
+        property_info.property_verification_no,
+        old_status,
+        new_status,
+    );
+
+    Ok(())
+}
+
+/// Fails unless every active lienholder has consented to the property moving to
+/// `new_owner`, then clears those consents. Expired liens are dropped on the way.
+fn consume_lien_consents(
+    env: &Env,
+    property_verification_no: u64,
+    new_owner: &Address,
+) -> Result<(), SytemapRegistryError> {
+    let mut liens = get_active_liens(env, property_verification_no);
+    for (index, mut lien) in liens.clone().iter().enumerate() {
+        if lien.consented_transferee.as_ref() != Some(new_owner) {
+            return Err(SytemapRegistryError::PropertyEncumbered);
+        }
+        lien.consented_transferee = None;
+        liens.set(index as u32, lien);
+    }
+    save_liens(env, property_verification_no, &liens);
+    Ok(())
+}
+
+/// Withdraws every consent lienholders gave for the property to move to someone.
+fn clear_lien_consents(env: &Env, property_verification_no: u64) {
+    let mut liens = get_liens(env, property_verification_no);
+    for (index, mut lien) in liens.clone().iter().enumerate() {
+        lien.consented_transferee = None;
+        liens.set(index as u32, lien);
+    }
+    save_liens(env, property_verification_no, &liens);
+}
+
+/// Fails if the property cannot currently change hands.
+fn ensure_property_movable(
+    env: &Env,
+    property_info: &PropertyInfo,
+) -> Result<(), SytemapRegistryError> {
+    ensure_owned_status(property_info)?;
+    if get_collateral_locker(env, property_info.property_verification_no).is_ok() {
+        return Err(SytemapRegistryError::PropertyLocked);
+    }
+    Ok(())
+}
+
+/// Fails unless the property is allocated to its owner, with or without liens.
+fn ensure_owned_status(property_info: &PropertyInfo) -> Result<(), SytemapRegistryError> {
+    ensure_not_frozen(property_info)?;
+    match property_info.status {
+        PropertyStatus::Allocated | PropertyStatus::Encumbered => Ok(()),
+        _ => Err(SytemapRegistryError::PropertyNotTransferable),
+    }
+}
+
+/// Status a property settles in once no sale is in progress.
+fn settled_status(env: &Env, property_verification_no: u64) -> PropertyStatus {
+    if get_active_liens(env, property_verification_no).is_empty() {
+        PropertyStatus::Allocated
+    } else {
+        PropertyStatus::Encumbered
+    }
+}
+
+/// Fails while the whole registry is paused.
+fn ensure_not_paused(env: &Env) -> Result<(), SytemapRegistryError> {
+    if is_paused(env) {
+        return Err(SytemapRegistryError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fails while the registry or the given estate is paused.
+fn ensure_estate_not_paused(env: &Env, estate_name: &String) -> Result<(), SytemapRegistryError> {
+    ensure_not_paused(env)?;
+    if is_estate_paused(env, estate_name) {
+        return Err(SytemapRegistryError::ContractPaused);
+    }
+    Ok(())
+}
+
+/// Fails while the registry or the estate of the property is paused.
+fn ensure_property_not_paused(
+    env: &Env,
+    property_verification_no: u64,
+) -> Result<(), SytemapRegistryError> {
+    ensure_not_paused(env)?;
+    if let Ok(property_info) = get_property_info_by_pvn(env, property_verification_no) {
+        ensure_estate_not_paused(env, &property_info.estate_name)?;
+    }
+    Ok(())
+}
+
+/// Fails if the property is frozen by an open dispute.
+fn ensure_not_frozen(property_info: &PropertyInfo) -> Result<(), SytemapRegistryError> {
+    if property_info.status == PropertyStatus::Frozen {
+        return Err(SytemapRegistryError::PropertyFrozen);
+    }
+    Ok(())
+}
+
+/// Fails unless the admin has signed the call.
+fn require_admin(env: &Env) -> Result<Address, SytemapRegistryError> {
+    let admin = get_admin(env)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Fails unless `account` has signed the call and holds `role`.
+fn require_role(env: &Env, role: Role, account: &Address) -> Result<(), SytemapRegistryError> {
+    account.require_auth();
+    if !has_role(env, role, account) {
+        return Err(SytemapRegistryError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Creates the property record and its indices and emits the mint event.
+fn mint_new_property(env: &Env, payload: PropertyInfoPayload) -> Result<(), SytemapRegistryError> {
+    let pvn = payload.property_verification_no;
+    let nft_address = payload.nft_address.clone();
+
+    ensure_mintable(env, &payload)?;
+    ensure_estate_not_paused(env, &payload.estate_name)?;
+    if !is_recipient_verified(env, &payload.estate_name, &payload.buyer_wallet_id) {
+        return Err(SytemapRegistryError::RecipientNotVerified);
+    }
+    // Generate a new token ID
+    let token_id = get_next_token_id(env)?;
+    log!(env, "count: {}", token_id);
+
+    // Capture the current timestamp from the ledger
+    let timestamp = env.ledger().timestamp();
+
+    // Create a new PropertyInfo with the timestamp included
+    let property_info = PropertyInfo {
+        price_of_plot: payload.price_of_plot,
+        property_verification_no: pvn,
+        buyer_wallet_id: payload.buyer_wallet_id,
+        plot_no: payload.plot_no,
+        size_of_plot: payload.size_of_plot,
+        coordinate_of_plot: payload.coordinate_of_plot,
+        token_url: payload.token_url,
+        estate_name: payload.estate_name,
+        plot_url: payload.plot_url,
+        date_of_allocation: payload.date_of_allocation,
+        estate_company_name: payload.estate_company_name,
+        nft_address: payload.nft_address,
+        timestamp, // Add timestamp to property_info
+        status: PropertyStatus::Allocated,
+    };
+
+    // // Save the property info, NFT address to token ID mapping, and property verification number to token ID mapping
+    mint_property_info(env, pvn, &property_info)?;
+    save_nft_address_to_token_id(env, nft_address.clone(), token_id)?;
+    save_nft_address_to_pvn(env, nft_address, pvn);
+    save_pvn_to_token_id(env, pvn, token_id)?;
+    save_estate_plot_pvn(env, &property_info.estate_name, &property_info.plot_no, pvn);
+    let mut estate_properties = get_estate_properties(env, &property_info.estate_name);
+    estate_properties.push_back(pvn);
+    save_estate_properties(env, &property_info.estate_name, &estate_properties);
+    let mut company_properties =
+        get_estate_company_properties(env, &property_info.estate_company_name);
+    company_properties.push_back(pvn);
+    save_estate_company_properties(env, &property_info.estate_company_name, &company_properties);
+
+    // Track all properties
+    let mut all_properties = get_all_property_verification_numbers(env);
+    all_properties.push_back(pvn);
+    update_all_properties(env, all_properties)?;
+
+    // Emit event
+    SytemapRegistryEvents::property_info_created(env, token_id, property_info);
+
+    Ok(())
+}
+
+/// Lists what is wrong with the indices of a property, except duplicate listings, which
+/// depend on where the caller found the PVN.
+fn index_issues(env: &Env, pvn: u64, listed: bool) -> Vec<IndexIssue> {
+    let mut issues = Vec::new(env);
+    let token_id = get_token_id_of_pvn(env, pvn);
+    let Ok(property_info) = get_property_info(env, pvn) else {
+        if listed || token_id.is_ok() {
+            issues.push_back(IndexIssue::MissingPropertyInfo);
+        }
+        return issues;
+    };
+
+    if !listed {
+        issues.push_back(IndexIssue::NotListed);
+    }
+    if token_id.is_err() {
+        issues.push_back(IndexIssue::MissingTokenId);
+    }
+    if get_estate_plot_pvn(env, &property_info.estate_name, &property_info.plot_no).is_err() {
+        issues.push_back(IndexIssue::MissingEstatePlot);
+    }
+    if !get_estate_properties(env, &property_info.estate_name).contains(pvn) {
+        issues.push_back(IndexIssue::NotListedInEstate);
+    }
+    if !get_estate_company_properties(env, &property_info.estate_company_name).contains(pvn) {
+        issues.push_back(IndexIssue::NotListedForEstateCompany);
+    }
+    match (
+        get_nft_address_to_token_id(env, property_info.nft_address.clone()),
+        token_id,
+    ) {
+        (Err(_), _) => issues.push_back(IndexIssue::MissingNftAddress),
+        (Ok(nft_token_id), Ok(token_id)) if nft_token_id != token_id => {
+            issues.push_back(IndexIssue::NftAddressMismatch)
+        }
+        _ => {}
+    }
+    match get_nft_address_to_pvn(env, property_info.nft_address) {
+        Err(_) => issues.push_back(IndexIssue::MissingNftAddressPvn),
+        Ok(nft_pvn) if nft_pvn != pvn => issues.push_back(IndexIssue::NftAddressPvnMismatch),
+        Ok(_) => {}
+    }
+    if !get_owner_properties(env, &property_info.buyer_wallet_id).contains(pvn) {
+        issues.push_back(IndexIssue::NotListedForOwner);
+    }
+    issues
+}
+
+/// Points the PVN and NFT address of a listed property at the same token ID, reusing the
+/// token ID of the NFT address when no other property holds it, and the NFT address back
+/// at the PVN.
+fn repair_token_indices(
+    env: &Env,
+    property_info: &PropertyInfo,
+) -> Result<(), SytemapRegistryError> {
+    let pvn = property_info.property_verification_no;
+    let nft_token_id = get_nft_address_to_token_id(env, property_info.nft_address.clone()).ok();
+    // Only scan the registry when the address's own index has lost track of its holder
+    let nft_holder = nft_token_id.and_then(|token_id| {
+        get_nft_address_to_pvn(env, property_info.nft_address.clone())
+            .ok()
+            .filter(|holder| get_token_id_of_pvn(env, *holder) == Ok(token_id))
+            .or_else(|| find_pvn_by_token_id(env, token_id))
+    });
+
+    let token_id = match get_token_id_of_pvn(env, pvn) {
+        Ok(token_id) => token_id,
+        Err(_) => {
+            let token_id = match (nft_token_id, nft_holder) {
+                (Some(token_id), None) => token_id,
+                _ => get_next_token_id(env)?,
+            };
+            save_pvn_to_token_id(env, pvn, token_id)?;
+            token_id
+        }
+    };
+
+    if nft_token_id != Some(token_id) {
+        if let Some(holder) = nft_holder.filter(|holder| *holder != pvn) {
+            // Only take the address over if its holder has moved on to another one
+            if get_property_info(env, holder)
+                .is_ok_and(|holder_info| holder_info.nft_address == property_info.nft_address)
+            {
+                return Err(SytemapRegistryError::NftAddressAlreadyInUse);
+            }
+        }
+        save_nft_address_to_token_id(env, property_info.nft_address.clone(), token_id)?;
+    }
+    save_nft_address_to_pvn(env, property_info.nft_address.clone(), pvn);
+    Ok(())
+}
+
+/// Adds a property to the estate, estate company and owner indices it is missing from.
+fn repair_estate_indices(env: &Env, property_info: &PropertyInfo) {
+    let pvn = property_info.property_verification_no;
+    let (estate_name, plot_no) = (&property_info.estate_name, &property_info.plot_no);
+    if get_estate_plot_pvn(env, estate_name, plot_no).is_err() {
+        save_estate_plot_pvn(env, estate_name, plot_no, pvn);
+    }
+
+    let mut estate_properties = get_estate_properties(env, estate_name);
+    if !estate_properties.contains(pvn) {
+        estate_properties.push_back(pvn);
+        save_estate_properties(env, estate_name, &estate_properties);
+    }
+    let estate_company_name = &property_info.estate_company_name;
+    let mut company_properties = get_estate_company_properties(env, estate_company_name);
+    if !company_properties.contains(pvn) {
+        company_properties.push_back(pvn);
+        save_estate_company_properties(env, estate_company_name, &company_properties);
+    }
+    let owner = &property_info.buyer_wallet_id;
+    let mut owner_properties = get_owner_properties(env, owner);
+    if !owner_properties.contains(pvn) {
+        owner_properties.push_back(pvn);
+        save_owner_properties(env, owner, &owner_properties);
+    }
+}
+
+/// Reads the properties at positions `cursor` to `cursor + limit` of a PVN index.
+fn property_page(
+    env: &Env,
+    pvns: &Vec<u64>,
+    cursor: u32,
+    limit: u32,
+) -> Result<PropertyPage, SytemapRegistryError> {
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return Err(SytemapRegistryError::InvalidPageLimit);
+    }
+
+    let end = pvns.len().min(cursor.saturating_add(limit));
+    let mut properties = Vec::new(env);
+    for position in cursor..end {
+        if let Ok(property_info) = get_property_info(env, pvns.get_unchecked(position)) {
+            properties.push_back(property_info);
+        }
+    }
+
+    Ok(PropertyPage {
+        properties,
+        next_cursor: if end < pvns.len() { Some(end) } else { None },
+    })
+}
+
+/// Fails if the estate of the property requires KYC and `recipient` does not pass it.
+fn ensure_recipient_verified(
+    env: &Env,
+    property_info: &PropertyInfo,
+    recipient: &Address,
+) -> Result<(), SytemapRegistryError> {
+    if !is_recipient_verified(env, &property_info.estate_name, recipient) {
+        return Err(SytemapRegistryError::RecipientNotVerified);
+    }
+    Ok(())
+}
+
+/// Collects the lineage links reachable from `pvn` breadth first, following parents when
+/// `upward` and children otherwise. A plot reached along several paths, as happens once
+/// split plots are merged again, is expanded only once.
+fn lineage_links(env: &Env, pvn: u64, upward: bool) -> Vec<LineageLink> {
+    let mut links = Vec::new(env);
+    let mut queue = Vec::from_array(env, [pvn]);
+    let mut expanded = 0;
+    while expanded < queue.len() {
+        let current = queue.get_unchecked(expanded);
+        expanded += 1;
+
+        let next = if upward {
+            get_parent_pvns(env, current)
+        } else {
+            get_child_pvns(env, current)
+        };
+        for other in next.iter() {
+            links.push_back(if upward {
+                LineageLink {
+                    property_verification_no: current,
+                    parent_pvn: other,
+                }
+            } else {
+                LineageLink {
+                    property_verification_no: other,
+                    parent_pvn: current,
+                }
+            });
+            if !queue.contains(other) {
+                queue.push_back(other);
+            }
+        }
+    }
+    links
+}
+
+/// Fails unless the property is allocated to its owner free of liens, leases, pledges,
+/// disputes and installment plans. A plot whose liens have all expired stays
+/// `Encumbered` until `clear_expired_liens` settles it.
+fn ensure_unencumbered(
+    env: &Env,
+    property_info: &PropertyInfo,
+) -> Result<(), SytemapRegistryError> {
+    ensure_property_movable(env, property_info)?;
+    if property_info.status != PropertyStatus::Allocated
+        || get_lease(env, property_info.property_verification_no).is_some()
+    {
+        return Err(SytemapRegistryError::PropertyEncumbered);
+    }
+    Ok(())
+}
+
+/// Whether every corner of `inner` lies within the outline `outer`. An `outer` recorded
+/// as a single point or a line has no inside, so nothing lies within it. Returns `None`
+/// if either is not a location.
+fn outline_covers(outer: &String, inner: &String) -> Option<bool> {
+    let mut outline = [(0, 0); MAX_OUTLINE_POINTS];
+    let outline_len = parse_plot_outline(outer, &mut outline)?;
+    let mut corners = [(0, 0); MAX_OUTLINE_POINTS];
+    let corner_count = parse_plot_outline(inner, &mut corners)?;
+
+    Some(
+        outline_len >= 3
+            && corners[..corner_count]
+                .iter()
+                .all(|corner| outline_contains(&outline[..outline_len], *corner)),
+    )
+}
+
+/// Fails unless the child plots can be carved out of `parent`: at least two of them, all
+/// going to its owner in the same estate and recorded as outlines inside the parent's that
+/// do not overlap one another. Neither their areas nor their outlines may add up to more
+/// than the parent's.
+fn ensure_subdivision_fits(
+    parent: &PropertyInfo,
+    child_payloads: &Vec<PropertyInfoPayload>,
+) -> Result<(), SytemapRegistryError> {
+    if child_payloads.len() < 2 {
+        return Err(SytemapRegistryError::InvalidSubdivision);
+    }
+    let parent_area =
+        parse_plot_area(&parent.size_of_plot).ok_or(SytemapRegistryError::InvalidSubdivision)?;
+    let parent_outline_area = plot_outline_area(&parent.coordinate_of_plot)
+        .ok_or(SytemapRegistryError::InvalidSubdivision)?;
+
+    let mut area: u64 = 0;
+    let mut outline_area: i128 = 0;
+    for (position, payload) in child_payloads.iter().enumerate() {
+        if payload.buyer_wallet_id != parent.buyer_wallet_id
+            || payload.estate_name != parent.estate_name
+            || payload.estate_company_name != parent.estate_company_name
+        {
+            return Err(SytemapRegistryError::InvalidSubdivision);
+        }
+        area = parse_plot_area(&payload.size_of_plot)
+            .and_then(|child_area| area.checked_add(child_area))
+            .ok_or(SytemapRegistryError::InvalidSubdivision)?;
+        outline_area = plot_outline_area(&payload.coordinate_of_plot)
+            .and_then(|child_area| outline_area.checked_add(child_area))
+            .ok_or(SytemapRegistryError::InvalidSubdivision)?;
+
+        if outline_covers(&parent.coordinate_of_plot, &payload.coordinate_of_plot) != Some(true) {
+            return Err(SytemapRegistryError::InvalidSubdivision);
+        }
+        for sibling in child_payloads.iter().skip(position + 1) {
+            if plot_outlines_overlap(&payload.coordinate_of_plot, &sibling.coordinate_of_plot)
+                != Some(false)
+            {
+                return Err(SytemapRegistryError::InvalidSubdivision);
+            }
+        }
+    }
+    if area > parent_area || outline_area > parent_outline_area {
+        return Err(SytemapRegistryError::InvalidSubdivision);
+    }
+    Ok(())
+}
+
+/// Fails unless `sources` can be merged into the plot of `new_payload`: at least two
+/// distinct plots, all held by the recipient of the new plot in its estate and recorded as
+/// outlines that lie inside its outline. Both the areas and the outlines of the sources
+/// must add up to exactly those of the new plot, so no land is lost and no gap between
+/// the sources is swallowed.
+fn ensure_merge_fits(
+    pvns: &Vec<u64>,
+    sources: &Vec<PropertyInfo>,
+    new_payload: &PropertyInfoPayload,
+) -> Result<(), SytemapRegistryError> {
+    if pvns.len() < 2 {
+        return Err(SytemapRegistryError::InvalidMerge);
+    }
+    let new_outline_area = plot_outline_area(&new_payload.coordinate_of_plot)
+        .ok_or(SytemapRegistryError::InvalidMerge)?;
+
+    let mut area: u64 = 0;
+    let mut outline_area: i128 = 0;
+    for (position, source) in sources.iter().enumerate() {
+        if pvns.first_index_of(source.property_verification_no) != Some(position as u32)
+            || source.buyer_wallet_id != new_payload.buyer_wallet_id
+            || source.estate_name != new_payload.estate_name
+            || source.estate_company_name != new_payload.estate_company_name
+        {
+            return Err(SytemapRegistryError::InvalidMerge);
+        }
+        area = parse_plot_area(&source.size_of_plot)
+            .and_then(|source_area| area.checked_add(source_area))
+            .ok_or(SytemapRegistryError::InvalidMerge)?;
+        outline_area = plot_outline_area(&source.coordinate_of_plot)
+            .and_then(|source_area| outline_area.checked_add(source_area))
+            .ok_or(SytemapRegistryError::InvalidMerge)?;
+        if outline_covers(&new_payload.coordinate_of_plot, &source.coordinate_of_plot) != Some(true)
+        {
+            return Err(SytemapRegistryError::InvalidMerge);
+        }
+    }
+    match parse_plot_area(&new_payload.size_of_plot) {
+        Some(new_area) if new_area == area && new_outline_area == outline_area => Ok(()),
+        _ => Err(SytemapRegistryError::InvalidMerge),
+    }
+}
+
+/// Whether the outlines of two plots share any area. Returns `None` if either is not a
+/// location.
+fn plot_outlines_overlap(first: &String, second: &String) -> Option<bool> {
+    let mut first_outline = [(0, 0); MAX_OUTLINE_POINTS];
+    let first_len = parse_plot_outline(first, &mut first_outline)?;
+    let mut second_outline = [(0, 0); MAX_OUTLINE_POINTS];
+    let second_len = parse_plot_outline(second, &mut second_outline)?;
+    Some(outlines_overlap(
+        &first_outline[..first_len],
+        &second_outline[..second_len],
+    ))
+}
+
+/// Twice the area inside the outline of a plot, or `None` unless it is recorded as an
+/// outline of at least three corners.
+fn plot_outline_area(coordinate_of_plot: &String) -> Option<i128> {
+    let mut outline = [(0, 0); MAX_OUTLINE_POINTS];
+    let outline_len = parse_plot_outline(coordinate_of_plot, &mut outline)?;
+    if outline_len < 3 {
+        return None;
+    }
+    Some(doubled_outline_area(&outline[..outline_len]))
+}
+
+/// Fails if the PVN or NFT address of a payload is already registered.
+fn ensure_mintable(env: &Env, payload: &PropertyInfoPayload) -> Result<(), SytemapRegistryError> {
+    if check_pvn_exists(env, payload.property_verification_no) {
+        return Err(SytemapRegistryError::AlreadyMinted);
+    }
+    if check_nft_address_exists(env, payload.nft_address.clone()) {
+        return Err(SytemapRegistryError::NftAddressAlreadyInUse);
+    }
+    if get_estate_plot_pvn(env, &payload.estate_name, &payload.plot_no).is_ok() {
+        return Err(SytemapRegistryError::PlotNoAlreadyInUse);
+    }
+    Ok(())
+}
+
+/// Drops expired proposals from the pending list and from storage.
+fn prune_expired_mint_proposals(env: &Env, pending_mints: &mut Vec<u64>) {
+    let now = env.ledger().timestamp();
+    let mut live_mints = Vec::new(env);
+    for proposal_id in pending_mints.iter() {
+        match get_mint_proposal(env, proposal_id) {
+            Ok(proposal) if proposal.expires_at > now => live_mints.push_back(proposal_id),
+            _ => remove_mint_proposal(env, proposal_id),
+        }
+    }
+    *pending_mints = live_mints;
 }
@@ -1,69 +1,1120 @@
-use soroban_sdk::{Address, Env, String, Symbol};
+use crate::types::{DocumentType, IndexIssue, Lease, PropertyInfo, PropertyStatus, Role};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+/// Version of the event payloads below. Bump it whenever a payload changes shape.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+//********** Event Payloads **********//
+//
+// Every event is published under one of two topic layouts:
+// - property events - `["sytemap", event_name: Symbol, property_verification_no: u64]`
+// - registry events - `["sytemap", event_name: Symbol]`
+//
+// and carries one of the payloads below as its data.
+
+/// Data of the `initialized` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct InitializedEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub sytemap_name: String,
+    pub sytemap_symbol: String,
+    pub metadata_uri: String,
+}
+
+/// Data of the `metadata_uri_updated` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct MetadataUriUpdatedEvent {
+    pub version: u32,
+    pub old_metadata_uri: String,
+    pub new_metadata_uri: String,
+}
+
+/// Data of the `property_minted` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PropertyMintedEvent {
+    pub version: u32,
+    pub token_id: u64,
+    pub property: PropertyInfo,
+}
+
+/// Data of the `price_changed` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PriceChangedEvent {
+    pub version: u32,
+    pub owner: Address,
+    pub old_price: u64,
+    pub new_price: u64,
+}
+
+/// Data of the `ownership_transferred` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct OwnershipTransferredEvent {
+    pub version: u32,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// Data of the `status_changed` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct StatusChangedEvent {
+    pub version: u32,
+    pub old_status: PropertyStatus,
+    pub new_status: PropertyStatus,
+}
+
+/// Data of the `payment_plan_created` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PaymentPlanCreatedEvent {
+    pub version: u32,
+    pub seller: Address,
+    pub buyer: Address,
+    pub total_amount: i128,
+    pub installment_count: u32,
+}
+
+/// Data of the `installment_paid` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct InstallmentPaidEvent {
+    pub version: u32,
+    pub buyer: Address,
+    pub amount: i128,
+    pub amount_paid: i128,
+    pub outstanding: i128,
+}
+
+/// Data of the `payment_plan_completed` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PaymentPlanCompletedEvent {
+    pub version: u32,
+    pub buyer: Address,
+    pub total_amount: i128,
+}
+
+/// Data of the `payment_default_declared` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PaymentDefaultDeclaredEvent {
+    pub version: u32,
+    pub buyer: Address,
+    pub days_overdue: u64,
+    pub outstanding: i128,
+}
+
+/// Data of the `payment_default_cured` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PaymentDefaultCuredEvent {
+    pub version: u32,
+    pub buyer: Address,
+    pub amount_paid: i128,
+}
+
+/// Data of the `repossessed` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct RepossessedEvent {
+    pub version: u32,
+    pub seller: Address,
+    pub buyer: Address,
+    pub refunded: i128,
+    pub retained: i128,
+}
+
+/// Data of the `lien_registered` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LienRegisteredEvent {
+    pub version: u32,
+    pub lienholder: Address,
+    pub amount: i128,
+    pub expiry: u64,
+}
+
+/// Data of the `lease_created` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LeaseCreatedEvent {
+    pub version: u32,
+    pub tenant: Address,
+    pub start: u64,
+    pub end: u64,
+    pub rent: i128,
+    pub token: Address,
+}
+
+/// Data of the `lease_terminated` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LeaseTerminatedEvent {
+    pub version: u32,
+    pub tenant: Address,
+}
+
+/// Data of the `lien_released` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LienReleasedEvent {
+    pub version: u32,
+    pub lienholder: Address,
+}
+
+/// Data of the `lien_expired` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LienExpiredEvent {
+    pub version: u32,
+    pub lienholder: Address,
+    pub expiry: u64,
+}
+
+/// Data of the `lien_consented` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LienConsentedEvent {
+    pub version: u32,
+    pub lienholder: Address,
+    pub transferee: Address,
+}
+
+/// Data of the `collateral_locked` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct CollateralLockedEvent {
+    pub version: u32,
+    pub owner: Address,
+    pub locker: Address,
+}
+
+/// Data of the `collateral_unlocked` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct CollateralUnlockedEvent {
+    pub version: u32,
+    pub locker: Address,
+}
+
+/// Data of the `liquidated` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct LiquidatedEvent {
+    pub version: u32,
+    pub locker: Address,
+    pub new_owner: Address,
+}
+
+/// Data of the `frozen` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct FrozenEvent {
+    pub version: u32,
+    pub arbitrator: Address,
+    pub case_ref: String,
+}
+
+/// Data of the `unfrozen` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct UnfrozenEvent {
+    pub version: u32,
+    pub arbitrator: Address,
+    pub case_ref: String,
+    pub resolution: String,
+}
+
+/// Data of the `dispute_transfer` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct DisputeTransferEvent {
+    pub version: u32,
+    pub arbitrator: Address,
+    pub case_ref: String,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// Data of the `mint_proposed` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct MintProposedEvent {
+    pub version: u32,
+    pub proposal_id: u64,
+    pub proposer: Address,
+}
+
+/// Data of the `mint_approved` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct MintApprovedEvent {
+    pub version: u32,
+    pub proposal_id: u64,
+    pub verifier: Address,
+    pub approvals: u32,
+}
+
+/// Data of the `document_attached` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct DocumentAttachedEvent {
+    pub version: u32,
+    pub doc_type: DocumentType,
+    pub sha256_hash: BytesN<32>,
+    pub uri: String,
+    pub attached_by: Address,
+}
+
+/// Data of the `role_granted` and `role_revoked` registry events
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct RoleChangedEvent {
+    pub version: u32,
+    pub role: Role,
+    pub account: Address,
+}
+
+/// Data of the `estate_company_bound` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct EstateCompanyBoundEvent {
+    pub version: u32,
+    pub estate_company_name: String,
+    pub account: Address,
+}
+
+/// Data of the `mint_config_updated` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct MintConfigUpdatedEvent {
+    pub version: u32,
+    pub threshold: u32,
+    pub proposal_ttl: u64,
+}
+
+/// Data of the `kyc_status_updated` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct KycStatusUpdatedEvent {
+    pub version: u32,
+    pub account: Address,
+    pub level: u32,
+    pub expiry: u64,
+    pub updated_by: Address,
+}
+
+/// Data of the `estate_kyc_updated` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct EstateKycUpdatedEvent {
+    pub version: u32,
+    pub estate_name: String,
+    pub min_level: u32,
+}
+
+/// Data of the `kyc_provider_updated` registry event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct KycProviderUpdatedEvent {
+    pub version: u32,
+    pub provider: Option<Address>,
+}
+
+/// Data of the `paused` and `unpaused` registry events
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PauseChangedEvent {
+    pub version: u32,
+    pub pauser: Address,
+}
+
+/// Data of the `estate_paused` and `estate_unpaused` registry events
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct EstatePauseChangedEvent {
+    pub version: u32,
+    pub estate_name: String,
+    pub pauser: Address,
+}
+
+/// Data of the `index_repaired` property event
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct IndexRepairedEvent {
+    pub version: u32,
+    pub issues: Vec<IndexIssue>,
+}
+
+/// Data of the `plots_merged` property event, published under the PVN of the merged plot
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PlotsMergedEvent {
+    pub version: u32,
+    pub owner: Address,
+    pub verifier: Address,
+    pub source_pvns: Vec<u64>,
+}
+
+/// Data of the `subdivided` property event, published under the PVN of the parent plot
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct SubdividedEvent {
+    pub version: u32,
+    pub owner: Address,
+    pub verifier: Address,
+    pub child_pvns: Vec<u64>,
+}
+
+/// Publishes an event about a single property.
+fn publish_property_event<D>(env: &Env, event_name: &str, property_verification_no: u64, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    let topics = (
+        symbol_short!("sytemap"),
+        Symbol::new(env, event_name),
+        property_verification_no,
+    );
+
+    env.events().publish(topics, data);
+}
+
+/// Publishes an event about the registry as a whole.
+fn publish_registry_event<D>(env: &Env, event_name: &str, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    let topics = (symbol_short!("sytemap"), Symbol::new(env, event_name));
+
+    env.events().publish(topics, data);
+}
 
 pub struct SytemapRegistryEvents {}
 
 impl SytemapRegistryEvents {
+    /// Emitted once when the registry is initialized
+    pub fn initialized(
+        env: &Env,
+        admin: Address,
+        sytemap_name: String,
+        sytemap_symbol: String,
+        metadata_uri: String,
+    ) {
+        publish_registry_event(
+            env,
+            "initialized",
+            InitializedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                admin,
+                sytemap_name,
+                sytemap_symbol,
+                metadata_uri,
+            },
+        );
+    }
+
+    /// Emitted when the admin points the registry at a new metadata URI
+    pub fn metadata_uri_updated(env: &Env, old_metadata_uri: String, new_metadata_uri: String) {
+        publish_registry_event(
+            env,
+            "metadata_uri_updated",
+            MetadataUriUpdatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                old_metadata_uri,
+                new_metadata_uri,
+            },
+        );
+    }
+
     /// Emitted when a property info is minted
-    /// - topics - `["new_property_info_added", plot_no: u32]`
-    /// - data - `[plot_no: String, property_verification_no: String, token_id: u32, timestamp: u64, price_of_plot: u64]`
-    pub fn property_info_created(
-        env: &Env,
-        plot_no: String,
-        property_verification_no: u64,
-        token_id: u64,
-        timestamp: u64,
-        price_of_plot: u64,
-        buyer_wallet_id: Address,
-        token_url: String,
-        estate_name: String,
-        size_of_plot: String,
-        plot_url: String,
-        date_of_allocation: String,
-        coordinate_of_plot: String,
-        estate_company_name: String,
-    ) {
-        let topics = (Symbol::new(&env, "new_property_info_added"), plot_no.clone());
-
-        env.events().publish(
-            topics,
-            (
-            plot_no,
-            property_verification_no,
-            token_id,
-            timestamp,
-            price_of_plot,
-            buyer_wallet_id,
-            token_url,
-            estate_name,
-            size_of_plot,
-            plot_url,
-            date_of_allocation,
-            coordinate_of_plot,
-            estate_company_name,
-        ));
+    pub fn property_info_created(env: &Env, token_id: u64, property: PropertyInfo) {
+        publish_property_event(
+            env,
+            "property_minted",
+            property.property_verification_no,
+            PropertyMintedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                token_id,
+                property,
+            },
+        );
     }
 
     /// Emitted when a property price is changed
-    ///
-    /// - topics - `["property_info_price_changed", property_verification_no: u32]`
-    /// - data - (["owner:Address",property_verification_no:u64,  ])
-    pub fn property_price_changed(env: &Env, owner:Address, property_verification_no: u64, new_price: u64)  {
-        let topics = (Symbol::new(&env, "property_info_price_changed"), property_verification_no.clone());
+    pub fn property_price_changed(
+        env: &Env,
+        owner: Address,
+        property_verification_no: u64,
+        old_price: u64,
+        new_price: u64,
+    ) {
+        publish_property_event(
+            env,
+            "price_changed",
+            property_verification_no,
+            PriceChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                owner,
+                old_price,
+                new_price,
+            },
+        );
+    }
+
+    /// Emitted when the ownership of a property is transferred
+    pub fn property_ownership_transferred(
+        env: &Env,
+        property_verification_no: u64,
+        from: Address,
+        to: Address,
+    ) {
+        publish_property_event(
+            env,
+            "ownership_transferred",
+            property_verification_no,
+            OwnershipTransferredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                from,
+                to,
+            },
+        );
+    }
+
+    /// Emitted when the status of a property changes
+    pub fn property_status_changed(
+        env: &Env,
+        property_verification_no: u64,
+        old_status: PropertyStatus,
+        new_status: PropertyStatus,
+    ) {
+        publish_property_event(
+            env,
+            "status_changed",
+            property_verification_no,
+            StatusChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                old_status,
+                new_status,
+            },
+        );
+    }
+
+    /// Emitted when an installment plan is attached to a property
+    pub fn payment_plan_created(
+        env: &Env,
+        property_verification_no: u64,
+        seller: Address,
+        buyer: Address,
+        total_amount: i128,
+        installment_count: u32,
+    ) {
+        publish_property_event(
+            env,
+            "payment_plan_created",
+            property_verification_no,
+            PaymentPlanCreatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                seller,
+                buyer,
+                total_amount,
+                installment_count,
+            },
+        );
+    }
 
-    env.events()
-        .publish(topics, (owner, property_verification_no, new_price));
+    /// Emitted when an installment is paid into escrow
+    pub fn installment_paid(
+        env: &Env,
+        property_verification_no: u64,
+        buyer: Address,
+        amount: i128,
+        amount_paid: i128,
+        outstanding: i128,
+    ) {
+        publish_property_event(
+            env,
+            "installment_paid",
+            property_verification_no,
+            InstallmentPaidEvent {
+                version: EVENT_SCHEMA_VERSION,
+                buyer,
+                amount,
+                amount_paid,
+                outstanding,
+            },
+        );
     }
 
+    /// Emitted when an installment plan is fully paid and the title is released to the buyer
+    pub fn payment_plan_completed(
+        env: &Env,
+        property_verification_no: u64,
+        buyer: Address,
+        total_amount: i128,
+    ) {
+        publish_property_event(
+            env,
+            "payment_plan_completed",
+            property_verification_no,
+            PaymentPlanCompletedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                buyer,
+                total_amount,
+            },
+        );
+    }
 
-pub fn emit_base_uri_updated_event(
-    env: &Env,
-    old_base_uri: String,
-    new_base_uri: String,
-) {
-    let topics = (Symbol::new(&env, "base_URI_updated"), new_base_uri.clone());
+    /// Emitted when an installment plan is declared in default
+    pub fn payment_default_declared(
+        env: &Env,
+        property_verification_no: u64,
+        buyer: Address,
+        days_overdue: u64,
+        outstanding: i128,
+    ) {
+        publish_property_event(
+            env,
+            "payment_default_declared",
+            property_verification_no,
+            PaymentDefaultDeclaredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                buyer,
+                days_overdue,
+                outstanding,
+            },
+        );
+    }
 
-    env.events()
-        .publish(topics, (old_base_uri, new_base_uri));
-}
+    /// Emitted when a buyer in default catches up with the schedule
+    pub fn payment_default_cured(
+        env: &Env,
+        property_verification_no: u64,
+        buyer: Address,
+        amount_paid: i128,
+    ) {
+        publish_property_event(
+            env,
+            "payment_default_cured",
+            property_verification_no,
+            PaymentDefaultCuredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                buyer,
+                amount_paid,
+            },
+        );
+    }
+
+    /// Emitted when the estate company repossesses a plot in default
+    pub fn property_repossessed(
+        env: &Env,
+        property_verification_no: u64,
+        seller: Address,
+        buyer: Address,
+        refunded: i128,
+        retained: i128,
+    ) {
+        publish_property_event(
+            env,
+            "repossessed",
+            property_verification_no,
+            RepossessedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                seller,
+                buyer,
+                refunded,
+                retained,
+            },
+        );
+    }
+
+    /// Emitted when a lien is registered against a property
+    pub fn lien_registered(
+        env: &Env,
+        property_verification_no: u64,
+        lienholder: Address,
+        amount: i128,
+        expiry: u64,
+    ) {
+        publish_property_event(
+            env,
+            "lien_registered",
+            property_verification_no,
+            LienRegisteredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                lienholder,
+                amount,
+                expiry,
+            },
+        );
+    }
+
+    /// Emitted when an owner leases a property to a tenant
+    pub fn lease_created(env: &Env, property_verification_no: u64, lease: &Lease) {
+        publish_property_event(
+            env,
+            "lease_created",
+            property_verification_no,
+            LeaseCreatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                tenant: lease.tenant.clone(),
+                start: lease.start,
+                end: lease.end,
+                rent: lease.rent,
+                token: lease.token.clone(),
+            },
+        );
+    }
+
+    /// Emitted when a tenant gives up a lease before its term ends
+    pub fn lease_terminated(env: &Env, property_verification_no: u64, tenant: Address) {
+        publish_property_event(
+            env,
+            "lease_terminated",
+            property_verification_no,
+            LeaseTerminatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                tenant,
+            },
+        );
+    }
+
+    /// Emitted when a lienholder releases its lien
+    pub fn lien_released(env: &Env, property_verification_no: u64, lienholder: Address) {
+        publish_property_event(
+            env,
+            "lien_released",
+            property_verification_no,
+            LienReleasedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                lienholder,
+            },
+        );
+    }
+
+    /// Emitted when a lien past its expiry is cleared from a property
+    pub fn lien_expired(
+        env: &Env,
+        property_verification_no: u64,
+        lienholder: Address,
+        expiry: u64,
+    ) {
+        publish_property_event(
+            env,
+            "lien_expired",
+            property_verification_no,
+            LienExpiredEvent {
+                version: EVENT_SCHEMA_VERSION,
+                lienholder,
+                expiry,
+            },
+        );
+    }
+
+    /// Emitted when a lienholder consents to the property moving to a new owner
+    pub fn lien_transfer_consented(
+        env: &Env,
+        property_verification_no: u64,
+        lienholder: Address,
+        transferee: Address,
+    ) {
+        publish_property_event(
+            env,
+            "lien_consented",
+            property_verification_no,
+            LienConsentedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                lienholder,
+                transferee,
+            },
+        );
+    }
+
+    /// Emitted when a property is locked as collateral
+    pub fn property_locked(
+        env: &Env,
+        property_verification_no: u64,
+        owner: Address,
+        locker: Address,
+    ) {
+        publish_property_event(
+            env,
+            "collateral_locked",
+            property_verification_no,
+            CollateralLockedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                owner,
+                locker,
+            },
+        );
+    }
+
+    /// Emitted when a collateral lock is released
+    pub fn property_unlocked(env: &Env, property_verification_no: u64, locker: Address) {
+        publish_property_event(
+            env,
+            "collateral_unlocked",
+            property_verification_no,
+            CollateralUnlockedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                locker,
+            },
+        );
+    }
+
+    /// Emitted when a locker liquidates a property held as collateral
+    pub fn property_liquidated(
+        env: &Env,
+        property_verification_no: u64,
+        locker: Address,
+        new_owner: Address,
+    ) {
+        publish_property_event(
+            env,
+            "liquidated",
+            property_verification_no,
+            LiquidatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                locker,
+                new_owner,
+            },
+        );
+    }
+
+    /// Emitted when an arbitrator freezes a disputed property
+    pub fn property_frozen(
+        env: &Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        case_ref: String,
+    ) {
+        publish_property_event(
+            env,
+            "frozen",
+            property_verification_no,
+            FrozenEvent {
+                version: EVENT_SCHEMA_VERSION,
+                arbitrator,
+                case_ref,
+            },
+        );
+    }
+
+    /// Emitted when an arbitrator lifts the freeze on a property
+    pub fn property_unfrozen(
+        env: &Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        case_ref: String,
+        resolution: String,
+    ) {
+        publish_property_event(
+            env,
+            "unfrozen",
+            property_verification_no,
+            UnfrozenEvent {
+                version: EVENT_SCHEMA_VERSION,
+                arbitrator,
+                case_ref,
+                resolution,
+            },
+        );
+    }
+
+    /// Emitted when an arbitrator forces the transfer of a disputed property
+    pub fn dispute_transfer_resolved(
+        env: &Env,
+        property_verification_no: u64,
+        arbitrator: Address,
+        case_ref: String,
+        from: Address,
+        to: Address,
+    ) {
+        publish_property_event(
+            env,
+            "dispute_transfer",
+            property_verification_no,
+            DisputeTransferEvent {
+                version: EVENT_SCHEMA_VERSION,
+                arbitrator,
+                case_ref,
+                from,
+                to,
+            },
+        );
+    }
+
+    /// Emitted when a property is submitted for verifier approval
+    pub fn mint_proposed(
+        env: &Env,
+        proposal_id: u64,
+        proposer: Address,
+        property_verification_no: u64,
+    ) {
+        publish_property_event(
+            env,
+            "mint_proposed",
+            property_verification_no,
+            MintProposedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                proposal_id,
+                proposer,
+            },
+        );
+    }
+
+    /// Emitted when a verifier approves a mint proposal
+    pub fn mint_approved(
+        env: &Env,
+        proposal_id: u64,
+        property_verification_no: u64,
+        verifier: Address,
+        approvals: u32,
+    ) {
+        publish_property_event(
+            env,
+            "mint_approved",
+            property_verification_no,
+            MintApprovedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                proposal_id,
+                verifier,
+                approvals,
+            },
+        );
+    }
+
+    /// Emitted when a document fingerprint is anchored to a property
+    pub fn document_attached(
+        env: &Env,
+        property_verification_no: u64,
+        doc_type: DocumentType,
+        sha256_hash: BytesN<32>,
+        uri: String,
+        attached_by: Address,
+    ) {
+        publish_property_event(
+            env,
+            "document_attached",
+            property_verification_no,
+            DocumentAttachedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                doc_type,
+                sha256_hash,
+                uri,
+                attached_by,
+            },
+        );
+    }
+
+    /// Emitted when the admin grants a role
+    pub fn role_granted(env: &Env, role: Role, account: Address) {
+        publish_registry_event(
+            env,
+            "role_granted",
+            RoleChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                role,
+                account,
+            },
+        );
+    }
+
+    /// Emitted when the admin revokes a role
+    pub fn role_revoked(env: &Env, role: Role, account: Address) {
+        publish_registry_event(
+            env,
+            "role_revoked",
+            RoleChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                role,
+                account,
+            },
+        );
+    }
+
+    /// Emitted when the admin binds an account to an estate company name
+    pub fn estate_company_bound(env: &Env, estate_company_name: String, account: Address) {
+        publish_registry_event(
+            env,
+            "estate_company_bound",
+            EstateCompanyBoundEvent {
+                version: EVENT_SCHEMA_VERSION,
+                estate_company_name,
+                account,
+            },
+        );
+    }
+
+    /// Emitted when the admin changes the mint approval threshold or proposal lifetime
+    pub fn mint_approval_config_updated(env: &Env, threshold: u32, proposal_ttl: u64) {
+        publish_registry_event(
+            env,
+            "mint_config_updated",
+            MintConfigUpdatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                threshold,
+                proposal_ttl,
+            },
+        );
+    }
+
+    /// Emitted when a compliance officer records the KYC result of an address
+    pub fn kyc_status_updated(
+        env: &Env,
+        account: Address,
+        level: u32,
+        expiry: u64,
+        updated_by: Address,
+    ) {
+        publish_registry_event(
+            env,
+            "kyc_status_updated",
+            KycStatusUpdatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                account,
+                level,
+                expiry,
+                updated_by,
+            },
+        );
+    }
+
+    /// Emitted when an estate opts in or out of KYC checks
+    pub fn estate_kyc_level_updated(env: &Env, estate_name: String, min_level: u32) {
+        publish_registry_event(
+            env,
+            "estate_kyc_updated",
+            EstateKycUpdatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                estate_name,
+                min_level,
+            },
+        );
+    }
+
+    /// Emitted when the admin sets or clears the external KYC contract
+    pub fn kyc_provider_updated(env: &Env, provider: Option<Address>) {
+        publish_registry_event(
+            env,
+            "kyc_provider_updated",
+            KycProviderUpdatedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                provider,
+            },
+        );
+    }
+
+    /// Emitted when the registry is paused
+    pub fn contract_paused(env: &Env, pauser: Address) {
+        publish_registry_event(
+            env,
+            "paused",
+            PauseChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                pauser,
+            },
+        );
+    }
+
+    /// Emitted when the registry resumes after a pause
+    pub fn contract_unpaused(env: &Env, pauser: Address) {
+        publish_registry_event(
+            env,
+            "unpaused",
+            PauseChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                pauser,
+            },
+        );
+    }
+
+    /// Emitted when a single estate is paused
+    pub fn estate_paused(env: &Env, estate_name: String, pauser: Address) {
+        publish_registry_event(
+            env,
+            "estate_paused",
+            EstatePauseChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                estate_name,
+                pauser,
+            },
+        );
+    }
+
+    /// Emitted when a single estate resumes after a pause
+    pub fn estate_unpaused(env: &Env, estate_name: String, pauser: Address) {
+        publish_registry_event(
+            env,
+            "estate_unpaused",
+            EstatePauseChangedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                estate_name,
+                pauser,
+            },
+        );
+    }
+
+    /// Emitted when the admin repairs the indices of a property
+    pub fn index_repaired(env: &Env, property_verification_no: u64, issues: Vec<IndexIssue>) {
+        publish_property_event(
+            env,
+            "index_repaired",
+            property_verification_no,
+            IndexRepairedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                issues,
+            },
+        );
+    }
+
+    /// Emitted when a plot is split into child plots
+    pub fn subdivided(
+        env: &Env,
+        property_verification_no: u64,
+        owner: Address,
+        verifier: Address,
+        child_pvns: Vec<u64>,
+    ) {
+        publish_property_event(
+            env,
+            "subdivided",
+            property_verification_no,
+            SubdividedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                owner,
+                verifier,
+                child_pvns,
+            },
+        );
+    }
 
-}
\ No newline at end of file
+    /// Emitted when neighbouring plots are merged into one
+    pub fn plots_merged(
+        env: &Env,
+        property_verification_no: u64,
+        owner: Address,
+        verifier: Address,
+        source_pvns: Vec<u64>,
+    ) {
+        publish_property_event(
+            env,
+            "plots_merged",
+            property_verification_no,
+            PlotsMergedEvent {
+                version: EVENT_SCHEMA_VERSION,
+                owner,
+                verifier,
+                source_pvns,
+            },
+        );
+    }
+}
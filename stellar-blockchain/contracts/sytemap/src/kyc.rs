@@ -0,0 +1,8 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface an external KYC contract must expose to be used as the registry KYC provider.
+#[contractclient(name = "KycProviderClient")]
+pub trait KycProviderInterface {
+    /// Returns true when `account` has passed KYC with the provider.
+    fn is_kyc_verified(env: Env, account: Address) -> bool;
+}
@@ -6,6 +6,7 @@ pub mod storage;
 pub mod types;
 pub mod util;
 pub mod events;
+pub mod kyc;
 pub mod test;
 
 
@@ -1,19 +1,47 @@
-use soroban_sdk::{contracttype, String};
+use crate::types::Role;
+use soroban_sdk::{contracttype, Address, String};
 
 //********** Storage Keys **********//
 
 #[contracttype]
 pub enum SytemapDataKeys {
-    // A map of token id to PropertyVerificationNoToTokenId
+    // A map of token id to PropertyVerificationNoToTokenId, kept in persistent storage
     PropertyVerificationNoToTokenId(u64),
-    NftAddressToTokenId(String), // For nftAddress to token ID
-    // A map property verification number to PropertyInfo
+    NftAddressToTokenId(String), // For nftAddress to token ID, kept in persistent storage
+    NftAddressToPvn(String), // PVN of the property holding an NFT address, kept in persistent storage
+    // A map property verification number to PropertyInfo, kept in persistent storage
     PvnToPropertyInfo(u64),
     SystemName,
     SystemSymbol,
     PropertyInfo(u64),
     TokenMetadata,
-    AllProperties, // New key to track all properties
+    AllProperties, // New key to track all properties, kept in persistent storage
     NextTokenId, // Key to track the next token ID
+    PaymentPlan(u64), // Installment plan keyed by property verification number, kept in persistent storage
+    Liens(u64), // Liens registered against a property verification number, kept in persistent storage
+    CollateralLocker(u64), // Contract holding a property as collateral, kept in persistent storage
+    Admin,
+    Role(Role, Address), // Whether an address holds a role
+    EstateCompanyAccount(String), // Account acting for an estate company, by company name
+    Dispute(u64), // Open dispute freezing a property, kept in persistent storage
+    MintApprovalThreshold, // Number of verifier approvals needed to mint
+    MintProposalTtl, // Seconds a mint proposal stays open
+    NextMintProposalId,
+    MintProposal(u64), // Kept in persistent storage
+    PendingMints, // Ids of mint proposals awaiting approval, kept in persistent storage
+    Documents(u64), // Documents anchored to a property verification number, kept in persistent storage
+    Kyc(Address), // KYC result of an address, kept in persistent storage
+    KycProvider, // Optional external KYC contract
+    EstateKycLevel(String), // Minimum KYC level an estate requires of recipients
+    Paused, // Set while the whole registry is paused
+    PausedEstate(String), // Set while a single estate is paused
+    EstatePlot(String, String), // PVN of the plot with a given plot number in an estate, kept in persistent storage
+    EstateProperties(String), // PVNs minted in an estate, kept in persistent storage
+    EstateCompanyProperties(String), // PVNs minted by an estate company, kept in persistent storage
+    EstateTotals(String), // Running sale totals of an estate, kept in persistent storage
+    OwnerProperties(Address), // PVNs held by an address, kept in persistent storage
+    ParentPvns(u64), // PVNs of the plots a plot was subdivided or merged from, kept in persistent storage
+    ChildPvns(u64), // PVNs of the plots a plot was subdivided or merged into, kept in persistent storage
+    Lease(u64), // Tenancy on a property verification number, kept apart from its owner in persistent storage
 
 }
@@ -2,6 +2,7 @@
 extern crate std;
 
 use crate::error::*;
+use crate::events::*;
 use crate::types::*;
 use crate::util::*;
 use crate::contract::{SytemapRegistry, SytemapRegistryClient};
@@ -9,7 +10,60 @@ use crate::contract::{SytemapRegistry, SytemapRegistryClient};
 // use super::DaoContract;
 // use super::SytemapRegistryClient;
 
-use soroban_sdk::{Address, Env, String};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{
+    symbol_short, token, vec, Address, BytesN, Env, IntoVal, String, Symbol, TryFromVal,
+};
+
+mod mock_lending {
+    use crate::contract::SytemapRegistryClient;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Minimal lending protocol that takes registry plots as collateral
+    #[contract]
+    pub struct MockLendingContract;
+
+    #[contractimpl]
+    impl MockLendingContract {
+        pub fn borrow(env: Env, registry: Address, property_verification_no: u64) {
+            SytemapRegistryClient::new(&env, &registry)
+                .lock_as_collateral(&property_verification_no, &env.current_contract_address());
+        }
+
+        pub fn repay(env: Env, registry: Address, property_verification_no: u64) {
+            SytemapRegistryClient::new(&env, &registry).unlock(&property_verification_no);
+        }
+
+        pub fn liquidate(
+            env: Env,
+            registry: Address,
+            property_verification_no: u64,
+            new_owner: Address,
+        ) {
+            SytemapRegistryClient::new(&env, &registry)
+                .liquidate_to(&property_verification_no, &new_owner);
+        }
+    }
+}
+
+mod mock_kyc {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// External KYC provider keeping a plain allowlist
+    #[contract]
+    pub struct MockKycProvider;
+
+    #[contractimpl]
+    impl MockKycProvider {
+        pub fn approve(env: Env, account: Address) {
+            env.storage().instance().set(&account, &true);
+        }
+
+        pub fn is_kyc_verified(env: Env, account: Address) -> bool {
+            env.storage().instance().has(&account)
+        }
+    }
+}
 
 // Initialize the environment and contract
 fn get_client<'a>(e: &Env) -> SytemapRegistryClient<'a> {
@@ -17,76 +71,2313 @@ fn get_client<'a>(e: &Env) -> SytemapRegistryClient<'a> {
     contract_client
 }
 
+fn initialize(env: &Env, contract_client: &SytemapRegistryClient) -> Address {
+    let admin = Address::generate(env);
+    contract_client.initialize(
+        &admin,
+        &String::from_str(env, "Sytemap"),
+        &String::from_str(env, "SYM"),
+        &String::from_str(env, "http://metadata.url"),
+    );
+    admin
+}
+
+fn create_payload(
+    env: &Env,
+    property_verification_no: u64,
+    buyer_wallet_id: &Address,
+    nft_address: &str,
+) -> PropertyInfoPayload {
+    PropertyInfoPayload {
+        price_of_plot: 100_000,
+        property_verification_no,
+        buyer_wallet_id: buyer_wallet_id.clone(),
+        plot_no: String::from_str(env, &std::format!("Plot{property_verification_no}")),
+        size_of_plot: String::from_str(env, "10x10"),
+        coordinate_of_plot: String::from_str(env, "12.34, 56.78"),
+        token_url: String::from_str(env, "http://token.url"),
+        estate_name: String::from_str(env, "Estate XYZ"),
+        plot_url: String::from_str(env, "http://plot.url"),
+        date_of_allocation: String::from_str(env, "2024-08-01"),
+        estate_company_name: String::from_str(env, "Estate Co."),
+        nft_address: String::from_str(env, nft_address),
+    }
+}
+
+fn create_token<'a>(env: &Env, holder: &Address, amount: i128) -> token::Client<'a> {
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(holder, &amount);
+    token::Client::new(env, &token_address)
+}
+
 #[test]
 fn test_initialize_success() {
     let env: Env = Default::default();
     let contract_client = get_client(&env);
 
-    let token_metadata = TokenMetadata {
-        sytemap_name: String::from_str(&env, "Sytemap"),
-        sytemap_symbol: String::from_str(&env, "SYM"),
-        base_token_uri: String::from_str(&env, "http://metadata.url"),
+    // Call the `initialize` function
+    initialize(&env, &contract_client);
+
+    env.as_contract(&contract_client.address, || {
+        assert!(token_metadata_exists(&env));
+    });
+}
+
+#[test]
+fn test_initialize_already_initialized() {
+    let env: Env = Default::default();
+
+    let contract_client = get_client(&env);
+
+    // Initialize once
+    initialize(&env, &contract_client);
+
+    // Attempt to initialize again
+    let result = contract_client.try_initialize(
+        &Address::generate(&env),
+        &String::from_str(&env, "Sytemap"),
+        &String::from_str(&env, "SYM"),
+        &String::from_str(&env, "http://metadata.url"),
+    );
+
+    assert_eq!(
+        result.err(),
+        Some(Ok(SytemapRegistryError::AlreadyInitialized.into()))
+    );
+}
+#[test]
+fn test_safe_mint_new_property_info_success() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let buyer_wallet_id = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+
+    let payload = create_payload(&env, 12345, &buyer_wallet_id, "nft_address");
+
+    contract_client.safe_mint_new_property_info(&payload);
+
+    env.as_contract(&contract_client.address, || {
+        assert!(get_property_info_by_pvn(&env, 12345).is_ok());
+    });
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.status, PropertyStatus::Allocated);
+}
+
+#[test]
+fn test_transfer_property_by_owner() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    contract_client.transfer_property_by_owner(&12345, &new_owner);
+
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), new_owner);
+}
+
+#[test]
+fn test_payment_plan_locks_title_until_fully_paid() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_200);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 3,
+        interval: 30 * 86_400,
+        first_due_date: 30 * 86_400,
+        grace_period_days: 30,
+        notice_period_days: 14,
+        refund_percentage: 50,
     };
+    contract_client.create_payment_plan(&12345, &buyer, &1_200, &schedule);
+
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.status, PropertyStatus::InPayment);
+    assert_eq!(property_info.buyer_wallet_id, estate_company);
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &buyer),
+        Err(Ok(SytemapRegistryError::PropertyNotTransferable))
+    );
+
+    let status = contract_client.pay_installment(&12345, &400);
+    assert_eq!(status.amount_paid, 400);
+    assert_eq!(status.outstanding, 800);
+    assert_eq!(status.next_due_date, 60 * 86_400);
+    assert_eq!(token.balance(&contract_client.address), 400);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), estate_company);
+
+    let status = contract_client.pay_installment(&12345, &800);
+    assert_eq!(status.state, PaymentState::Completed);
+    assert_eq!(token.balance(&contract_client.address), 0);
+    assert_eq!(token.balance(&estate_company), 1_200);
+
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.status, PropertyStatus::Allocated);
+    assert_eq!(property_info.buyer_wallet_id, buyer);
+}
 
-    // Call the `init` function
-    let result = contract_client.initialize(&token_metadata);
+#[test]
+fn test_payment_plan_rejects_terms_past_the_end_of_time() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_200);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 3,
+        interval: 30 * 86_400,
+        first_due_date: 30 * 86_400,
+        grace_period_days: 30,
+        notice_period_days: 14,
+        refund_percentage: 50,
+    };
+    let mut endless_notice = schedule.clone();
+    endless_notice.notice_period_days = u64::MAX;
+    let mut endless_grace = schedule.clone();
+    endless_grace.grace_period_days = MAX_PAYMENT_PERIOD_DAYS + 1;
+    let mut endless_interval = schedule.clone();
+    endless_interval.interval = u64::MAX / 2;
+    let mut last_due_overflows = schedule.clone();
+    last_due_overflows.first_due_date = u64::MAX - 30 * 86_400;
+    for schedule in [endless_notice, endless_grace, endless_interval, last_due_overflows] {
+        assert_eq!(
+            contract_client.try_create_payment_plan(&12345, &buyer, &1_200, &schedule).err(),
+            Some(Ok(SytemapRegistryError::InvalidPaymentPlan))
+        );
+    }
+    // A total whose refund share cannot be worked out
+    assert_eq!(
+        contract_client
+            .try_create_payment_plan(&12345, &buyer, &(MAX_PAYMENT_PLAN_TOTAL + 1), &schedule)
+            .err(),
+        Some(Ok(SytemapRegistryError::InvalidPaymentPlan))
+    );
+
+    // The longest notice period still lets the seller repossess once it has run
+    let mut longest_notice = schedule.clone();
+    longest_notice.notice_period_days = MAX_PAYMENT_PERIOD_DAYS;
+    contract_client.create_payment_plan(&12345, &buyer, &1_200, &longest_notice);
+    env.ledger().set_timestamp(61 * 86_400);
+    contract_client.declare_payment_default(&12345);
+    assert_eq!(
+        contract_client.try_repossess(&12345).err(),
+        Some(Ok(SytemapRegistryError::NoticePeriodNotElapsed))
+    );
+    env.ledger().set_timestamp((61 + MAX_PAYMENT_PERIOD_DAYS) * 86_400);
+    contract_client.repossess(&12345);
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::Allocated
+    );
+}
 
-    // assert!(result.is_ok());
-    assert_eq!(token_metadata_exists(&env), true);
+#[test]
+fn test_payment_status_overdue_from_ledger_time() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 4,
+        interval: 10 * 86_400,
+        first_due_date: 10 * 86_400,
+        grace_period_days: 30,
+        notice_period_days: 14,
+        refund_percentage: 50,
+    };
+    contract_client.create_payment_plan(&12345, &buyer, &1_000, &schedule);
+    assert_eq!(
+        contract_client.get_payment_status(&12345).state,
+        PaymentState::Current
+    );
+
+    env.ledger().set_timestamp(15 * 86_400);
+    let status = contract_client.get_payment_status(&12345);
+    assert_eq!(status.state, PaymentState::Overdue);
+    assert_eq!(status.amount_due, 250);
+    assert_eq!(status.days_overdue, 5);
+
+    contract_client.pay_installment(&12345, &250);
+    assert_eq!(
+        contract_client.get_payment_status(&12345).state,
+        PaymentState::Current
+    );
+    assert_eq!(
+        contract_client.try_pay_installment(&12345, &1_000).err(),
+        Some(Ok(SytemapRegistryError::InvalidPaymentAmount))
+    );
 }
 
 #[test]
-fn test_initialize_already_initialized() {
+fn test_default_and_repossession_refunds_buyer() {
     let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 4,
+        interval: 30 * 86_400,
+        first_due_date: 30 * 86_400,
+        grace_period_days: 10,
+        notice_period_days: 14,
+        refund_percentage: 40,
+    };
+    contract_client.create_payment_plan(&12345, &buyer, &1_000, &schedule);
+    contract_client.pay_installment(&12345, &250);
+
+    // Second installment falls due on day 60, still within the grace period on day 65
+    env.ledger().set_timestamp(65 * 86_400);
+    assert_eq!(
+        contract_client.try_declare_payment_default(&12345).err(),
+        Some(Ok(SytemapRegistryError::PaymentNotOverdue))
+    );
+    assert_eq!(
+        contract_client.try_repossess(&12345).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotInDefault))
+    );
+
+    env.ledger().set_timestamp(71 * 86_400);
+    let status = contract_client.declare_payment_default(&12345);
+    assert_eq!(status.state, PaymentState::InDefault);
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::InDefault
+    );
+
+    env.ledger().set_timestamp(80 * 86_400);
+    assert_eq!(
+        contract_client.try_repossess(&12345).err(),
+        Some(Ok(SytemapRegistryError::NoticePeriodNotElapsed))
+    );
+
+    env.ledger().set_timestamp(85 * 86_400);
+    contract_client.repossess(&12345);
+
+    assert_eq!(token.balance(&buyer), 750 + 100);
+    assert_eq!(token.balance(&estate_company), 150);
+    assert_eq!(token.balance(&contract_client.address), 0);
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.status, PropertyStatus::Allocated);
+    assert_eq!(property_info.buyer_wallet_id, estate_company);
+    assert_eq!(
+        contract_client.try_get_payment_status(&12345).err(),
+        Some(Ok(SytemapRegistryError::PaymentPlanNotFound))
+    );
+}
 
+#[test]
+fn test_paying_arrears_cures_default() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
     let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 2,
+        interval: 30 * 86_400,
+        first_due_date: 30 * 86_400,
+        grace_period_days: 5,
+        notice_period_days: 14,
+        refund_percentage: 0,
+    };
+    contract_client.create_payment_plan(&12345, &buyer, &1_000, &schedule);
+
+    env.ledger().set_timestamp(40 * 86_400);
+    contract_client.declare_payment_default(&12345);
+
+    let status = contract_client.pay_installment(&12345, &500);
+    assert_eq!(status.state, PaymentState::Current);
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::InPayment
+    );
+}
+
+#[test]
+fn test_lien_blocks_transfer_until_lienholder_consents() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let bank = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    contract_client.register_lien(&12345, &bank, &50_000, &(365 * 86_400));
+    assert_eq!(contract_client.get_encumbrances(&12345).len(), 1);
+    assert_eq!(
+        contract_client.try_register_lien(&12345, &bank, &10_000, &(365 * 86_400)).err(),
+        Some(Ok(SytemapRegistryError::LienAlreadyExists))
+    );
+
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &new_owner).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    contract_client.consent_to_lien_transfer(&12345, &bank, &new_owner);
+    contract_client.transfer_property_by_owner(&12345, &new_owner);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), new_owner);
+
+    // The lien stays with the plot and the consent has been used up
+    let liens = contract_client.get_encumbrances(&12345);
+    assert_eq!(liens.get_unchecked(0).consented_transferee, None);
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &owner).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    contract_client.release_lien(&12345, &bank);
+    assert_eq!(contract_client.get_encumbrances(&12345).len(), 0);
+    contract_client.transfer_property_by_owner(&12345, &owner);
+}
+
+#[test]
+fn test_expired_lien_no_longer_encumbers() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let bank = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    assert_eq!(
+        contract_client.try_register_lien(&12345, &bank, &50_000, &0).err(),
+        Some(Ok(SytemapRegistryError::InvalidLien))
+    );
+    contract_client.register_lien(&12345, &bank, &50_000, &(30 * 86_400));
+
+    env.ledger().set_timestamp(31 * 86_400);
+    assert_eq!(contract_client.get_encumbrances(&12345).len(), 0);
+    contract_client.transfer_property_by_owner(&12345, &new_owner);
+}
+
+#[test]
+fn test_collateral_lock_through_lending_contract() {
+    let env: Env = Default::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let contract_client = get_client(&env);
+    let lending_id = env.register_contract(None, mock_lending::MockLendingContract);
+    let lending_client = mock_lending::MockLendingContractClient::new(&env, &lending_id);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    lending_client.borrow(&contract_client.address, &12345);
+    assert!(contract_client.is_locked(&12345));
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &new_owner).err(),
+        Some(Ok(SytemapRegistryError::PropertyLocked))
+    );
+    // Nor can the pledged plot be let out from under the lender
+    let token = create_token(&env, &new_owner, 100);
+    assert_eq!(
+        contract_client.try_create_lease(&12345, &new_owner, &0, &(30 * 86_400), &100, &token.address).err(),
+        Some(Ok(SytemapRegistryError::PropertyLocked))
+    );
+
+    lending_client.repay(&contract_client.address, &12345);
+    assert!(!contract_client.is_locked(&12345));
+    assert_eq!(
+        contract_client.try_unlock(&12345).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotLocked))
+    );
+
+    lending_client.borrow(&contract_client.address, &12345);
+    lending_client.liquidate(&contract_client.address, &12345, &new_owner);
+    assert!(!contract_client.is_locked(&12345));
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), new_owner);
+}
+
+#[test]
+fn test_only_locker_can_unlock() {
+    let env: Env = Default::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let contract_client = get_client(&env);
+    let lending_id = env.register_contract(None, mock_lending::MockLendingContract);
+    let lending_client = mock_lending::MockLendingContractClient::new(&env, &lending_id);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+    lending_client.borrow(&contract_client.address, &12345);
+
+    // Stop mocking signatures: the owner calling the registry directly cannot release the lock
+    env.set_auths(&[]);
+    assert!(contract_client.try_unlock(&12345).is_err());
+    assert!(contract_client.try_liquidate_to(&12345, &owner).is_err());
+    assert!(contract_client.is_locked(&12345));
+}
+
+#[test]
+fn test_lease_runs_on_ledger_time_and_survives_a_sale() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let token = create_token(&env, &farmer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    contract_client.create_lease(&12345, &farmer, &(10 * 86_400), &(100 * 86_400), &400, &token.address);
+    assert_eq!(token.balance(&owner), 400);
+    assert_eq!(token.balance(&farmer), 600);
+    assert_eq!(
+        contract_client.try_create_lease(&12345, &farmer, &(10 * 86_400), &(100 * 86_400), &400, &token.address).err(),
+        Some(Ok(SytemapRegistryError::LeaseAlreadyExists))
+    );
+
+    // Not in force until its term starts
+    assert_eq!(contract_client.get_active_lease(&12345), None);
+    env.ledger().set_timestamp(10 * 86_400);
+    let lease = contract_client.get_active_lease(&12345).unwrap();
+    assert_eq!((lease.tenant.clone(), lease.granted_by.clone()), (farmer.clone(), owner.clone()));
+
+    // A sale carries the lease with it, and the tenant is not the owner
+    contract_client.transfer_property_by_owner(&12345, &new_owner);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), new_owner);
+    assert_eq!(contract_client.get_active_lease(&12345), Some(lease));
+
+    env.ledger().set_timestamp(100 * 86_400);
+    assert_eq!(contract_client.get_active_lease(&12345), None);
+    assert_eq!(
+        contract_client.try_terminate_lease(&12345).err(),
+        Some(Ok(SytemapRegistryError::LeaseNotFound))
+    );
+
+    // Once expired the plot can be let again, by its new owner
+    contract_client.create_lease(&12345, &farmer, &(100 * 86_400), &(200 * 86_400), &300, &token.address);
+    assert_eq!(token.balance(&new_owner), 300);
+}
+
+#[test]
+fn test_expired_lease_no_longer_blocks_subdivision_or_merge() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let farmer = Address::generate(&env);
+    let token = create_token(&env, &farmer, 1_000);
+    let whole = "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E";
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 1, &owner, "20x30", whole));
+    contract_client.create_lease(&1, &farmer, &(50 * 86_400), &(60 * 86_400), &100, &token.address);
+
+    let halves = vec![
+        &env,
+        create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E"),
+        create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E"),
+    ];
+    env.ledger().set_timestamp(59 * 86_400);
+    assert_eq!(
+        contract_client.try_subdivide(&1, &verifier, &halves).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    // Once its term is over the lease no longer stands in the way, without anyone ending it
+    env.ledger().set_timestamp(60 * 86_400);
+    assert_eq!(contract_client.get_active_lease(&1), None);
+    contract_client.subdivide(&1, &verifier, &halves);
+
+    let merged = create_child_payload(&env, 4, &owner, "600sqm", whole);
+    contract_client.create_lease(&3, &farmer, &(60 * 86_400), &(70 * 86_400), &100, &token.address);
+    assert_eq!(
+        contract_client.try_merge_plots(&vec![&env, 2, 3], &verifier, &merged).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+    env.ledger().set_timestamp(70 * 86_400);
+    contract_client.merge_plots(&vec![&env, 2, 3], &verifier, &merged);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&4).status, PropertyStatus::Allocated);
+}
+
+#[test]
+fn test_lease_terms_and_termination() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let developer = Address::generate(&env);
+    let token = create_token(&env, &developer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    contract_client.safe_mint_new_property_info(&create_child_payload(
+        &env, 1, &owner, "20x30", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E",
+    ));
+
+    env.ledger().set_timestamp(50 * 86_400);
+    for (tenant, start, end, rent) in [
+        (&developer, 50 * 86_400, 60 * 86_400, 0),
+        (&developer, 60 * 86_400, 60 * 86_400, 100),
+        (&developer, 10 * 86_400, 50 * 86_400, 100),
+        (&owner, 50 * 86_400, 60 * 86_400, 100),
+    ] {
+        assert_eq!(
+            contract_client.try_create_lease(&1, tenant, &start, &end, &rent, &token.address).err(),
+            Some(Ok(SytemapRegistryError::InvalidLease))
+        );
+    }
+    assert_eq!(
+        contract_client.try_create_lease(&2, &developer, &(50 * 86_400), &(60 * 86_400), &100, &token.address).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFound))
+    );
+
+    contract_client.create_lease(&1, &developer, &(50 * 86_400), &(60 * 86_400), &100, &token.address);
+
+    // The tenant's use-right stops the plot being carved up beneath them
+    let halves = vec![
+        &env,
+        create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E"),
+        create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E"),
+    ];
+    assert_eq!(
+        contract_client.try_subdivide(&1, &verifier, &halves).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    contract_client.terminate_lease(&1);
+    // Only the tenant signs to give up the lease
+    assert_eq!(env.auths().iter().map(|(address, _)| address.clone()).collect::<std::vec::Vec<_>>(), std::vec![developer.clone()]);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "lease_terminated"), 1u64).into_val(&env)
+    );
+    assert_eq!(LeaseTerminatedEvent::try_from_val(&env, &data).unwrap().tenant, developer);
+    assert_eq!(contract_client.get_active_lease(&1), None);
+
+    contract_client.subdivide(&1, &verifier, &halves);
+}
 
-    let token_metadata = TokenMetadata {
-        sytemap_name: String::from_str(&env, "Sytemap"),
-        sytemap_symbol: String::from_str(&env, "SYM"),
-        base_token_uri: String::from_str(&env, "http://metadata.url"),
+#[test]
+fn test_freeze_blocks_price_change_and_transfer() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let case_ref = String::from_str(&env, "FHC/ABJ/CS/123/2024");
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    assert_eq!(
+        contract_client.try_freeze_property(&12345, &arbitrator, &case_ref).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    contract_client.grant_role(&Role::Arbitrator, &arbitrator);
+    contract_client.freeze_property(&12345, &arbitrator, &case_ref);
+
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.status, PropertyStatus::Frozen);
+    assert_eq!(contract_client.get_dispute(&12345).case_ref, case_ref);
+    assert_eq!(
+        contract_client.try_change_property_price_by_owner(&12345, &150_000).err(),
+        Some(Ok(SytemapRegistryError::PropertyFrozen))
+    );
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &new_owner).err(),
+        Some(Ok(SytemapRegistryError::PropertyFrozen))
+    );
+
+    // Neither the owner nor the admin can move the plot out of the freeze
+    assert_eq!(
+        contract_client.try_submit_for_survey(&12345).err(),
+        Some(Ok(SytemapRegistryError::PropertyFrozen))
+    );
+    assert_eq!(
+        contract_client.try_revoke_property(&12345).err(),
+        Some(Ok(SytemapRegistryError::PropertyFrozen))
+    );
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&12345).status, PropertyStatus::Frozen);
+
+    contract_client.unfreeze_property(&12345, &arbitrator, &String::from_str(&env, "struck out"));
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::Allocated
+    );
+    contract_client.change_property_price_by_owner(&12345, &150_000);
+}
+
+#[test]
+fn test_dispute_transfer_checks_kyc_and_drops_the_old_owners_pledge() {
+    let env: Env = Default::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    let contract_client = get_client(&env);
+    let lending_id = env.register_contract(None, mock_lending::MockLendingContract);
+    let lending_client = mock_lending::MockLendingContractClient::new(&env, &lending_id);
+    let owner = Address::generate(&env);
+    let rightful_owner = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let officer = Address::generate(&env);
+    let bank = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let case_ref = String::from_str(&env, "FHC/ABJ/CS/123/2024");
+
+    let admin = initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Arbitrator, &arbitrator);
+    contract_client.grant_role(&Role::Compliance, &officer);
+    contract_client.set_kyc_status(&officer, &owner, &1, &(365 * 86_400));
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+    contract_client.set_estate_kyc_level(&admin, &String::from_str(&env, "Estate XYZ"), &1);
+
+    contract_client.register_lien(&12345, &bank, &50_000, &(365 * 86_400));
+    contract_client.consent_to_lien_transfer(&12345, &bank, &buyer);
+    lending_client.borrow(&contract_client.address, &12345);
+    contract_client.freeze_property(&12345, &arbitrator, &case_ref);
+
+    // The court's choice of owner still has to pass the estate's KYC
+    assert_eq!(
+        contract_client.try_resolve_dispute_transfer(&12345, &arbitrator, &rightful_owner).err(),
+        Some(Ok(SytemapRegistryError::RecipientNotVerified))
+    );
+    contract_client.set_kyc_status(&officer, &rightful_owner, &1, &(365 * 86_400));
+    contract_client.resolve_dispute_transfer(&12345, &arbitrator, &rightful_owner);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), rightful_owner);
+
+    // The lien stays with the land, but the old owner's pledge and consents are gone
+    assert!(!contract_client.is_locked(&12345));
+    let liens = contract_client.get_encumbrances(&12345);
+    assert_eq!(liens.len(), 1);
+    assert_eq!(liens.get_unchecked(0).consented_transferee, None);
+
+    contract_client.unfreeze_property(&12345, &arbitrator, &String::from_str(&env, "ordered"));
+    assert!(lending_client
+        .try_liquidate(&contract_client.address, &12345, &owner)
+        .is_err());
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), rightful_owner);
+}
+
+#[test]
+fn test_resolve_dispute_transfer_restores_previous_status() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let rightful_owner = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    let token = create_token(&env, &buyer, 1_000);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Arbitrator, &arbitrator);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &estate_company, "nft_address"));
+
+    let schedule = PaymentSchedule {
+        token: token.address.clone(),
+        installment_count: 2,
+        interval: 30 * 86_400,
+        first_due_date: 30 * 86_400,
+        grace_period_days: 5,
+        notice_period_days: 14,
+        refund_percentage: 0,
     };
+    contract_client.create_payment_plan(&12345, &buyer, &1_000, &schedule);
+
+    let case_ref = String::from_str(&env, "case-42");
+    contract_client.freeze_property(&12345, &arbitrator, &case_ref);
+    assert_eq!(
+        contract_client.try_pay_installment(&12345, &500).err(),
+        Some(Ok(SytemapRegistryError::PropertyFrozen))
+    );
+
+    contract_client.resolve_dispute_transfer(&12345, &arbitrator, &rightful_owner);
+    contract_client.unfreeze_property(&12345, &arbitrator, &String::from_str(&env, "title restored"));
+
+    let property_info = contract_client.get_property_info_details_by_pvn(&12345);
+    assert_eq!(property_info.buyer_wallet_id, rightful_owner);
+    assert_eq!(property_info.status, PropertyStatus::InPayment);
+    assert_eq!(
+        contract_client.try_get_dispute(&12345).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFrozen))
+    );
+}
 
-    // Initialize once
-    contract_client.initialize(&token_metadata).unwrap_err();
+#[test]
+fn test_mint_executes_after_threshold_of_distinct_verifiers() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let surveyor = Address::generate(&env);
+    let registrar = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &surveyor);
+    contract_client.grant_role(&Role::Verifier, &registrar);
+    contract_client.set_mint_approval_config(&2, &(3 * 86_400));
+
+    let payload = create_payload(&env, 12345, &buyer, "nft_address");
+    assert_eq!(
+        contract_client.try_safe_mint_new_property_info(&payload).err(),
+        Some(Ok(SytemapRegistryError::MintApprovalRequired))
+    );
+
+    // Proposals are limited to estate companies and verifiers
+    assert_eq!(
+        contract_client.try_propose_mint(&estate_company, &payload).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+
+    let proposal_id = contract_client.propose_mint(&estate_company, &payload);
+    assert_eq!(
+        contract_client.try_propose_mint(&estate_company, &payload).err(),
+        Some(Ok(SytemapRegistryError::MintProposalAlreadyExists))
+    );
+    assert_eq!(contract_client.get_pending_mints().len(), 1);
+
+    assert!(!contract_client.approve_mint(&proposal_id, &surveyor));
+    assert_eq!(
+        contract_client.try_approve_mint(&proposal_id, &surveyor).err(),
+        Some(Ok(SytemapRegistryError::AlreadyApproved))
+    );
+    assert_eq!(
+        contract_client.try_approve_mint(&proposal_id, &estate_company).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    assert_eq!(contract_client.get_number_of_property_minted(), 0);
+
+    assert!(contract_client.approve_mint(&proposal_id, &registrar));
+    assert_eq!(contract_client.get_number_of_property_minted(), 1);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), buyer);
+    assert_eq!(contract_client.get_pending_mints().len(), 0);
+}
 
-    // Attempt to initialize again
-    let result = contract_client.initialize(&token_metadata);
+#[test]
+fn test_expired_mint_proposal_cannot_be_approved() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let surveyor = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &surveyor);
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+    contract_client.set_mint_approval_config(&1, &86_400);
+
+    let payload = create_payload(&env, 12345, &buyer, "nft_address");
+    let proposal_id = contract_client.propose_mint(&estate_company, &payload);
+
+    env.ledger().set_timestamp(86_400);
+    assert_eq!(contract_client.get_pending_mints().len(), 0);
+    assert_eq!(
+        contract_client.try_approve_mint(&proposal_id, &surveyor).err(),
+        Some(Ok(SytemapRegistryError::MintProposalExpired))
+    );
+
+    // The same plot can be proposed again once the old proposal lapsed
+    let proposal_id = contract_client.propose_mint(&estate_company, &payload);
+    assert!(contract_client.approve_mint(&proposal_id, &surveyor));
+}
 
-    assert_eq!(result, Err(SytemapRegistryError::AlreadyInitialized));
+#[test]
+fn test_mint_proposal_ttl_is_bounded() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+    assert_eq!(
+        contract_client.try_set_mint_approval_config(&1, &(MAX_MINT_PROPOSAL_TTL + 1)).err(),
+        Some(Ok(SytemapRegistryError::InvalidMintProposalTtl))
+    );
+    contract_client.set_mint_approval_config(&1, &MAX_MINT_PROPOSAL_TTL);
+
+    // A lifetime stored before the bound existed cannot push the expiry past the end of time
+    env.as_contract(&contract_client.address, || {
+        env.storage()
+            .instance()
+            .set(&crate::storage::SytemapDataKeys::MintProposalTtl, &u64::MAX);
+    });
+    env.ledger().set_timestamp(86_400);
+    let payload = create_payload(&env, 12345, &buyer, "nft_address");
+    assert_eq!(
+        contract_client.try_propose_mint(&estate_company, &payload).err(),
+        Some(Ok(SytemapRegistryError::InvalidMintProposalTtl))
+    );
 }
+
 #[test]
-fn test_safe_mint_new_property_info_success() {
+fn test_pending_mint_proposals_are_capped() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+    let contract_client = get_client(&env);
+    let estate_company = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+    contract_client.set_mint_approval_config(&1, &86_400);
+
+    for pvn in 1..=MAX_PENDING_MINTS as u64 {
+        let nft_address = std::format!("nft_{pvn}");
+        contract_client.propose_mint(&estate_company, &create_payload(&env, pvn, &buyer, &nft_address));
+    }
+    let payload = create_payload(&env, 1_000, &buyer, "nft_1000");
+    assert_eq!(
+        contract_client.try_propose_mint(&estate_company, &payload).err(),
+        Some(Ok(SytemapRegistryError::TooManyPendingMints))
+    );
+
+    // Lapsed proposals free their slots
+    env.ledger().set_timestamp(86_400);
+    contract_client.propose_mint(&estate_company, &payload);
+    assert_eq!(contract_client.get_pending_mints().len(), 1);
+    let pending_mints: soroban_sdk::Vec<u64> = env.as_contract(&contract_client.address, || {
+        env.storage().persistent().get(&crate::storage::SytemapDataKeys::PendingMints).unwrap()
+    });
+    assert_eq!(pending_mints.len(), 1);
+}
+
+#[test]
+fn test_survey_lifecycle_transitions() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let surveyor = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &surveyor);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    assert_eq!(
+        contract_client.try_verify_property(&12345, &surveyor).err(),
+        Some(Ok(SytemapRegistryError::InvalidStateTransition))
+    );
+
+    contract_client.submit_for_survey(&12345);
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &surveyor).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotTransferable))
+    );
+    contract_client.reject_survey(&12345, &surveyor);
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::Draft
+    );
+    assert_eq!(
+        contract_client.try_allocate_property(&12345).err(),
+        Some(Ok(SytemapRegistryError::InvalidStateTransition))
+    );
+
+    contract_client.submit_for_survey(&12345);
+    contract_client.verify_property(&12345, &surveyor);
+    contract_client.allocate_property(&12345);
+    assert_eq!(
+        contract_client.get_property_info_details_by_pvn(&12345).status,
+        PropertyStatus::Allocated
+    );
+
+    contract_client.revoke_property(&12345);
+    assert_eq!(
+        contract_client.try_submit_for_survey(&12345).err(),
+        Some(Ok(SytemapRegistryError::InvalidStateTransition))
+    );
+}
+
+#[test]
+fn test_status_transition_table() {
+    use PropertyStatus::*;
+
+    let statuses = [
+        Draft, PendingSurvey, Verified, Allocated, Encumbered, InPayment, InDefault, Frozen, Revoked, Subdivided, Merged,
+    ];
+    // Rows are the current status and columns the new one, both in the order of `statuses`
+    let allowed = [
+        //D  PS V  A  E  IP ID F  R  S  M
+        [0, 1, 0, 0, 0, 0, 0, 1, 1, 0, 0], // Draft
+        [1, 0, 1, 0, 0, 0, 0, 1, 1, 0, 0], // PendingSurvey
+        [0, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0], // Verified
+        [0, 1, 0, 0, 1, 1, 0, 1, 1, 1, 1], // Allocated
+        [0, 0, 0, 1, 0, 1, 0, 1, 1, 0, 0], // Encumbered
+        [0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0], // InPayment
+        [0, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0], // InDefault
+        [1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0], // Frozen
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], // Revoked
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], // Subdivided
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], // Merged
+    ];
+    for (row, from) in statuses.iter().enumerate() {
+        for (column, to) in statuses.iter().enumerate() {
+            assert_eq!(
+                is_valid_status_transition(*from, *to),
+                allowed[row][column] == 1,
+                "{from:?} -> {to:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_liens_move_property_to_encumbered_and_back() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let bank = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 67890, &owner, "nft_address2"));
+
+    contract_client.register_lien(&12345, &bank, &50_000, &(365 * 86_400));
+    let encumbered = contract_client.get_properties_by_status(&PropertyStatus::Encumbered);
+    assert_eq!(encumbered.len(), 1);
+    assert_eq!(encumbered.get_unchecked(0).property_verification_no, 12345);
+    assert_eq!(
+        contract_client.get_properties_by_status(&PropertyStatus::Allocated).len(),
+        1
+    );
+
+    contract_client.release_lien(&12345, &bank);
+    assert_eq!(
+        contract_client.get_properties_by_status(&PropertyStatus::Allocated).len(),
+        2
+    );
+}
+
+#[test]
+fn test_expired_liens_are_cleared_and_the_plot_settles() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let bank = Address::generate(&env);
+    let lender = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    contract_client.safe_mint_new_property_info(&create_child_payload(
+        &env, 1, &owner, "20x30", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E",
+    ));
+    contract_client.register_lien(&1, &bank, &50_000, &(30 * 86_400));
+    contract_client.register_lien(&1, &lender, &20_000, &(90 * 86_400));
+    let halves = vec![
+        &env,
+        create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E"),
+        create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E"),
+    ];
+
+    // Only the bank's lien has run out, so the plot stays encumbered by the lender's
+    env.ledger().set_timestamp(31 * 86_400);
+    contract_client.clear_expired_liens(&1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "lien_expired"), 1u64).into_val(&env)
+    );
+    let expired = LienExpiredEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!((expired.lienholder, expired.expiry), (bank.clone(), 30 * 86_400));
+    assert_eq!(contract_client.get_encumbrances(&1).len(), 1);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Encumbered);
+    assert_eq!(
+        contract_client.try_subdivide(&1, &verifier, &halves).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    // Once every lien has expired the plot is stuck encumbered until someone clears them
+    env.ledger().set_timestamp(91 * 86_400);
+    assert_eq!(contract_client.get_encumbrances(&1).len(), 0);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Encumbered);
+    assert_eq!(
+        contract_client.try_subdivide(&1, &verifier, &halves).err(),
+        Some(Ok(SytemapRegistryError::PropertyEncumbered))
+    );
+
+    contract_client.clear_expired_liens(&1);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Allocated);
+    env.as_contract(&contract_client.address, || {
+        assert!(!env.storage().persistent().has(&crate::storage::SytemapDataKeys::Liens(1)));
+    });
+    // Clearing again finds nothing left to do
+    contract_client.clear_expired_liens(&1);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Allocated);
+
+    contract_client.subdivide(&1, &verifier, &halves);
+}
+
+#[test]
+fn test_attach_and_verify_documents() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let estate_company = Address::generate(&env);
+    let rival_company = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let survey_plan = BytesN::from_array(&env, &[1; 32]);
+    let revised_survey_plan = BytesN::from_array(&env, &[2; 32]);
+    let deed = BytesN::from_array(&env, &[3; 32]);
+    let uri = String::from_str(&env, "ipfs://survey-plan");
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+    contract_client.grant_role(&Role::EstateCompany, &rival_company);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    assert_eq!(
+        contract_client
+            .try_attach_document(&12345, &stranger, &DocumentType::SurveyPlan, &survey_plan, &uri)
+            .err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    // The role alone is not enough, the company has to be bound to the plot's company name
+    assert_eq!(
+        contract_client
+            .try_attach_document(&12345, &estate_company, &DocumentType::SurveyPlan, &survey_plan, &uri)
+            .err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    contract_client.set_estate_company_account(&String::from_str(&env, "Estate Co."), &estate_company);
+    contract_client.set_estate_company_account(&String::from_str(&env, "Rival Co."), &rival_company);
+    assert_eq!(
+        contract_client.get_estate_company_account(&String::from_str(&env, "Estate Co.")),
+        Some(estate_company.clone())
+    );
+
+    contract_client.attach_document(&12345, &estate_company, &DocumentType::SurveyPlan, &survey_plan, &uri);
+    contract_client.attach_document(&12345, &estate_company, &DocumentType::DeedOfAssignment, &deed, &uri);
+
+    // Another company cannot supersede the deed of a plot it did not sell
+    assert_eq!(
+        contract_client
+            .try_attach_document(&12345, &rival_company, &DocumentType::DeedOfAssignment, &survey_plan, &uri)
+            .err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    assert!(contract_client.verify_document(&12345, &DocumentType::DeedOfAssignment, &deed));
+    assert!(contract_client.verify_document(&12345, &DocumentType::SurveyPlan, &survey_plan));
+    assert!(!contract_client.verify_document(&12345, &DocumentType::SurveyPlan, &deed));
+    assert!(!contract_client.verify_document(&12345, &DocumentType::Receipt, &deed));
+
+    // A revised survey plan supersedes the earlier one but both stay on record
+    contract_client.attach_document(&12345, &estate_company, &DocumentType::SurveyPlan, &revised_survey_plan, &uri);
+    assert!(!contract_client.verify_document(&12345, &DocumentType::SurveyPlan, &survey_plan));
+    assert!(contract_client.verify_document(&12345, &DocumentType::SurveyPlan, &revised_survey_plan));
+    assert_eq!(contract_client.get_documents(&12345).len(), 3);
+}
+
+#[test]
+fn test_documents_per_plot_are_capped() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let uri = String::from_str(&env, "ipfs://receipt");
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    let west_half = "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E";
+    let east_half = "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E";
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 1, &owner, "300sqm", west_half));
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 2, &owner, "300sqm", east_half));
+
+    for index in 0..MAX_DOCUMENTS_PER_PLOT {
+        let receipt = BytesN::from_array(&env, &[index as u8; 32]);
+        contract_client.attach_document(&1, &verifier, &DocumentType::Receipt, &receipt, &uri);
+    }
+    let receipt = BytesN::from_array(&env, &[0xff; 32]);
+    assert_eq!(
+        contract_client.try_attach_document(&1, &verifier, &DocumentType::Receipt, &receipt, &uri).err(),
+        Some(Ok(SytemapRegistryError::TooManyDocuments))
+    );
+    assert_eq!(contract_client.get_documents(&1).len(), MAX_DOCUMENTS_PER_PLOT);
+
+    // A merge cannot carry more documents over than the new plot may hold
+    contract_client.attach_document(&2, &verifier, &DocumentType::Receipt, &receipt, &uri);
+    let merged = create_child_payload(&env, 3, &owner, "600sqm", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E");
+    assert_eq!(
+        contract_client.try_merge_plots(&vec![&env, 1, 2], &verifier, &merged).err(),
+        Some(Ok(SytemapRegistryError::TooManyDocuments))
+    );
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Allocated);
+}
+
+#[test]
+fn test_kyc_gates_mint_and_transfer_for_opted_in_estate() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let officer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let estate_name = String::from_str(&env, "Estate XYZ");
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Compliance, &officer);
+    contract_client.set_estate_kyc_level(&officer, &estate_name, &1);
+
+    let payload = create_payload(&env, 12345, &buyer, "nft_address");
+    assert_eq!(
+        contract_client.try_safe_mint_new_property_info(&payload).err(),
+        Some(Ok(SytemapRegistryError::RecipientNotVerified))
+    );
+
+    contract_client.set_kyc_status(&officer, &buyer, &1, &(365 * 86_400));
+    contract_client.safe_mint_new_property_info(&payload);
+
+    // Each result is an entry of its own rather than part of the contract instance
+    env.as_contract(&contract_client.address, || {
+        let key = crate::storage::SytemapDataKeys::Kyc(buyer.clone());
+        assert!(env.storage().persistent().has(&key));
+        assert!(!env.storage().instance().has(&key));
+    });
+
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &new_owner).err(),
+        Some(Ok(SytemapRegistryError::RecipientNotVerified))
+    );
+
+    // Expired verifications no longer count
+    contract_client.set_kyc_status(&officer, &new_owner, &1, &86_400);
+    env.ledger().set_timestamp(2 * 86_400);
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&12345, &new_owner).err(),
+        Some(Ok(SytemapRegistryError::RecipientNotVerified))
+    );
+
+    // Estate companies cannot switch the checks off, even for their own estates
+    let estate_company = Address::generate(&env);
+    contract_client.grant_role(&Role::EstateCompany, &estate_company);
+    assert_eq!(
+        contract_client.try_set_estate_kyc_level(&estate_company, &estate_name, &0).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    assert_eq!(
+        contract_client.try_set_estate_kyc_level(&estate_company, &String::from_str(&env, "Estate ABC"), &1).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    assert_eq!(contract_client.get_estate_kyc_level(&estate_name), 1);
+
+    // Once the estate opts out anyone may receive its plots
+    contract_client.set_estate_kyc_level(&officer, &estate_name, &0);
+    contract_client.transfer_property_by_owner(&12345, &new_owner);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), new_owner);
+}
+
+#[test]
+fn test_external_kyc_provider_is_consulted() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let provider_id = env.register_contract(None, mock_kyc::MockKycProvider);
+    let provider_client = mock_kyc::MockKycProviderClient::new(&env, &provider_id);
+    let buyer = Address::generate(&env);
+
+    let admin = initialize(&env, &contract_client);
+    contract_client.set_estate_kyc_level(&admin, &String::from_str(&env, "Estate XYZ"), &1);
+    contract_client.set_kyc_provider(&Some(provider_id.clone()));
+
+    let payload = create_payload(&env, 12345, &buyer, "nft_address");
+    assert_eq!(
+        contract_client.try_safe_mint_new_property_info(&payload).err(),
+        Some(Ok(SytemapRegistryError::RecipientNotVerified))
+    );
+
+    provider_client.approve(&buyer);
+    contract_client.safe_mint_new_property_info(&payload);
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), buyer);
+}
+
+#[test]
+fn test_global_pause_blocks_mutations_but_not_queries() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let pauser = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Pauser, &pauser);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+
+    contract_client.pause(&pauser);
+    assert!(contract_client.is_paused());
+    assert_eq!(
+        contract_client
+            .try_safe_mint_new_property_info(&create_payload(&env, 67890, &owner, "nft_address2"))
+            .err(),
+        Some(Ok(SytemapRegistryError::ContractPaused))
+    );
+    assert_eq!(
+        contract_client.try_change_property_price_by_owner(&12345, &150_000).err(),
+        Some(Ok(SytemapRegistryError::ContractPaused))
+    );
+    assert_eq!(contract_client.get_owner_of_pvn(&12345), owner);
+    assert_eq!(contract_client.get_number_of_property_minted(), 1);
+
+    // The admin can still replace keys and fix indices, but nothing else moves
+    let new_pauser = Address::generate(&env);
+    let company_account = Address::generate(&env);
+    contract_client.grant_role(&Role::Pauser, &new_pauser);
+    contract_client.revoke_role(&Role::Pauser, &pauser);
+    contract_client.set_estate_company_account(&String::from_str(&env, "Estate Co."), &company_account);
+    assert!(contract_client.repair_index(&12345).is_empty());
+    assert_eq!(
+        contract_client.try_update_metadata_uri(&String::from_str(&env, "ipfs://new")).err(),
+        Some(Ok(SytemapRegistryError::ContractPaused))
+    );
+    assert_eq!(
+        contract_client.try_unpause(&pauser).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+
+    contract_client.unpause(&new_pauser);
+    contract_client.change_property_price_by_owner(&12345, &150_000);
+}
+
+#[test]
+fn test_estate_pause_only_affects_that_estate() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let pauser = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Pauser, &pauser);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+    let mut other_estate_payload = create_payload(&env, 67890, &owner, "nft_address2");
+    other_estate_payload.estate_name = String::from_str(&env, "Estate ABC");
+    contract_client.safe_mint_new_property_info(&other_estate_payload);
+
+    assert_eq!(
+        contract_client.try_pause_estate(&owner, &String::from_str(&env, "Estate XYZ")).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+    contract_client.pause_estate(&pauser, &String::from_str(&env, "Estate XYZ"));
+
+    assert_eq!(
+        contract_client.try_change_property_price_by_owner(&12345, &150_000).err(),
+        Some(Ok(SytemapRegistryError::ContractPaused))
+    );
+    contract_client.change_property_price_by_owner(&67890, &150_000);
+
+    contract_client.unpause_estate(&pauser, &String::from_str(&env, "Estate XYZ"));
+    contract_client.change_property_price_by_owner(&12345, &150_000);
+}
+
+#[test]
+fn test_events_use_versioned_sytemap_topics() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "initialized")).into_val(&env)
+    );
+    let event = InitializedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 12345, &owner, "nft_address"));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "property_minted"), 12345u64).into_val(&env)
+    );
+    let event = PropertyMintedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.token_id, 1);
+    assert_eq!(event.property.buyer_wallet_id, owner);
+
+    contract_client.change_property_price_by_owner(&12345, &150_000);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "price_changed"), 12345u64).into_val(&env)
+    );
+    let event = PriceChangedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, EVENT_SCHEMA_VERSION);
+    assert_eq!((event.old_price, event.new_price), (100_000, 150_000));
+
+    contract_client.update_metadata_uri(&String::from_str(&env, "http://new.metadata.url"));
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event = MetadataUriUpdatedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.old_metadata_uri, String::from_str(&env, "http://metadata.url"));
+    assert_eq!(event.new_metadata_uri, String::from_str(&env, "http://new.metadata.url"));
+}
+
+#[test]
+fn test_batch_mint_is_all_or_nothing() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+
+    contract_client.batch_mint_new_property_info(&vec![
+        &env,
+        create_payload(&env, 1, &owner_a, "nft-1"),
+        create_payload(&env, 2, &owner_b, "nft-2"),
+    ]);
+    assert_eq!(contract_client.get_number_of_property_minted(), 2);
+    assert_eq!(contract_client.get_owner_of_pvn(&2), owner_b);
+
+    // The duplicate NFT address in the second payload rolls back the first one too
+    let result = contract_client.try_batch_mint_new_property_info(&vec![
+        &env,
+        create_payload(&env, 3, &owner_a, "nft-3"),
+        create_payload(&env, 4, &owner_b, "nft-1"),
+    ]);
+    assert_eq!(
+        result.err(),
+        Some(Ok(SytemapRegistryError::NftAddressAlreadyInUse))
+    );
+    assert_eq!(contract_client.get_number_of_property_minted(), 2);
+    assert!(contract_client.try_get_owner_of_pvn(&3).is_err());
+}
+
+#[test]
+fn test_get_properties_by_owner_page() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    for pvn in 1..=5u64 {
+        let owner = if pvn % 2 == 1 { &owner_a } else { &owner_b };
+        let nft_address = std::format!("nft-{pvn}");
+        contract_client.safe_mint_new_property_info(&create_payload(&env, pvn, owner, &nft_address));
+    }
+
+    let first = contract_client.get_properties_by_owner_page(&owner_a, &0, &2);
+    assert_eq!(first.properties.len(), 2);
+    assert_eq!(first.properties.get_unchecked(1).property_verification_no, 3);
+    assert_eq!(first.next_cursor, Some(2));
+
+    let second = contract_client.get_properties_by_owner_page(&owner_a, &2, &2);
+    assert_eq!(second.properties.len(), 1);
+    assert_eq!(second.properties.get_unchecked(0).property_verification_no, 5);
+    assert_eq!(second.next_cursor, None);
+
+    assert_eq!(
+        contract_client.try_get_properties_by_owner_page(&owner_b, &0, &0).err(),
+        Some(Ok(SytemapRegistryError::InvalidPageLimit))
+    );
+}
+
+#[test]
+fn test_get_property_info_by_nft_address_out_of_pvn_order() {
     let env: Env = Default::default();
+    env.mock_all_auths();
     let contract_client = get_client(&env);
-    // let buyer_wallet_id = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 7, &owner, "nft-7"));
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 1, &owner, "nft-1"));
+
+    // Token 1 went to PVN 7, so the lookup must not treat token IDs as PVNs
+    let property_info =
+        contract_client.get_property_info_by_nft_address(&String::from_str(&env, "nft-7"));
+    assert_eq!(property_info.property_verification_no, 7);
+    let property_info =
+        contract_client.get_property_info_by_nft_address(&String::from_str(&env, "nft-1"));
+    assert_eq!(property_info.property_verification_no, 1);
+}
+
+#[test]
+fn test_verify_integrity_and_repair_index() {
+    use crate::storage::SytemapDataKeys;
 
-    let token_metadata = TokenMetadata {
-        sytemap_name: String::from_str(&env, "Sytemap"),
-        sytemap_symbol: String::from_str(&env, "SYM"),
-        base_token_uri: String::from_str(&env, "http://metadata.url"),
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    for pvn in 1..=4u64 {
+        let nft_address = std::format!("nft-{pvn}");
+        contract_client.safe_mint_new_property_info(&create_payload(&env, pvn, &owner, &nft_address));
+    }
+    let report = contract_client.verify_integrity(&0, &MAX_INTEGRITY_PAGE_SIZE);
+    assert_eq!(report.checked, 4);
+    assert!(report.inconsistencies.is_empty());
+    assert_eq!(report.next_cursor, None);
+
+    // Break every index the way a botched migration could
+    env.as_contract(&contract_client.address, || {
+        update_all_properties(&env, vec![&env, 2, 3, 4, 2, 9]).unwrap();
+        remove_pvn_to_token_id(&env, 3);
+        env.storage()
+            .persistent()
+            .remove(&SytemapDataKeys::NftAddressToTokenId(String::from_str(&env, "nft-4")));
+        env.storage()
+            .persistent()
+            .remove(&SytemapDataKeys::NftAddressToPvn(String::from_str(&env, "nft-3")));
+        save_nft_address_to_pvn(&env, String::from_str(&env, "nft-4"), 1);
+        env.storage().persistent().remove(&SytemapDataKeys::EstatePlot(
+            String::from_str(&env, "Estate XYZ"),
+            String::from_str(&env, "Plot4"),
+        ));
+        save_estate_properties(&env, &String::from_str(&env, "Estate XYZ"), &vec![&env, 1, 2, 3]);
+        save_owner_properties(&env, &owner, &vec![&env, 1, 2, 3]);
+    });
+
+    let inconsistency = |property_verification_no, position, issue| IndexInconsistency {
+        property_verification_no,
+        position,
+        issue,
     };
-    contract_client.init(&token_metadata).unwrap();
+    let first = contract_client.verify_integrity(&0, &2);
+    assert_eq!(first.checked, 2);
+    assert_eq!(
+        first.inconsistencies,
+        vec![
+            &env,
+            inconsistency(3, 1, IndexIssue::MissingTokenId),
+            inconsistency(3, 1, IndexIssue::MissingNftAddressPvn),
+        ]
+    );
+    assert_eq!(first.next_cursor, Some(2));
+    let second = contract_client.verify_integrity(&2, &MAX_INTEGRITY_PAGE_SIZE);
+    assert_eq!(second.checked, 3);
+    assert_eq!(
+        second.inconsistencies,
+        vec![
+            &env,
+            inconsistency(4, 2, IndexIssue::MissingEstatePlot),
+            inconsistency(4, 2, IndexIssue::NotListedInEstate),
+            inconsistency(4, 2, IndexIssue::MissingNftAddress),
+            inconsistency(4, 2, IndexIssue::NftAddressPvnMismatch),
+            inconsistency(4, 2, IndexIssue::NotListedForOwner),
+            inconsistency(2, 3, IndexIssue::DuplicateListing),
+            inconsistency(9, 4, IndexIssue::MissingPropertyInfo),
+        ]
+    );
+    assert_eq!(second.next_cursor, None);
+    assert_eq!(
+        contract_client.try_verify_integrity(&0, &(MAX_INTEGRITY_PAGE_SIZE + 1)).err(),
+        Some(Ok(SytemapRegistryError::InvalidPageLimit))
+    );
+
+    // The lookup by NFT address reads its own index rather than scanning every property
+    assert_eq!(
+        contract_client.try_get_property_info_by_nft_address(&String::from_str(&env, "nft-3")).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFound))
+    );
+
+    // PVN 1 is invisible to the scan, but the admin can name it
+    assert_eq!(contract_client.repair_index(&1), vec![&env, IndexIssue::NotListed]);
+    assert_eq!(contract_client.repair_index(&2), vec![&env, IndexIssue::DuplicateListing]);
+    assert_eq!(
+        contract_client.repair_index(&3),
+        vec![&env, IndexIssue::MissingTokenId, IndexIssue::MissingNftAddressPvn]
+    );
+    assert_eq!(
+        contract_client.repair_index(&4),
+        vec![
+            &env,
+            IndexIssue::MissingEstatePlot,
+            IndexIssue::NotListedInEstate,
+            IndexIssue::MissingNftAddress,
+            IndexIssue::NftAddressPvnMismatch,
+            IndexIssue::NotListedForOwner
+        ]
+    );
+    assert_eq!(contract_client.repair_index(&9), vec![&env, IndexIssue::MissingPropertyInfo]);
+    assert!(contract_client.repair_index(&4).is_empty());
+    assert_eq!(
+        contract_client.try_repair_index(&42).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFound))
+    );
+
+    let report = contract_client.verify_integrity(&0, &MAX_INTEGRITY_PAGE_SIZE);
+    assert_eq!(report.checked, 4);
+    assert!(report.inconsistencies.is_empty());
+    assert_eq!(contract_client.get_number_of_property_minted(), 4);
+    assert_eq!(contract_client.get_no_of_property_by_address(&owner), 4);
+    for pvn in 1..=4u64 {
+        let nft_address = String::from_str(&env, &std::format!("nft-{pvn}"));
+        let property_info = contract_client.get_property_info_by_nft_address(&nft_address);
+        assert_eq!(property_info.property_verification_no, pvn);
+    }
+    let plot_no = String::from_str(&env, "Plot4");
+    let property_info =
+        contract_client.get_property_by_estate_plot(&String::from_str(&env, "Estate XYZ"), &plot_no);
+    assert_eq!(property_info.property_verification_no, 4);
+    assert_eq!(contract_client.count_properties_by_estate(&String::from_str(&env, "Estate XYZ")), 4);
+}
 
-    let payload = PropertyInfoPayload {
-        price_of_plot: 100_000,
-        property_verification_no: 12345,
-        buyer_wallet_id: Address::from_string(&env, "buyer_wallet_id"),
-        plot_no: String::from_str(&env, "Plot123"),
-        size_of_plot: String::from_str(&env, "10x10"),
-        coordinate_of_plot: String::from_str(&env, "12.34, 56.78"),
-        token_url: String::from_str(&env, "http://token.url"),
-        estate_name: String::from_str(&env, "Estate XYZ"),
-        plot_url: String::from_str(&env, "http://plot.url"),
-        date_of_allocation: String::from_str(&env, "2024-08-01"),
-        estate_company_name: String::from_str(&env, "Estate Co."),
-        nft_address: String::from_str(&env, "nft_address"),
+#[test]
+fn test_plot_no_is_unique_within_an_estate() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.safe_mint_new_property_info(&create_payload(&env, 1, &owner, "nft-1"));
+
+    // Same beacon number in the same estate
+    let mut payload = create_payload(&env, 2, &owner, "nft-2");
+    payload.plot_no = String::from_str(&env, "Plot1");
+    assert_eq!(
+        contract_client.try_safe_mint_new_property_info(&payload).err(),
+        Some(Ok(SytemapRegistryError::PlotNoAlreadyInUse))
+    );
+
+    // Another estate may reuse it
+    payload.estate_name = String::from_str(&env, "Estate ABC");
+    contract_client.safe_mint_new_property_info(&payload);
+
+    let plot_no = String::from_str(&env, "Plot1");
+    let property_info = contract_client
+        .get_property_by_estate_plot(&String::from_str(&env, "Estate XYZ"), &plot_no);
+    assert_eq!(property_info.property_verification_no, 1);
+    let property_info = contract_client
+        .get_property_by_estate_plot(&String::from_str(&env, "Estate ABC"), &plot_no);
+    assert_eq!(property_info.property_verification_no, 2);
+    assert_eq!(
+        contract_client
+            .try_get_property_by_estate_plot(&String::from_str(&env, "Estate XYZ"), &String::from_str(&env, "Plot2"))
+            .err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFound))
+    );
+}
+
+#[test]
+fn test_estate_indices_and_summary() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    let estate = String::from_str(&env, "Estate XYZ");
+    let company = String::from_str(&env, "Estate Co.");
+    for (pvn, price, size) in [(1, 100, "600sqm"), (2, 200, "20x30"), (3, 300, "irregular"), (4, 400, "450 m2")] {
+        let mut payload = create_payload(&env, pvn, &owner, &std::format!("nft-{pvn}"));
+        payload.price_of_plot = price;
+        payload.size_of_plot = String::from_str(&env, size);
+        contract_client.safe_mint_new_property_info(&payload);
+    }
+    // Same company, another estate
+    let mut payload = create_payload(&env, 5, &owner, "nft-5");
+    payload.estate_name = String::from_str(&env, "Estate ABC");
+    contract_client.safe_mint_new_property_info(&payload);
+    contract_client.revoke_property(&4);
+
+    assert_eq!(contract_client.count_properties_by_estate(&estate), 4);
+    assert_eq!(contract_client.count_properties_by_estate(&String::from_str(&env, "Estate ABC")), 1);
+    assert_eq!(contract_client.count_properties_by_estate(&String::from_str(&env, "Nowhere")), 0);
+
+    let first = contract_client.get_properties_by_estate(&estate, &0, &3);
+    assert_eq!(first.properties.len(), 3);
+    assert_eq!(first.next_cursor, Some(3));
+    let second = contract_client.get_properties_by_estate(&estate, &3, &3);
+    assert_eq!(second.properties.len(), 1);
+    assert_eq!(second.properties.get_unchecked(0).property_verification_no, 4);
+    assert_eq!(second.next_cursor, None);
+    let by_company = contract_client.get_properties_by_estate_company(&company, &0, &MAX_PAGE_SIZE);
+    assert_eq!(by_company.properties.len(), 5);
+    assert_eq!(
+        contract_client.try_get_properties_by_estate(&estate, &0, &0).err(),
+        Some(Ok(SytemapRegistryError::InvalidPageLimit))
+    );
+
+    // The revoked plot is not sold, and `irregular` has no area
+    assert_eq!(
+        contract_client.get_estate_summary(&estate),
+        EstateSummary {
+            estate_name: estate.clone(),
+            plot_count: 4,
+            sold_count: 3,
+            total_value: 600,
+            average_price: 200,
+            area_sold: 1_200,
+            sold_without_area: 1,
+        }
+    );
+
+    // The totals follow a sold plot's new price, and never count a plot twice
+    contract_client.change_property_price_by_owner(&2, &500);
+    contract_client.transfer_property_by_owner(&2, &Address::generate(&env));
+    let summary = contract_client.get_estate_summary(&estate);
+    assert_eq!((summary.sold_count, summary.total_value, summary.average_price), (3, 900, 300));
+}
+
+#[test]
+fn test_parse_plot_area() {
+    let env = Env::default();
+    let area = |size: &str| parse_plot_area(&String::from_str(&env, size));
+    assert_eq!(area("600sqm"), Some(600));
+    assert_eq!(area(" 600 SQM "), Some(600));
+    assert_eq!(area("450m2"), Some(450));
+    assert_eq!(area("10x10"), Some(100));
+    assert_eq!(area("20 X 30"), Some(600));
+    assert_eq!(area("irregular"), None);
+    assert_eq!(area("600 acres"), None);
+    assert_eq!(area("10x"), None);
+    assert_eq!(area("99999999999999999999"), None);
+}
+
+fn create_child_payload(
+    env: &Env,
+    property_verification_no: u64,
+    owner: &Address,
+    size_of_plot: &str,
+    coordinate_of_plot: &str,
+) -> PropertyInfoPayload {
+    let mut payload = create_payload(env, property_verification_no, owner, &std::format!("nft-{property_verification_no}"));
+    payload.size_of_plot = String::from_str(env, size_of_plot);
+    payload.coordinate_of_plot = String::from_str(env, coordinate_of_plot);
+    payload
+}
+
+#[test]
+fn test_subdivide_mints_children_and_walks_lineage() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    contract_client.safe_mint_new_property_info(&create_child_payload(
+        &env, 1, &owner, "20x30", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E",
+    ));
+
+    let halves = vec![
+        &env,
+        create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E"),
+        create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E"),
+    ];
+    contract_client.subdivide(&1, &verifier, &halves);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "subdivided"), 1u64).into_val(&env)
+    );
+    let event = SubdividedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.child_pvns, vec![&env, 2, 3]);
+    assert_eq!(event.verifier, verifier);
+
+    let parent = contract_client.get_property_info_details_by_pvn(&1);
+    assert_eq!(parent.status, PropertyStatus::Subdivided);
+    let child = contract_client.get_property_info_details_by_pvn(&3);
+    assert_eq!(child.status, PropertyStatus::Allocated);
+    assert_eq!(child.buyer_wallet_id, owner);
+
+    // The retired parent can no longer move, and only the children count as sold
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&1, &Address::generate(&env)).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotTransferable))
+    );
+    let summary = contract_client.get_estate_summary(&String::from_str(&env, "Estate XYZ"));
+    assert_eq!((summary.plot_count, summary.sold_count, summary.area_sold), (3, 2, 600));
+
+    // Split the western half again, in the other coordinate notation this time
+    let quarters = vec![
+        &env,
+        create_child_payload(&env, 4, &owner, "150sqm", "6.50N,3.30E;6.50N,3.35E;6.55N,3.35E;6.55N,3.30E"),
+        create_child_payload(&env, 5, &owner, "150sqm", "6.55, 3.30; 6.55, 3.35; 6.60, 3.35; 6.60, 3.30"),
+    ];
+    contract_client.subdivide(&2, &verifier, &quarters);
+
+    let lineage = contract_client.get_lineage(&1);
+    assert_eq!(lineage.ancestors, vec![&env]);
+    let link = |property_verification_no, parent_pvn| LineageLink {
+        property_verification_no,
+        parent_pvn,
+    };
+    assert_eq!(lineage.descendants, vec![&env, link(2, 1), link(3, 1), link(4, 2), link(5, 2)]);
+
+    let lineage = contract_client.get_lineage(&5);
+    assert_eq!(lineage.ancestors, vec![&env, link(5, 2), link(2, 1)]);
+    assert_eq!(lineage.descendants, vec![&env]);
+    assert_eq!(
+        contract_client.try_get_lineage(&6).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotFound))
+    );
+}
+
+#[test]
+fn test_subdivide_rejects_children_that_do_not_fit() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    contract_client.safe_mint_new_property_info(&create_child_payload(
+        &env, 1, &owner, "600sqm", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E",
+    ));
+    let subdivide = |children: &[PropertyInfoPayload]| {
+        let mut child_payloads = vec![&env];
+        for child in children {
+            child_payloads.push_back(child.clone());
+        }
+        contract_client.try_subdivide(&1, &verifier, &child_payloads).err()
     };
+    let west = create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E");
+    let east = create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E");
+    let invalid = Some(Ok(SytemapRegistryError::InvalidSubdivision));
+
+    assert_eq!(subdivide(std::slice::from_ref(&west)), invalid);
+
+    let mut too_large = east.clone();
+    too_large.size_of_plot = String::from_str(&env, "301sqm");
+    assert_eq!(subdivide(&[west.clone(), too_large]), invalid);
+
+    let mut outside = east.clone();
+    outside.coordinate_of_plot = String::from_str(&env, "6.55N,3.38E;6.55N,3.41E;6.56N,3.38E");
+    assert_eq!(subdivide(&[west.clone(), outside]), invalid);
+
+    let mut elsewhere = east.clone();
+    elsewhere.estate_name = String::from_str(&env, "Estate ABC");
+    assert_eq!(subdivide(&[west.clone(), elsewhere]), invalid);
+
+    let mut sold_on = east.clone();
+    sold_on.buyer_wallet_id = Address::generate(&env);
+    assert_eq!(subdivide(&[west.clone(), sold_on]), invalid);
+
+    // Children have to be outlined: a point has no area of its own to account for
+    let mut point = east.clone();
+    point.coordinate_of_plot = String::from_str(&env, "6.55N,3.38E");
+    assert_eq!(subdivide(&[west.clone(), point]), invalid);
+
+    // Both children claiming the whole parent, or a strip of the west half twice
+    let mut whole_west = west.clone();
+    whole_west.coordinate_of_plot = String::from_str(&env, "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E");
+    let mut whole_east = east.clone();
+    whole_east.coordinate_of_plot = whole_west.coordinate_of_plot.clone();
+    assert_eq!(subdivide(&[whole_west, whole_east]), invalid);
+    let mut overlapping = east.clone();
+    overlapping.coordinate_of_plot = String::from_str(&env, "6.50N,3.34E;6.50N,3.36E;6.60N,3.36E;6.60N,3.34E");
+    overlapping.size_of_plot = String::from_str(&env, "100sqm");
+    assert_eq!(subdivide(&[west.clone(), overlapping]), invalid);
+
+    // A plot recorded as a single point has no inside to carve the children out of
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 9, &owner, "600sqm", "6.55N,3.35E"));
+    assert_eq!(
+        contract_client.try_subdivide(&9, &verifier, &vec![&env, west.clone(), east.clone()]).err(),
+        invalid
+    );
+
+    // Someone without the verifier role cannot sign off
+    let child_payloads = vec![&env, west, east];
+    assert_eq!(
+        contract_client.try_subdivide(&1, &owner, &child_payloads).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+
+    // Nothing was minted by the failed attempts
+    assert_eq!(contract_client.get_number_of_property_minted(), 2);
+    contract_client.subdivide(&1, &verifier, &child_payloads);
+
+    // A retired plot cannot be split again
+    assert_eq!(
+        contract_client.try_subdivide(&1, &verifier, &child_payloads).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotTransferable))
+    );
+}
 
-    let result = contract_client.safe_mint_new_property_info(&env, payload);
+#[test]
+fn test_merge_plots_requires_one_owner_and_no_encumbrances() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let neighbour = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let bank = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    let west_half = "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E";
+    let east_half = "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E";
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 1, &owner, "300sqm", west_half));
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 2, &owner, "300sqm", east_half));
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 3, &neighbour, "300sqm", east_half));
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 4, &owner, "300sqm", east_half));
+    // Two plots of the owner with a strip of someone else's land between them
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 5, &owner, "300sqm", "6.50N,3.30E;6.50N,3.33E;6.60N,3.33E;6.60N,3.30E"));
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 6, &owner, "300sqm", "6.50N,3.37E;6.50N,3.40E;6.60N,3.40E;6.60N,3.37E"));
+    // A plot recorded only as a point has no outline to account for
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 7, &owner, "300sqm", "6.55N,3.38E"));
+    contract_client.register_lien(&4, &bank, &50_000, &(365 * 86_400));
+
+    let merged = create_child_payload(&env, 10, &owner, "20x30", "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E");
+    let merge = |pvns: &[u64], payload: &PropertyInfoPayload| {
+        let mut source_pvns = vec![&env];
+        for pvn in pvns {
+            source_pvns.push_back(*pvn);
+        }
+        contract_client.try_merge_plots(&source_pvns, &verifier, payload).err()
+    };
+    let invalid = Some(Ok(SytemapRegistryError::InvalidMerge));
+
+    assert_eq!(merge(&[1], &merged), invalid);
+    assert_eq!(merge(&[1, 1], &merged), invalid);
+    // Plots of two owners, or a plot carrying a lien
+    assert_eq!(merge(&[1, 3], &merged), invalid);
+    assert_eq!(merge(&[1, 4], &merged), Some(Ok(SytemapRegistryError::PropertyEncumbered)));
+
+    let mut too_large = merged.clone();
+    too_large.size_of_plot = String::from_str(&env, "601sqm");
+    assert_eq!(merge(&[1, 2], &too_large), invalid);
+
+    // Claiming less than the sources lets land vanish from the register
+    let mut too_small = merged.clone();
+    too_small.size_of_plot = String::from_str(&env, "599sqm");
+    assert_eq!(merge(&[1, 2], &too_small), invalid);
+
+    let mut outside = merged.clone();
+    outside.coordinate_of_plot = String::from_str(&env, west_half);
+    assert_eq!(merge(&[1, 2], &outside), invalid);
+
+    let mut no_outline = merged.clone();
+    no_outline.coordinate_of_plot = String::from_str(&env, "6.55N,3.35E");
+    assert_eq!(merge(&[1, 2], &no_outline), invalid);
+    assert_eq!(merge(&[1, 7], &merged), invalid);
+    // The sizes add up, but the new outline swallows the strip between the plots
+    assert_eq!(merge(&[5, 6], &merged), invalid);
+
+    let mut elsewhere = merged.clone();
+    elsewhere.estate_name = String::from_str(&env, "Estate ABC");
+    assert_eq!(merge(&[1, 2], &elsewhere), invalid);
+
+    assert_eq!(
+        contract_client.try_merge_plots(&vec![&env, 1, 2], &neighbour, &merged).err(),
+        Some(Ok(SytemapRegistryError::Unauthorized))
+    );
+
+    // Nothing was minted or retired by the failed attempts
+    assert_eq!(contract_client.get_number_of_property_minted(), 7);
+    assert_eq!(contract_client.get_property_info_details_by_pvn(&1).status, PropertyStatus::Allocated);
+    contract_client.merge_plots(&vec![&env, 1, 2], &verifier, &merged);
+}
+
+#[test]
+fn test_merge_plots_retires_sources_and_carries_provenance() {
+    let env: Env = Default::default();
+    env.mock_all_auths();
+    let contract_client = get_client(&env);
+    let owner = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    initialize(&env, &contract_client);
+    contract_client.grant_role(&Role::Verifier, &verifier);
+    let whole = "6.50N,3.30E;6.50N,3.40E;6.60N,3.40E;6.60N,3.30E";
+    contract_client.safe_mint_new_property_info(&create_child_payload(&env, 1, &owner, "600sqm", whole));
+    contract_client.subdivide(
+        &1,
+        &verifier,
+        &vec![
+            &env,
+            create_child_payload(&env, 2, &owner, "300sqm", "6.50N,3.30E;6.50N,3.35E;6.60N,3.35E;6.60N,3.30E"),
+            create_child_payload(&env, 3, &owner, "300sqm", "6.50N,3.35E;6.50N,3.40E;6.60N,3.40E;6.60N,3.35E"),
+        ],
+    );
+    for (pvn, hash) in [(2, [2; 32]), (3, [3; 32])] {
+        contract_client.attach_document(
+            &pvn,
+            &verifier,
+            &DocumentType::SurveyPlan,
+            &BytesN::from_array(&env, &hash),
+            &String::from_str(&env, "ipfs://survey-plan"),
+        );
+    }
+
+    // Put the halves back together under a new title
+    contract_client.merge_plots(&vec![&env, 2, 3], &verifier, &create_child_payload(&env, 4, &owner, "600sqm", whole));
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("sytemap"), Symbol::new(&env, "plots_merged"), 4u64).into_val(&env)
+    );
+    let event = PlotsMergedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.source_pvns, vec![&env, 2, 3]);
+    assert_eq!(event.owner, owner);
+
+    for pvn in [2, 3] {
+        assert_eq!(contract_client.get_property_info_details_by_pvn(&pvn).status, PropertyStatus::Merged);
+    }
+    let merged = contract_client.get_property_info_details_by_pvn(&4);
+    assert_eq!((merged.status, merged.buyer_wallet_id), (PropertyStatus::Allocated, owner));
+    let documents = contract_client.get_documents(&4);
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents.get_unchecked(1).sha256_hash, BytesN::from_array(&env, &[3; 32]));
+    assert_eq!(
+        contract_client.try_transfer_property_by_owner(&2, &Address::generate(&env)).err(),
+        Some(Ok(SytemapRegistryError::PropertyNotTransferable))
+    );
+
+    // The original plot is reached along both halves but listed once per link
+    let link = |property_verification_no, parent_pvn| LineageLink {
+        property_verification_no,
+        parent_pvn,
+    };
+    let lineage = contract_client.get_lineage(&4);
+    assert_eq!(lineage.ancestors, vec![&env, link(4, 2), link(4, 3), link(2, 1), link(3, 1)]);
+    assert_eq!(lineage.descendants, vec![&env]);
+    let lineage = contract_client.get_lineage(&1);
+    assert_eq!(lineage.descendants, vec![&env, link(2, 1), link(3, 1), link(4, 2), link(4, 3)]);
+
+    // Only the merged plot counts as sold
+    let summary = contract_client.get_estate_summary(&String::from_str(&env, "Estate XYZ"));
+    assert_eq!((summary.plot_count, summary.sold_count, summary.area_sold), (4, 1, 600));
+}
+
+#[test]
+fn test_parse_plot_outline() {
+    let env = Env::default();
+    let mut points = [(0, 0); MAX_OUTLINE_POINTS];
+    let mut outline = |coordinate: &str| {
+        let count = parse_plot_outline(&String::from_str(&env, coordinate), &mut points)?;
+        Some(points[..count].to_vec())
+    };
+    assert_eq!(outline("6.5244N,3.3792E"), Some(std::vec![(6_524_400, 3_379_200)]));
+    assert_eq!(outline("12.34, 56.78"), Some(std::vec![(12_340_000, 56_780_000)]));
+    assert_eq!(outline("-6.5, 3.25W"), Some(std::vec![(-6_500_000, -3_250_000)]));
+    assert_eq!(outline("33.9S,18.4E; 34S,18.5E"), Some(std::vec![(-33_900_000, 18_400_000), (-34_000_000, 18_500_000)]));
+    assert_eq!(outline("6.123456789,3"), Some(std::vec![(6_123_456, 3_000_000)]));
+    assert_eq!(outline("Lekki Phase 1"), None);
+    assert_eq!(outline("6.5N"), None);
+    assert_eq!(outline("6.5N,3.3E,1"), None);
+    assert_eq!(outline("-6.5S,3.3E"), None);
+    assert_eq!(outline(""), None);
+
+    let square = [(0, 0), (0, 10), (10, 10), (10, 0)];
+    assert!(outline_contains(&square, (5, 5)));
+    assert!(outline_contains(&square, (0, 5)));
+    assert!(outline_contains(&square, (10, 10)));
+    assert!(!outline_contains(&square, (5, 11)));
+    assert!(!outline_contains(&square, (-1, 5)));
+    // The notch of an L-shaped plot is outside it
+    let l_shape = [(0, 0), (0, 10), (5, 10), (5, 5), (10, 5), (10, 0)];
+    assert!(outline_contains(&l_shape, (2, 8)));
+    assert!(!outline_contains(&l_shape, (8, 8)));
+}
+
+#[test]
+fn test_outlines_overlap() {
+    let square = [(0, 0), (0, 10), (10, 10), (10, 0)];
+    let reversed = [(10, 0), (10, 10), (0, 10), (0, 0)];
+    assert!(outlines_overlap(&square, &square));
+    assert!(outlines_overlap(&square, &reversed));
+    // Neighbours sharing an edge or a corner
+    assert!(!outlines_overlap(&square, &[(0, 10), (0, 20), (10, 20), (10, 10)]));
+    assert!(!outlines_overlap(&square, &[(10, 10), (10, 20), (20, 20), (20, 10)]));
+    assert!(!outlines_overlap(&square, &[(0, 11), (0, 20), (10, 20), (10, 11)]));
+    // Crossing, nested, and nested along an edge
+    assert!(outlines_overlap(&square, &[(5, 5), (5, 15), (15, 15), (15, 5)]));
+    assert!(outlines_overlap(&square, &[(2, 2), (2, 8), (8, 8), (8, 2)]));
+    assert!(outlines_overlap(&square, &[(0, 0), (0, 5), (10, 5), (10, 0)]));
+    // Halves cut along the diagonal, and a triangle reaching across it
+    let lower = [(0, 0), (0, 10), (10, 10)];
+    assert!(!outlines_overlap(&lower, &[(0, 0), (10, 10), (10, 0)]));
+    assert!(outlines_overlap(&lower, &[(0, 0), (10, 10), (0, 10)]));
+    // A plot filling the notch of an L-shaped one, and one poking into the L
+    let l_shape = [(0, 0), (0, 10), (5, 10), (5, 5), (10, 5), (10, 0)];
+    assert!(!outlines_overlap(&l_shape, &[(5, 5), (5, 10), (10, 10), (10, 5)]));
+    assert!(outlines_overlap(&l_shape, &[(4, 5), (4, 10), (10, 10), (10, 5)]));
+}
 
-    assert!(result.is_ok());
-    assert_eq!(get_property_info_by_pvn(&env, 12345).is_ok(), true);
+mod invariants {
+    //! State-machine test: random sequences of mints, reprices, transfers and revocations
+    //! run against the registry and a plain model of it, checking the registry's indices
+    //! after every step.
+
+    use super::*;
+    use crate::storage::SytemapDataKeys;
+    use proptest::prelude::*;
+    use soroban_sdk::{testutils::EnvTestConfig, ConversionError, InvokeError};
+    use std::collections::BTreeMap;
+    use std::vec::Vec as StdVec;
+
+    /// Owners plots are minted to and transferred between
+    const OWNERS: usize = 4;
+    /// PVNs and NFT addresses are drawn from a small range so that calls keep colliding
+    const MAX_PVN: u64 = 12;
+
+    #[derive(Debug, Clone)]
+    enum Op {
+        Mint {
+            pvn: u64,
+            owner: usize,
+            nft: u64,
+            price: u64,
+        },
+        Reprice {
+            pvn: u64,
+            price: u64,
+        },
+        Transfer {
+            pvn: u64,
+            to: usize,
+        },
+        Revoke {
+            pvn: u64,
+        },
+    }
+
+    fn op() -> impl Strategy<Value = Op> {
+        prop_oneof![
+            3 => (1..=MAX_PVN, 0..OWNERS, 1..=MAX_PVN, 1..1_000_000u64)
+                .prop_map(|(pvn, owner, nft, price)| Op::Mint { pvn, owner, nft, price }),
+            2 => (1..=MAX_PVN, 0..1_000u64).prop_map(|(pvn, price)| Op::Reprice { pvn, price }),
+            2 => (1..=MAX_PVN, 0..OWNERS).prop_map(|(pvn, to)| Op::Transfer { pvn, to }),
+            1 => (1..=MAX_PVN).prop_map(|pvn| Op::Revoke { pvn }),
+        ]
+    }
+
+    struct Plot {
+        owner: usize,
+        price: u64,
+        revoked: bool,
+    }
+
+    /// What the registry should hold. Revoked titles stay on the registry, so every PVN
+    /// minted once is live for good.
+    #[derive(Default)]
+    struct Model {
+        plots: BTreeMap<u64, Plot>,
+        // NFT address number to the PVN that declared it
+        nfts: BTreeMap<u64, u64>,
+    }
+
+    impl Model {
+        /// Applies `op` when the registry should accept it, returning the error it should
+        /// raise otherwise.
+        fn apply(&mut self, op: &Op) -> Result<(), SytemapRegistryError> {
+            match *op {
+                Op::Mint { pvn, owner, nft, price } => {
+                    if self.plots.contains_key(&pvn) {
+                        return Err(SytemapRegistryError::AlreadyMinted);
+                    }
+                    if self.nfts.contains_key(&nft) {
+                        return Err(SytemapRegistryError::NftAddressAlreadyInUse);
+                    }
+                    self.plots.insert(pvn, Plot { owner, price, revoked: false });
+                    self.nfts.insert(nft, pvn);
+                }
+                Op::Reprice { pvn, price } => {
+                    if price == 0 {
+                        return Err(SytemapRegistryError::InvalidPrice);
+                    }
+                    let plot = self.plot(pvn)?;
+                    plot.price = price;
+                }
+                Op::Transfer { pvn, to } => {
+                    let plot = self.plot(pvn)?;
+                    if plot.revoked {
+                        return Err(SytemapRegistryError::PropertyNotTransferable);
+                    }
+                    plot.owner = to;
+                }
+                Op::Revoke { pvn } => self.plot(pvn)?.revoked = true,
+            }
+            Ok(())
+        }
+
+        fn plot(&mut self, pvn: u64) -> Result<&mut Plot, SytemapRegistryError> {
+            self.plots
+                .get_mut(&pvn)
+                .ok_or(SytemapRegistryError::PropertyNotFound)
+        }
+    }
+
+    fn outcome<T>(
+        result: Result<Result<T, ConversionError>, Result<SytemapRegistryError, InvokeError>>,
+    ) -> Result<(), SytemapRegistryError> {
+        match result {
+            Ok(_) => Ok(()),
+            Err(Ok(err)) => Err(err),
+            Err(Err(err)) => panic!("host error: {err:?}"),
+        }
+    }
+
+    fn nft_address(env: &Env, nft: u64) -> String {
+        String::from_str(env, &std::format!("nft-{nft}"))
+    }
+
+    fn run(
+        env: &Env,
+        contract_client: &SytemapRegistryClient,
+        owners: &[Address],
+        op: &Op,
+    ) -> Result<(), SytemapRegistryError> {
+        match *op {
+            Op::Mint { pvn, owner, nft, price } => {
+                let mut payload = create_payload(env, pvn, &owners[owner], "");
+                payload.nft_address = nft_address(env, nft);
+                payload.price_of_plot = price;
+                outcome(contract_client.try_safe_mint_new_property_info(&payload))
+            }
+            Op::Reprice { pvn, price } => {
+                outcome(contract_client.try_change_property_price_by_owner(&pvn, &price))
+            }
+            Op::Transfer { pvn, to } => {
+                outcome(contract_client.try_transfer_property_by_owner(&pvn, &owners[to]))
+            }
+            Op::Revoke { pvn } => outcome(contract_client.try_revoke_property(&pvn)),
+        }
+    }
+
+    fn check_invariants(
+        env: &Env,
+        contract_client: &SytemapRegistryClient,
+        owners: &[Address],
+        model: &Model,
+    ) -> Result<(), TestCaseError> {
+        // The minted count covers every live PVN and nothing else
+        let minted = contract_client.get_number_of_property_minted();
+        prop_assert_eq!(minted, model.plots.len() as u64);
+        let properties = contract_client.get_all_minted_property_details();
+        prop_assert_eq!(properties.len() as u64, minted);
+        for property_info in properties.iter() {
+            let plot = &model.plots[&property_info.property_verification_no];
+            prop_assert_eq!(&property_info.buyer_wallet_id, &owners[plot.owner]);
+            prop_assert_eq!(property_info.price_of_plot, plot.price);
+            prop_assert_eq!(property_info.status == PropertyStatus::Revoked, plot.revoked);
+        }
+
+        // Every NFT address resolves to the plot that declared it
+        for (&nft, &pvn) in &model.nfts {
+            let property_info = contract_client.get_property_info_by_nft_address(&nft_address(env, nft));
+            prop_assert_eq!(property_info.property_verification_no, pvn);
+        }
+
+        // Each plot is counted against exactly one owner
+        let owned: u64 = owners
+            .iter()
+            .map(|owner| contract_client.get_no_of_property_by_address(owner))
+            .sum();
+        prop_assert_eq!(owned, minted);
+
+        // Every plot of the model is minted in the same estate
+        let estate = String::from_str(env, "Estate XYZ");
+        prop_assert_eq!(contract_client.count_properties_by_estate(&estate), minted);
+
+        // The registry's own check agrees
+        let mut cursor = Some(0);
+        while let Some(position) = cursor {
+            let report = contract_client.verify_integrity(&position, &MAX_INTEGRITY_PAGE_SIZE);
+            prop_assert!(report.inconsistencies.is_empty(), "{:?}", report.inconsistencies);
+            cursor = report.next_cursor;
+        }
+
+        // Token IDs are handed out in mint order without reuse
+        let token_ids: StdVec<u64> = env.as_contract(&contract_client.address, || {
+            get_all_property_verification_numbers(env)
+                .iter()
+                .map(|pvn| get_token_id_of_pvn(env, pvn).unwrap())
+                .collect()
+        });
+        prop_assert!(token_ids.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", token_ids);
+        let next_token_id: Option<u64> = env.as_contract(&contract_client.address, || {
+            env.storage().instance().get(&SytemapDataKeys::NextTokenId)
+        });
+        if let Some(&last) = token_ids.last() {
+            prop_assert!(next_token_id.unwrap() > last);
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_registry_invariants_hold(ops in proptest::collection::vec(op(), 1..30)) {
+            let env = Env::new_with_config(EnvTestConfig {
+                capture_snapshot_at_drop: false,
+            });
+            env.mock_all_auths();
+            env.budget().reset_unlimited();
+            let contract_client = get_client(&env);
+            initialize(&env, &contract_client);
+            let owners: StdVec<Address> = (0..OWNERS).map(|_| Address::generate(&env)).collect();
+
+            let mut model = Model::default();
+            for op in &ops {
+                let expected = model.apply(op);
+                prop_assert_eq!(run(&env, &contract_client, &owners, op), expected, "{:?}", op);
+                check_invariants(&env, &contract_client, &owners, &model)?;
+            }
+        }
+    }
 }
 
 // #[test]
@@ -1,48 +1,286 @@
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
-/// Object for storing token meta data
+pub use sytemap_types::{PropertyInfo, PropertyInfoPayload, PropertyStatus, TokenMetadata};
+
+/// One page of a paginated property query
+#[derive(Debug, Clone)]
 #[contracttype]
-pub struct TokenMetadata {
-    pub sytemap_name: String,
-    pub sytemap_symbol: String,
+pub struct PropertyPage {
+    pub properties: Vec<PropertyInfo>,
+    /// Where to resume the scan, or `None` once the whole registry has been read
+    pub next_cursor: Option<u32>,
+}
 
-    /// The Asset Metadata is a URI where the metadata is hosted, this returns an String
-    pub metadata_uri: String,
+/// Figures for one estate, computed from the plots minted in it
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct EstateSummary {
+    pub estate_name: String,
+    /// Plots minted in the estate, revoked ones included
+    pub plot_count: u64,
+    /// Plots allocated to a buyer, including those being paid off, pledged or disputed
+    pub sold_count: u64,
+    /// Sum of the prices of the sold plots
+    pub total_value: u128,
+    /// Mean price of the sold plots, 0 while none is sold
+    pub average_price: u64,
+    /// Square metres sold, over the sold plots whose size reads as an area
+    pub area_sold: u64,
+    /// Sold plots whose `size_of_plot` does not read as an area, such as `irregular`
+    pub sold_without_area: u64,
 }
 
-/// Object for storing property info data
+/// Running sale totals of an estate, kept up to date as its plots change hands
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct EstateTotals {
+    pub sold_count: u64,
+    pub total_value: u128,
+    pub area_sold: u64,
+    pub sold_without_area: u64,
+}
+
+/// A plot and one of the plots it was subdivided or merged from
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct LineageLink {
+    pub property_verification_no: u64,
+    pub parent_pvn: u64,
+}
+
+/// Where a plot sits among the subdivisions and merges of its estate
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct Lineage {
+    pub property_verification_no: u64,
+    /// Links up to the plots it came from, breadth first
+    pub ancestors: Vec<LineageLink>,
+    /// Links down to the plots that came from it, breadth first
+    pub descendants: Vec<LineageLink>,
+}
+
+/// Ways the indices of a property can disagree with its record
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[contracttype]
+pub enum IndexIssue {
+    /// The PVN is listed in `AllProperties` more than once
+    DuplicateListing,
+    /// The property has a record but is missing from `AllProperties`
+    NotListed,
+    /// The PVN is indexed but its record is gone
+    MissingPropertyInfo,
+    /// The PVN has no token ID
+    MissingTokenId,
+    /// The NFT address of the record does not resolve to a token ID
+    MissingNftAddress,
+    /// The NFT address of the record resolves to another token ID
+    NftAddressMismatch,
+    /// The NFT address of the record does not resolve to a PVN
+    MissingNftAddressPvn,
+    /// The NFT address of the record resolves to another PVN
+    NftAddressPvnMismatch,
+    /// The estate and plot number of the record do not resolve to a PVN
+    MissingEstatePlot,
+    /// The PVN is missing from the index of its estate
+    NotListedInEstate,
+    /// The PVN is missing from the index of its estate company
+    NotListedForEstateCompany,
+    /// The PVN is missing from the index of its holder
+    NotListedForOwner,
+}
+
+/// An index inconsistency found by `verify_integrity`
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct IndexInconsistency {
+    pub property_verification_no: u64,
+    /// Position of the PVN in `AllProperties`
+    pub position: u32,
+    pub issue: IndexIssue,
+}
+
+/// Result of checking one slice of the registry
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct IntegrityReport {
+    /// Number of `AllProperties` entries checked
+    pub checked: u32,
+    pub inconsistencies: Vec<IndexInconsistency>,
+    /// Where to resume the check, or `None` once the whole registry has been checked
+    pub next_cursor: Option<u32>,
+}
+
+/// Timing and settlement asset of an installment plan
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PaymentSchedule {
+    /// Token contract the installments are paid in
+    pub token: Address,
+    pub installment_count: u32,
+    /// Seconds between two consecutive due dates
+    pub interval: u64,
+    /// Ledger timestamp at which the first installment falls due
+    pub first_due_date: u64,
+    /// Days an installment may be overdue before the plan can be declared in default
+    pub grace_period_days: u64,
+    /// Days after the default before the estate company may repossess the plot
+    pub notice_period_days: u64,
+    /// Share of the escrowed installments refunded to the buyer on repossession, 0 to 100
+    pub refund_percentage: u32,
+}
+
+/// Object for storing an installment plan attached to a property
 #[derive(Debug, Clone)]
 #[contracttype]
-pub struct PropertyInfo {
-    pub price_of_plot: u64,
+pub struct PaymentPlan {
     pub property_verification_no: u64,
-    pub timestamp: u64,
-    pub buyer_wallet_id: Address,
-    pub plot_no: String,
-    pub size_of_plot: String,
-    pub coordinate_of_plot: String,
-    pub token_url: String,
-    pub estate_name: String,
-    pub plot_url: String,
-    pub date_of_allocation: String,
-    pub estate_company_name: String,
-    pub nft_address: String,
+    pub seller: Address,
+    pub buyer: Address,
+    pub token: Address,
+    pub total_amount: i128,
+    pub amount_paid: i128,
+    pub installment_count: u32,
+    pub interval: u64,
+    pub first_due_date: u64,
+    pub grace_period_days: u64,
+    pub notice_period_days: u64,
+    pub refund_percentage: u32,
+    pub created_at: u64,
+    /// Ledger timestamp at which the plan was declared in default
+    pub defaulted_at: Option<u64>,
+    pub completed: bool,
 }
 
-/// Object for storing property info data
+/// Standing of an installment plan at the current ledger time
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[contracttype]
+pub enum PaymentState {
+    Current,
+    Overdue,
+    InDefault,
+    Completed,
+}
+
+/// Object returned when querying the payment status of a property
 #[derive(Debug, Clone)]
 #[contracttype]
-pub struct PropertyInfoPayload {
-    pub price_of_plot: u64,
+pub struct PaymentStatus {
     pub property_verification_no: u64,
-    pub buyer_wallet_id: Address,
-    pub plot_no: String,
-    pub size_of_plot: String,
-    pub coordinate_of_plot: String,
-    pub token_url: String,
-    pub estate_name: String,
-    pub plot_url: String,
-    pub date_of_allocation: String,
-    pub estate_company_name: String,
-    pub nft_address: String,
+    pub buyer: Address,
+    pub total_amount: i128,
+    pub amount_paid: i128,
+    pub outstanding: i128,
+    /// Amount that should have been paid by now according to the schedule
+    pub amount_due: i128,
+    /// Due date of the earliest installment that is not yet fully paid, 0 once completed
+    pub next_due_date: u64,
+    /// Whole days the earliest unpaid installment is past due
+    pub days_overdue: u64,
+    pub state: PaymentState,
+}
+
+/// Object for storing a lien registered against a property
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct Lien {
+    pub lienholder: Address,
+    pub amount: i128,
+    /// Ledger timestamp after which the lien no longer encumbers the property
+    pub expiry: u64,
+    pub registered_at: u64,
+    /// New owner the lienholder has agreed to let the property move to
+    pub consented_transferee: Option<Address>,
+}
+
+/// A tenancy on a plot. It belongs to the plot rather than its owner, so it carries over
+/// when the plot changes hands.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[contracttype]
+pub struct Lease {
+    pub tenant: Address,
+    /// Ledger timestamp the tenancy starts at
+    pub start: u64,
+    /// Ledger timestamp the tenancy ends at
+    pub end: u64,
+    /// Rent for the whole term, paid to the owner when the lease is created
+    pub rent: i128,
+    pub token: Address,
+    /// Owner who granted the lease
+    pub granted_by: Address,
+    pub created_at: u64,
+}
+
+/// Roles that can be granted by the contract admin
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[contracttype]
+pub enum Role {
+    /// May freeze disputed titles and enforce arbitration outcomes
+    Arbitrator,
+    /// May approve mint proposals
+    Verifier,
+    /// Estate company allowed to anchor documents for its plots
+    EstateCompany,
+    /// May record KYC results for buyers
+    Compliance,
+    /// May halt the registry or a single estate
+    Pauser,
+}
+
+/// Object for storing an open dispute on a property
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct DisputeRecord {
+    pub case_ref: String,
+    pub arbitrator: Address,
+    pub frozen_at: u64,
+    /// Status restored when the freeze is lifted
+    pub previous_status: PropertyStatus,
+}
+
+/// Object for storing a property awaiting verifier approval before it is minted
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct MintProposal {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub payload: PropertyInfoPayload,
+    /// Distinct verifiers that approved the proposal so far
+    pub approvals: Vec<Address>,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// Kinds of land documents that can be anchored to a property
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[contracttype]
+pub enum DocumentType {
+    SurveyPlan,
+    DeedOfAssignment,
+    CertificateOfOccupancy,
+    GovernorsConsent,
+    Receipt,
+}
+
+/// Object for storing the fingerprint of a document anchored to a property
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PropertyDocument {
+    pub doc_type: DocumentType,
+    /// SHA-256 of the document file
+    pub sha256_hash: BytesN<32>,
+    /// Where the document itself is hosted
+    pub uri: String,
+    pub attached_by: Address,
+    pub attached_at: u64,
+}
+
+/// Object for storing the KYC result of an address
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct KycStatus {
+    /// Verification tier, 0 means not verified
+    pub level: u32,
+    /// Ledger timestamp after which the verification must be renewed
+    pub expiry: u64,
+    pub updated_by: Address,
 }
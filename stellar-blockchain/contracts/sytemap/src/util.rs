@@ -1,7 +1,12 @@
 use crate::error::SytemapRegistryError;
+use crate::kyc::KycProviderClient;
 use crate::storage::SytemapDataKeys;
-use crate::types::{PropertyInfo, TokenMetadata};
-use soroban_sdk::{Env, String, Vec};
+use crate::types::{
+    DisputeRecord, DocumentType, EstateTotals, KycStatus, Lease, Lien, MintProposal, PaymentPlan,
+    PaymentState, PaymentStatus, PropertyDocument, PropertyInfo, PropertyStatus, Role,
+    TokenMetadata,
+};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 //********** Storage Utils **********//
 
@@ -17,7 +22,7 @@ pub fn get_next_token_id(env: &Env) -> Result<u64, SytemapRegistryError> {
         .instance()
         .set(&SytemapDataKeys::NextTokenId, &(next_token_id + 1));
 
-    Ok(next_token_id.try_into().unwrap())
+    Ok(next_token_id)
 }
 
 /// Initializes the token ID tracker.
@@ -31,13 +36,13 @@ pub fn initialize_token_id_tracker(env: &Env) {
 /// Checks if a PropertyInfo exists for a given property verification number.
 pub fn check_pvn_exists(env: &Env, pvn: u64) -> bool {
     let key = SytemapDataKeys::PvnToPropertyInfo;
-    env.storage().instance().has(&key(pvn))
+    env.storage().persistent().has(&key(pvn))
 }
 
 /// Checks if an NFT address to token ID mapping exists.
 pub fn check_nft_address_exists(env: &Env, nft_address: String) -> bool {
     let key = SytemapDataKeys::NftAddressToTokenId;
-    env.storage().instance().has(&key(nft_address))
+    env.storage().persistent().has(&key(nft_address))
 }
 
 /// Checks if token metadata exists.
@@ -49,7 +54,7 @@ pub fn token_metadata_exists(env: &Env) -> bool {
 /// Checks if the AllProperties key exists.
 pub fn all_properties_exists(env: &Env) -> bool {
     let key = SytemapDataKeys::AllProperties;
-    env.storage().instance().has(&key)
+    env.storage().persistent().has(&key)
 }
 
 /// Retrieves a `TokenMetadata` from storage.
@@ -64,9 +69,9 @@ pub fn get_token_metadata(env: &Env) -> Result<TokenMetadata, SytemapRegistryErr
 pub fn get_all_property_verification_numbers(env: &Env) -> Vec<u64> {
     let key = SytemapDataKeys::AllProperties;
     env.storage()
-        .instance()
+        .persistent()
         .get::<SytemapDataKeys, Vec<u64>>(&key)
-        .unwrap_or_else(|| Vec::new(&env))
+        .unwrap_or_else(|| Vec::new(env))
 }
 
 /// Saves a `PropertyInfo` to storage with a given property verification number.
@@ -76,7 +81,9 @@ pub fn mint_property_info(
     property_info: &PropertyInfo,
 ) -> Result<(), SytemapRegistryError> {
     let key = SytemapDataKeys::PvnToPropertyInfo;
-    env.storage().instance().set(&key(pvn), property_info);
+    tally_estate_sale(env, None, property_info);
+    index_owner(env, pvn, None, property_info);
+    env.storage().persistent().set(&key(pvn), property_info);
     Ok(())
 }
 
@@ -92,20 +99,27 @@ pub fn save_nft_address_to_token_id(
     nft_address: String,
     token_id: u64,
 ) -> Result<(), SytemapRegistryError> {
-    env.storage().instance().set(
+    env.storage().persistent().set(
         &SytemapDataKeys::NftAddressToTokenId(nft_address),
         &token_id,
     );
     Ok(())
 }
 
+/// Saves an NFT address to property verification number mapping to storage.
+pub fn save_nft_address_to_pvn(env: &Env, nft_address: String, pvn: u64) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::NftAddressToPvn(nft_address), &pvn);
+}
+
 /// Updates the list of all properties in storage.
 pub fn update_all_properties(
     env: &Env,
     all_properties: Vec<u64>,
 ) -> Result<(), SytemapRegistryError> {
     env.storage()
-        .instance()
+        .persistent()
         .set(&SytemapDataKeys::AllProperties, &all_properties);
     Ok(())
 }
@@ -116,8 +130,11 @@ pub fn save_property_info(
     pvn: u64,
     property_info: &PropertyInfo,
 ) -> Result<(), SytemapRegistryError> {
+    let previous = get_property_info(env, pvn).ok();
+    tally_estate_sale(env, previous.as_ref(), property_info);
+    index_owner(env, pvn, previous.as_ref(), property_info);
     env.storage()
-        .instance()
+        .persistent()
         .set(&SytemapDataKeys::PvnToPropertyInfo(pvn), property_info);
     Ok(())
 }
@@ -128,17 +145,40 @@ pub fn save_pvn_to_token_id(
     pvn: u64,
     token_id: u64,
 ) -> Result<(), SytemapRegistryError> {
-    env.storage().instance().set(
+    env.storage().persistent().set(
         &SytemapDataKeys::PropertyVerificationNoToTokenId(pvn),
         &token_id,
     );
     Ok(())
 }
 
+/// Retrieve the token ID a property verification number was minted as.
+pub fn get_token_id_of_pvn(env: &Env, pvn: u64) -> Result<u64, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, u64>(&SytemapDataKeys::PropertyVerificationNoToTokenId(pvn))
+        .ok_or(SytemapRegistryError::PropertyNotFound)
+}
+
+/// Remove the token ID mapping of a property verification number.
+pub fn remove_pvn_to_token_id(env: &Env, pvn: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::PropertyVerificationNoToTokenId(pvn));
+}
+
+/// Finds the listed property that was minted as `token_id`. This scans every listed
+/// property, so it is only meant for index repairs.
+pub fn find_pvn_by_token_id(env: &Env, token_id: u64) -> Option<u64> {
+    get_all_property_verification_numbers(env)
+        .iter()
+        .find(|pvn| get_token_id_of_pvn(env, *pvn) == Ok(token_id))
+}
+
 /// Retrieve property info from storage.
 pub fn get_property_info(env: &Env, pvn: u64) -> Result<PropertyInfo, SytemapRegistryError> {
     env.storage()
-        .instance()
+        .persistent()
         .get::<SytemapDataKeys, PropertyInfo>(&SytemapDataKeys::PvnToPropertyInfo(pvn))
         .ok_or(SytemapRegistryError::PropertyNotFound)
 }
@@ -149,20 +189,1009 @@ pub fn get_nft_address_to_token_id(
     nft_address: String,
 ) -> Result<u64, SytemapRegistryError> {
     env.storage()
-        .instance()
+        .persistent()
         .get::<SytemapDataKeys, u64>(&SytemapDataKeys::NftAddressToTokenId(nft_address))
         .ok_or(SytemapRegistryError::PropertyNotFound)
 }
 
+/// Retrieve the property verification number holding a given NFT address.
+pub fn get_nft_address_to_pvn(env: &Env, nft_address: String) -> Result<u64, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, u64>(&SytemapDataKeys::NftAddressToPvn(nft_address))
+        .ok_or(SytemapRegistryError::PropertyNotFound)
+}
+
 /// Retrieves a `PropertyInfo` from storage by its property verification number.
 pub fn get_property_info_by_pvn(
     env: &Env,
     property_verification_no: u64,
 ) -> Result<PropertyInfo, SytemapRegistryError> {
     env.storage()
-        .instance()
+        .persistent()
         .get::<SytemapDataKeys, PropertyInfo>(&SytemapDataKeys::PvnToPropertyInfo(
             property_verification_no,
         ))
         .ok_or(SytemapRegistryError::PropertyNotFound)
 }
+
+/// Retrieve the PVN of the plot numbered `plot_no` in an estate.
+pub fn get_estate_plot_pvn(
+    env: &Env,
+    estate_name: &String,
+    plot_no: &String,
+) -> Result<u64, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, u64>(&SytemapDataKeys::EstatePlot(
+            estate_name.clone(),
+            plot_no.clone(),
+        ))
+        .ok_or(SytemapRegistryError::PropertyNotFound)
+}
+
+/// Save the PVN of the plot numbered `plot_no` in an estate.
+pub fn save_estate_plot_pvn(env: &Env, estate_name: &String, plot_no: &String, pvn: u64) {
+    env.storage().persistent().set(
+        &SytemapDataKeys::EstatePlot(estate_name.clone(), plot_no.clone()),
+        &pvn,
+    );
+}
+
+/// Retrieve the PVNs minted in an estate, oldest first.
+pub fn get_estate_properties(env: &Env, estate_name: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::EstateProperties(estate_name.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the PVNs minted in an estate.
+pub fn save_estate_properties(env: &Env, estate_name: &String, pvns: &Vec<u64>) {
+    env.storage().persistent().set(
+        &SytemapDataKeys::EstateProperties(estate_name.clone()),
+        pvns,
+    );
+}
+
+/// Retrieve the PVNs minted by an estate company, oldest first.
+pub fn get_estate_company_properties(env: &Env, estate_company_name: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::EstateCompanyProperties(
+            estate_company_name.clone(),
+        ))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the PVNs minted by an estate company.
+pub fn save_estate_company_properties(env: &Env, estate_company_name: &String, pvns: &Vec<u64>) {
+    env.storage().persistent().set(
+        &SytemapDataKeys::EstateCompanyProperties(estate_company_name.clone()),
+        pvns,
+    );
+}
+
+/// Retrieve the running sale totals of an estate.
+pub fn get_estate_totals(env: &Env, estate_name: &String) -> EstateTotals {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, EstateTotals>(&SytemapDataKeys::EstateTotals(estate_name.clone()))
+        .unwrap_or_default()
+}
+
+/// Save the running sale totals of an estate.
+pub fn save_estate_totals(env: &Env, estate_name: &String, totals: &EstateTotals) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::EstateTotals(estate_name.clone()), totals);
+}
+
+/// Moves the sale totals of the plot's estate from its `previous` record to the one
+/// replacing it, so estate summaries never have to read every plot.
+fn tally_estate_sale(env: &Env, previous: Option<&PropertyInfo>, property_info: &PropertyInfo) {
+    let sale = |info: &PropertyInfo| {
+        is_sold(info.status).then(|| {
+            (
+                info.estate_name.clone(),
+                info.price_of_plot,
+                parse_plot_area(&info.size_of_plot),
+            )
+        })
+    };
+    let before = previous.and_then(sale);
+    let after = sale(property_info);
+    if before == after {
+        return;
+    }
+
+    if let Some((estate_name, price, area)) = before {
+        let mut totals = get_estate_totals(env, &estate_name);
+        totals.sold_count = totals.sold_count.saturating_sub(1);
+        totals.total_value = totals.total_value.saturating_sub(price as u128);
+        match area {
+            Some(area) => totals.area_sold = totals.area_sold.saturating_sub(area),
+            None => totals.sold_without_area = totals.sold_without_area.saturating_sub(1),
+        }
+        save_estate_totals(env, &estate_name, &totals);
+    }
+    if let Some((estate_name, price, area)) = after {
+        let mut totals = get_estate_totals(env, &estate_name);
+        totals.sold_count += 1;
+        totals.total_value += price as u128;
+        match area {
+            Some(area) => totals.area_sold = totals.area_sold.saturating_add(area),
+            None => totals.sold_without_area += 1,
+        }
+        save_estate_totals(env, &estate_name, &totals);
+    }
+}
+
+/// Retrieve the PVNs held by an address, in the order it came to hold them.
+pub fn get_owner_properties(env: &Env, owner: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::OwnerProperties(owner.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the PVNs held by an address.
+pub fn save_owner_properties(env: &Env, owner: &Address, pvns: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::OwnerProperties(owner.clone()), pvns);
+}
+
+/// Moves a plot from the index of its `previous` holder to the one of the holder its new
+/// record names.
+fn index_owner(env: &Env, pvn: u64, previous: Option<&PropertyInfo>, property_info: &PropertyInfo) {
+    let owner = &property_info.buyer_wallet_id;
+    if let Some(previous) = previous {
+        if previous.buyer_wallet_id == *owner {
+            return;
+        }
+        let mut pvns = get_owner_properties(env, &previous.buyer_wallet_id);
+        if let Some(position) = pvns.first_index_of(pvn) {
+            pvns.remove(position);
+            save_owner_properties(env, &previous.buyer_wallet_id, &pvns);
+        }
+    }
+
+    let mut pvns = get_owner_properties(env, owner);
+    if !pvns.contains(pvn) {
+        pvns.push_back(pvn);
+        save_owner_properties(env, owner, &pvns);
+    }
+}
+
+/// Retrieve the PVNs of the plots a plot was subdivided or merged from.
+pub fn get_parent_pvns(env: &Env, pvn: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::ParentPvns(pvn))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the PVNs of the plots a plot was subdivided or merged from.
+pub fn save_parent_pvns(env: &Env, pvn: u64, parent_pvns: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::ParentPvns(pvn), parent_pvns);
+}
+
+/// Retrieve the PVNs of the plots a plot was subdivided or merged into.
+pub fn get_child_pvns(env: &Env, pvn: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::ChildPvns(pvn))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the PVNs of the plots a plot was subdivided or merged into.
+pub fn save_child_pvns(env: &Env, pvn: u64, child_pvns: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::ChildPvns(pvn), child_pvns);
+}
+
+/// Longest `size_of_plot` that is read as an area
+const MAX_PLOT_SIZE_LEN: usize = 32;
+
+/// Reads the area in square metres out of a `size_of_plot` such as `600sqm`, `600 m2` or
+/// `20x30`. Returns `None` for anything else.
+pub fn parse_plot_area(size_of_plot: &String) -> Option<u64> {
+    let len = size_of_plot.len() as usize;
+    if len > MAX_PLOT_SIZE_LEN {
+        return None;
+    }
+    let mut buf = [0u8; MAX_PLOT_SIZE_LEN];
+    size_of_plot.copy_into_slice(&mut buf[..len]);
+
+    let (area, rest) = split_number(buf[..len].trim_ascii())?;
+    let rest = rest.trim_ascii_start();
+    if rest.is_empty() || rest.eq_ignore_ascii_case(b"sqm") || rest.eq_ignore_ascii_case(b"m2") {
+        return Some(area);
+    }
+    let rest = rest
+        .strip_prefix(b"x")
+        .or_else(|| rest.strip_prefix(b"X"))?;
+    let (length, rest) = split_number(rest.trim_ascii_start())?;
+    if !rest.is_empty() {
+        return None;
+    }
+    area.checked_mul(length)
+}
+
+/// Splits the leading decimal digits off `bytes`.
+fn split_number(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let digits = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    let mut number: u64 = 0;
+    for byte in &bytes[..digits] {
+        number = number.checked_mul(10)?.checked_add((byte - b'0') as u64)?;
+    }
+    Some((number, &bytes[digits..]))
+}
+
+/// Longest `coordinate_of_plot` that is read as a location
+const MAX_COORDINATE_LEN: usize = 512;
+
+/// Most corners an outline may have
+pub const MAX_OUTLINE_POINTS: usize = 24;
+
+/// Millionths of a degree in one degree
+const MICRODEGREES: i64 = 1_000_000;
+
+/// Latitude and longitude, in millionths of a degree
+pub type GeoPoint = (i64, i64);
+
+/// Reads a `coordinate_of_plot` made of `;` separated `lat,lon` pairs, such as
+/// `6.5244N,3.3792E` for a single point or `6.52,3.37;6.53,3.37;6.53,3.38` for the corners
+/// of an outline. Degrees may be signed or carry a hemisphere letter. Returns the number of
+/// points read into `points`, or `None` if the string is not a location.
+pub fn parse_plot_outline(
+    coordinate_of_plot: &String,
+    points: &mut [GeoPoint; MAX_OUTLINE_POINTS],
+) -> Option<usize> {
+    let len = coordinate_of_plot.len() as usize;
+    if len > MAX_COORDINATE_LEN {
+        return None;
+    }
+    let mut buf = [0u8; MAX_COORDINATE_LEN];
+    coordinate_of_plot.copy_into_slice(&mut buf[..len]);
+
+    let mut count = 0;
+    for pair in buf[..len].split(|byte| *byte == b';') {
+        let mut parts = pair.split(|byte| *byte == b',');
+        let (Some(lat), Some(lon), None) = (parts.next(), parts.next(), parts.next()) else {
+            return None;
+        };
+        let point = (
+            parse_degrees(lat, b'N', b'S')?,
+            parse_degrees(lon, b'E', b'W')?,
+        );
+        *points.get_mut(count)? = point;
+        count += 1;
+    }
+    Some(count)
+}
+
+/// Reads `-6.5244`, `6.5244` or `6.5244S` as millionths of a degree. Digits past the
+/// sixth decimal are dropped.
+fn parse_degrees(bytes: &[u8], positive: u8, negative: u8) -> Option<i64> {
+    let mut bytes = bytes.trim_ascii();
+    let mut sign = 1;
+    if let Some(rest) = bytes.strip_prefix(b"-") {
+        bytes = rest;
+        sign = -1;
+    }
+    match bytes.last().map(u8::to_ascii_uppercase) {
+        Some(letter) if letter == positive => bytes = &bytes[..bytes.len() - 1],
+        Some(letter) if letter == negative && sign == 1 => {
+            bytes = &bytes[..bytes.len() - 1];
+            sign = -1;
+        }
+        _ => {}
+    }
+
+    let (whole, rest) = split_number(bytes.trim_ascii_end())?;
+    let mut value = (whole as i64).checked_mul(MICRODEGREES)?;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let (_, tail) = split_number(fraction)?;
+        if !tail.is_empty() {
+            return None;
+        }
+        let mut scale = MICRODEGREES / 10;
+        for byte in fraction.iter().take(6) {
+            value += (byte - b'0') as i64 * scale;
+            scale /= 10;
+        }
+    } else if !rest.is_empty() {
+        return None;
+    }
+    Some(sign * value)
+}
+
+/// Whether `point` lies inside `outline` or on its edge.
+pub fn outline_contains(outline: &[GeoPoint], point: GeoPoint) -> bool {
+    let (y, x) = (point.0 as i128, point.1 as i128);
+    let mut inside = false;
+    for (index, a) in outline.iter().enumerate() {
+        let b = outline[(index + 1) % outline.len()];
+        let (ay, ax, by, bx) = (a.0 as i128, a.1 as i128, b.0 as i128, b.1 as i128);
+
+        let cross = (bx - ax) * (y - ay) - (by - ay) * (x - ax);
+        if cross == 0 && x >= ax.min(bx) && x <= ax.max(bx) && y >= ay.min(by) && y <= ay.max(by) {
+            return true;
+        }
+        if (ay > y) != (by > y) {
+            // Whether the edge crosses the ray running east of `point`
+            let crosses = if by > ay { cross > 0 } else { cross < 0 };
+            if crosses {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Twice the area enclosed by `outline`, in square millionths of a degree. Doubling keeps
+/// the shoelace sum whole.
+pub fn doubled_outline_area(outline: &[GeoPoint]) -> i128 {
+    signed_doubled_area(outline).abs()
+}
+
+/// Shoelace sum of `outline`, positive when its corners run counterclockwise.
+fn signed_doubled_area(outline: &[GeoPoint]) -> i128 {
+    let mut sum: i128 = 0;
+    for (index, a) in outline.iter().enumerate() {
+        let b = outline[(index + 1) % outline.len()];
+        sum += a.1 as i128 * b.0 as i128 - b.1 as i128 * a.0 as i128;
+    }
+    sum
+}
+
+/// Which side of the line through `a` and `b` the point `p` lies on: positive to the left,
+/// zero on the line.
+fn turn(a: GeoPoint, b: GeoPoint, p: GeoPoint) -> i128 {
+    (b.1 as i128 - a.1 as i128) * (p.0 as i128 - a.0 as i128)
+        - (b.0 as i128 - a.0 as i128) * (p.1 as i128 - a.1 as i128)
+}
+
+/// How far along the direction from `a` to `b` the point `p` lies, scaled by the length of
+/// that direction.
+fn along(a: GeoPoint, b: GeoPoint, p: GeoPoint) -> i128 {
+    (b.0 as i128 - a.0 as i128) * (p.0 as i128 - a.0 as i128)
+        + (b.1 as i128 - a.1 as i128) * (p.1 as i128 - a.1 as i128)
+}
+
+/// Whether `p` lies on the segment from `a` to `b`, ends included.
+fn on_segment(a: GeoPoint, b: GeoPoint, p: GeoPoint) -> bool {
+    turn(a, b, p) == 0 && (0..=along(a, b, b)).contains(&along(a, b, p))
+}
+
+/// Whether `point` lies strictly inside `outline`, off its edges.
+fn outline_holds(outline: &[GeoPoint], point: GeoPoint) -> bool {
+    outline_contains(outline, point)
+        && !(0..outline.len())
+            .any(|index| on_segment(outline[index], outline[(index + 1) % outline.len()], point))
+}
+
+/// Whether some stretch of an edge of `inner` runs strictly inside `outer`. Each edge is cut
+/// at the corners of `outer` lying on it and the middle of every piece is tested. Both
+/// outlines are given at twice their scale, so the middles stay whole.
+fn edges_enter(outer: &[GeoPoint], inner: &[GeoPoint]) -> bool {
+    for (index, a) in inner.iter().enumerate() {
+        let b = inner[(index + 1) % inner.len()];
+        let mut cuts = [*a; MAX_OUTLINE_POINTS + 2];
+        cuts[1] = b;
+        let mut cut_count = 2;
+        for corner in outer {
+            if on_segment(*a, b, *corner) {
+                cuts[cut_count] = *corner;
+                cut_count += 1;
+            }
+        }
+        let cuts = &mut cuts[..cut_count];
+        cuts.sort_unstable_by_key(|cut| along(*a, b, *cut));
+        for pair in cuts.windows(2) {
+            let middle = ((pair[0].0 + pair[1].0) / 2, (pair[0].1 + pair[1].1) / 2);
+            if outline_holds(outer, middle) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Whether the outlines `first` and `second` share any area. Touching along an edge or at
+/// a corner does not count.
+pub fn outlines_overlap(first: &[GeoPoint], second: &[GeoPoint]) -> bool {
+    let first_turn = signed_doubled_area(first).signum();
+    let second_turn = signed_doubled_area(second).signum();
+    for (index, a) in first.iter().enumerate() {
+        let b = first[(index + 1) % first.len()];
+        for (other, c) in second.iter().enumerate() {
+            let d = second[(other + 1) % second.len()];
+            let (c_side, d_side) = (turn(*a, b, *c), turn(*a, b, d));
+            // Edges crossing in the middle of both
+            if c_side.signum() * d_side.signum() < 0
+                && turn(*c, d, *a).signum() * turn(*c, d, b).signum() < 0
+            {
+                return true;
+            }
+            // Edges running along each other with both insides on the same side
+            if c_side == 0 && d_side == 0 {
+                let (c_at, d_at) = (along(*a, b, *c), along(*a, b, d));
+                let shared = c_at.max(d_at).min(along(*a, b, b)) - c_at.min(d_at).max(0);
+                let same_way = (d_at - c_at).signum();
+                if shared > 0 && first_turn * second_turn * same_way > 0 {
+                    return true;
+                }
+            }
+        }
+    }
+
+    let mut first_doubled = [(0, 0); MAX_OUTLINE_POINTS];
+    let mut second_doubled = [(0, 0); MAX_OUTLINE_POINTS];
+    for (doubled, corner) in first_doubled.iter_mut().zip(first) {
+        *doubled = (corner.0 * 2, corner.1 * 2);
+    }
+    for (doubled, corner) in second_doubled.iter_mut().zip(second) {
+        *doubled = (corner.0 * 2, corner.1 * 2);
+    }
+    let (first, second) = (
+        &first_doubled[..first.len()],
+        &second_doubled[..second.len()],
+    );
+    edges_enter(first, second) || edges_enter(second, first)
+}
+
+//********** Payment Plan Utils **********//
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Longest grace or notice period an installment plan may set, in days.
+pub const MAX_PAYMENT_PERIOD_DAYS: u64 = 36_500;
+
+/// Largest total an installment plan may ask for, so that a refund percentage of any
+/// amount paid towards it can be worked out.
+pub const MAX_PAYMENT_PLAN_TOTAL: i128 = i128::MAX / 100;
+
+/// Retrieve the installment plan of a property from storage.
+pub fn get_payment_plan(env: &Env, pvn: u64) -> Result<PaymentPlan, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, PaymentPlan>(&SytemapDataKeys::PaymentPlan(pvn))
+        .ok_or(SytemapRegistryError::PaymentPlanNotFound)
+}
+
+/// Save the installment plan of a property to storage.
+pub fn save_payment_plan(env: &Env, plan: &PaymentPlan) {
+    env.storage().persistent().set(
+        &SytemapDataKeys::PaymentPlan(plan.property_verification_no),
+        plan,
+    );
+}
+
+/// Remove the installment plan of a property from storage.
+pub fn remove_payment_plan(env: &Env, pvn: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::PaymentPlan(pvn));
+}
+
+/// Amount of a regular installment. The last installment also carries the remainder.
+pub fn installment_amount(plan: &PaymentPlan) -> i128 {
+    plan.total_amount / plan.installment_count as i128
+}
+
+/// Total amount that falls due on or before `now` according to the plan schedule.
+pub fn amount_due_at(plan: &PaymentPlan, now: u64) -> i128 {
+    if now < plan.first_due_date {
+        return 0;
+    }
+    let elapsed_installments = match plan.interval {
+        0 => plan.installment_count as u64,
+        interval => (now - plan.first_due_date) / interval + 1,
+    };
+    if elapsed_installments >= plan.installment_count as u64 {
+        plan.total_amount
+    } else {
+        installment_amount(plan) * elapsed_installments as i128
+    }
+}
+
+/// Due date of the installment at `index`, counting from zero, or `None` if it falls past
+/// the end of time.
+pub fn installment_due_date(plan: &PaymentPlan, index: u64) -> Option<u64> {
+    index
+        .checked_mul(plan.interval)
+        .and_then(|offset| plan.first_due_date.checked_add(offset))
+}
+
+/// Due date of the earliest installment that has not been fully paid.
+pub fn next_due_date(plan: &PaymentPlan) -> Result<u64, SytemapRegistryError> {
+    let paid_installments = (plan.amount_paid / installment_amount(plan)) as u64;
+    let last_installment = plan.installment_count as u64 - 1;
+    installment_due_date(plan, paid_installments.min(last_installment))
+        .ok_or(SytemapRegistryError::InvalidPaymentPlan)
+}
+
+/// Computes the standing of an installment plan at the current ledger time.
+pub fn compute_payment_status(
+    env: &Env,
+    plan: &PaymentPlan,
+) -> Result<PaymentStatus, SytemapRegistryError> {
+    let now = env.ledger().timestamp();
+    let outstanding = plan.total_amount - plan.amount_paid;
+
+    let (next_due, days_overdue, state) = if plan.completed {
+        (0, 0, PaymentState::Completed)
+    } else {
+        let next_due = next_due_date(plan)?;
+        if now > next_due {
+            let state = match plan.defaulted_at {
+                Some(_) => PaymentState::InDefault,
+                None => PaymentState::Overdue,
+            };
+            (next_due, (now - next_due) / SECONDS_PER_DAY, state)
+        } else {
+            (next_due, 0, PaymentState::Current)
+        }
+    };
+
+    Ok(PaymentStatus {
+        property_verification_no: plan.property_verification_no,
+        buyer: plan.buyer.clone(),
+        total_amount: plan.total_amount,
+        amount_paid: plan.amount_paid,
+        outstanding,
+        amount_due: amount_due_at(plan, now),
+        next_due_date: next_due,
+        days_overdue,
+        state,
+    })
+}
+
+//********** Lien Utils **********//
+
+/// Retrieve every lien registered against a property, including expired ones.
+pub fn get_liens(env: &Env, pvn: u64) -> Vec<Lien> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<Lien>>(&SytemapDataKeys::Liens(pvn))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Retrieve the liens that still encumber a property at the current ledger time.
+pub fn get_active_liens(env: &Env, pvn: u64) -> Vec<Lien> {
+    let now = env.ledger().timestamp();
+    let mut active_liens = Vec::new(env);
+    for lien in get_liens(env, pvn).iter() {
+        if lien.expiry > now {
+            active_liens.push_back(lien);
+        }
+    }
+    active_liens
+}
+
+/// Save the liens of a property, dropping the key once none are left.
+pub fn save_liens(env: &Env, pvn: u64, liens: &Vec<Lien>) {
+    let key = SytemapDataKeys::Liens(pvn);
+    if liens.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, liens);
+    }
+}
+
+//********** Lease Utils **********//
+
+/// Retrieve the lease of a property unless its term has ended at the current ledger time.
+pub fn get_lease(env: &Env, pvn: u64) -> Option<Lease> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Lease>(&SytemapDataKeys::Lease(pvn))
+        .filter(|lease| lease.end > env.ledger().timestamp())
+}
+
+/// Save the lease of a property.
+pub fn save_lease(env: &Env, pvn: u64, lease: &Lease) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::Lease(pvn), lease);
+}
+
+/// Remove the lease of a property.
+pub fn remove_lease(env: &Env, pvn: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::Lease(pvn));
+}
+
+//********** Collateral Utils **********//
+
+/// Retrieve the contract a property is locked to as collateral.
+pub fn get_collateral_locker(env: &Env, pvn: u64) -> Result<Address, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Address>(&SytemapDataKeys::CollateralLocker(pvn))
+        .ok_or(SytemapRegistryError::PropertyNotLocked)
+}
+
+/// Save the contract a property is locked to as collateral.
+pub fn save_collateral_locker(env: &Env, pvn: u64, locker: &Address) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::CollateralLocker(pvn), locker);
+}
+
+/// Remove the collateral lock of a property.
+pub fn remove_collateral_locker(env: &Env, pvn: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::CollateralLocker(pvn));
+}
+
+//********** Access Control Utils **********//
+
+/// Retrieve the contract admin.
+pub fn get_admin(env: &Env) -> Result<Address, SytemapRegistryError> {
+    env.storage()
+        .instance()
+        .get::<SytemapDataKeys, Address>(&SytemapDataKeys::Admin)
+        .ok_or(SytemapRegistryError::Unauthorized)
+}
+
+/// Save the contract admin.
+pub fn save_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&SytemapDataKeys::Admin, admin);
+}
+
+/// Checks if an address holds a role.
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&SytemapDataKeys::Role(role, account.clone()))
+}
+
+/// Grants or revokes a role for an address.
+pub fn set_role(env: &Env, role: Role, account: &Address, granted: bool) {
+    let key = SytemapDataKeys::Role(role, account.clone());
+    if granted {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Retrieve the account bound to an estate company name, if any.
+pub fn get_estate_company_account(env: &Env, estate_company_name: &String) -> Option<Address> {
+    env.storage().instance().get::<SytemapDataKeys, Address>(
+        &SytemapDataKeys::EstateCompanyAccount(estate_company_name.clone()),
+    )
+}
+
+/// Bind an account to an estate company name.
+pub fn save_estate_company_account(env: &Env, estate_company_name: &String, account: &Address) {
+    env.storage().instance().set(
+        &SytemapDataKeys::EstateCompanyAccount(estate_company_name.clone()),
+        account,
+    );
+}
+
+//********** Dispute Utils **********//
+
+/// Retrieve the open dispute on a property.
+pub fn get_dispute(env: &Env, pvn: u64) -> Result<DisputeRecord, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, DisputeRecord>(&SytemapDataKeys::Dispute(pvn))
+        .ok_or(SytemapRegistryError::PropertyNotFrozen)
+}
+
+/// Save the open dispute on a property.
+pub fn save_dispute(env: &Env, pvn: u64, dispute: &DisputeRecord) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::Dispute(pvn), dispute);
+}
+
+/// Remove the open dispute on a property.
+pub fn remove_dispute(env: &Env, pvn: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::Dispute(pvn));
+}
+
+//********** Mint Proposal Utils **********//
+
+/// Default lifetime of a mint proposal: seven days.
+pub const DEFAULT_MINT_PROPOSAL_TTL: u64 = 7 * SECONDS_PER_DAY;
+
+/// Longest a mint proposal may stay open: a year.
+pub const MAX_MINT_PROPOSAL_TTL: u64 = 365 * SECONDS_PER_DAY;
+
+/// Most properties a paginated query returns at once. Each one is a ledger entry of
+/// its own, so a page has to stay well inside the entries a transaction may read.
+pub const MAX_PAGE_SIZE: u32 = 30;
+
+/// Most properties `verify_integrity` checks at once, as each check reads six entries
+pub const MAX_INTEGRITY_PAGE_SIZE: u32 = 5;
+
+/// Most mint proposals that may await approval at once
+pub const MAX_PENDING_MINTS: u32 = 100;
+
+/// Number of distinct verifier approvals needed to mint, 0 when direct minting is allowed.
+pub fn get_mint_approval_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get::<SytemapDataKeys, u32>(&SytemapDataKeys::MintApprovalThreshold)
+        .unwrap_or(0)
+}
+
+/// Seconds a mint proposal stays open for approvals.
+pub fn get_mint_proposal_ttl(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get::<SytemapDataKeys, u64>(&SytemapDataKeys::MintProposalTtl)
+        .unwrap_or(DEFAULT_MINT_PROPOSAL_TTL)
+}
+
+/// Save the mint approval threshold and proposal lifetime.
+pub fn save_mint_approval_config(env: &Env, threshold: u32, proposal_ttl: u64) {
+    env.storage()
+        .instance()
+        .set(&SytemapDataKeys::MintApprovalThreshold, &threshold);
+    env.storage()
+        .instance()
+        .set(&SytemapDataKeys::MintProposalTtl, &proposal_ttl);
+}
+
+/// Retrieves the next available mint proposal ID from storage, and increments it.
+pub fn get_next_mint_proposal_id(env: &Env) -> u64 {
+    let next_proposal_id = env
+        .storage()
+        .instance()
+        .get::<SytemapDataKeys, u64>(&SytemapDataKeys::NextMintProposalId)
+        .unwrap_or(1);
+
+    env.storage().instance().set(
+        &SytemapDataKeys::NextMintProposalId,
+        &(next_proposal_id + 1),
+    );
+
+    next_proposal_id
+}
+
+/// Retrieve a mint proposal from storage.
+pub fn get_mint_proposal(
+    env: &Env,
+    proposal_id: u64,
+) -> Result<MintProposal, SytemapRegistryError> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, MintProposal>(&SytemapDataKeys::MintProposal(proposal_id))
+        .ok_or(SytemapRegistryError::MintProposalNotFound)
+}
+
+/// Save a mint proposal to storage.
+pub fn save_mint_proposal(env: &Env, proposal: &MintProposal) {
+    env.storage().persistent().set(
+        &SytemapDataKeys::MintProposal(proposal.proposal_id),
+        proposal,
+    );
+}
+
+/// Remove a mint proposal from storage.
+pub fn remove_mint_proposal(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&SytemapDataKeys::MintProposal(proposal_id));
+}
+
+/// Retrieve the ids of mint proposals awaiting approval.
+pub fn get_pending_mint_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<u64>>(&SytemapDataKeys::PendingMints)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the ids of mint proposals awaiting approval.
+pub fn save_pending_mint_ids(env: &Env, pending_mints: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::PendingMints, pending_mints);
+}
+
+//********** Property Status Utils **********//
+
+/// Checks the status transition table. Every live status may be frozen, and a freeze is
+/// only lifted back to the status it interrupted. Revoked, subdivided and merged are
+/// terminal.
+pub fn is_valid_status_transition(from: PropertyStatus, to: PropertyStatus) -> bool {
+    use PropertyStatus::*;
+
+    matches!(
+        (from, to),
+        (Draft, PendingSurvey | Frozen | Revoked)
+            | (PendingSurvey, Verified | Draft | Frozen | Revoked)
+            | (
+                Verified,
+                Allocated | Encumbered | PendingSurvey | Frozen | Revoked
+            )
+            | (
+                Allocated,
+                PendingSurvey | Encumbered | InPayment | Subdivided | Merged | Frozen | Revoked
+            )
+            | (Encumbered, Allocated | InPayment | Frozen | Revoked)
+            | (InPayment, Allocated | Encumbered | InDefault | Frozen)
+            | (InDefault, Allocated | Encumbered | InPayment | Frozen)
+            | (
+                Frozen,
+                Draft | PendingSurvey | Verified | Allocated | Encumbered | InPayment | InDefault
+            )
+    )
+}
+
+/// Whether a property has been allocated to a buyer, including while it is paid off,
+/// pledged or disputed.
+pub fn is_sold(status: PropertyStatus) -> bool {
+    use PropertyStatus::*;
+
+    !matches!(
+        status,
+        Draft | PendingSurvey | Verified | Revoked | Subdivided | Merged
+    )
+}
+
+//********** Document Utils **********//
+
+/// Most documents that may be anchored to one property, superseded versions included
+pub const MAX_DOCUMENTS_PER_PLOT: u32 = 50;
+
+/// Retrieve every document anchored to a property, oldest first.
+pub fn get_documents(env: &Env, pvn: u64) -> Vec<PropertyDocument> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, Vec<PropertyDocument>>(&SytemapDataKeys::Documents(pvn))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Save the documents anchored to a property.
+pub fn save_documents(env: &Env, pvn: u64, documents: &Vec<PropertyDocument>) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::Documents(pvn), documents);
+}
+
+/// Checks a hash against the latest document of a given type. Earlier versions that
+/// have been superseded no longer verify.
+pub fn matches_latest_document(
+    env: &Env,
+    pvn: u64,
+    doc_type: DocumentType,
+    sha256_hash: &BytesN<32>,
+) -> bool {
+    get_documents(env, pvn)
+        .iter()
+        .filter(|document| document.doc_type == doc_type)
+        .last()
+        .map(|document| document.sha256_hash == *sha256_hash)
+        .unwrap_or(false)
+}
+
+//********** KYC Utils **********//
+
+/// Retrieve the KYC result recorded for an address.
+pub fn get_kyc_status(env: &Env, account: &Address) -> Option<KycStatus> {
+    env.storage()
+        .persistent()
+        .get::<SytemapDataKeys, KycStatus>(&SytemapDataKeys::Kyc(account.clone()))
+}
+
+/// Save the KYC result of an address.
+pub fn save_kyc_status(env: &Env, account: &Address, kyc_status: &KycStatus) {
+    env.storage()
+        .persistent()
+        .set(&SytemapDataKeys::Kyc(account.clone()), kyc_status);
+}
+
+/// Retrieve the external KYC contract, if one is configured.
+pub fn get_kyc_provider(env: &Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get::<SytemapDataKeys, Address>(&SytemapDataKeys::KycProvider)
+}
+
+/// Save or clear the external KYC contract.
+pub fn save_kyc_provider(env: &Env, provider: &Option<Address>) {
+    match provider {
+        Some(provider) => env
+            .storage()
+            .instance()
+            .set(&SytemapDataKeys::KycProvider, provider),
+        None => env
+            .storage()
+            .instance()
+            .remove(&SytemapDataKeys::KycProvider),
+    }
+}
+
+/// Minimum KYC level an estate requires of recipients, 0 when the estate opted out.
+pub fn get_estate_kyc_level(env: &Env, estate_name: &String) -> u32 {
+    env.storage()
+        .instance()
+        .get::<SytemapDataKeys, u32>(&SytemapDataKeys::EstateKycLevel(estate_name.clone()))
+        .unwrap_or(0)
+}
+
+/// Save the minimum KYC level an estate requires of recipients.
+pub fn save_estate_kyc_level(env: &Env, estate_name: &String, min_level: u32) {
+    let key = SytemapDataKeys::EstateKycLevel(estate_name.clone());
+    if min_level == 0 {
+        env.storage().instance().remove(&key);
+    } else {
+        env.storage().instance().set(&key, &min_level);
+    }
+}
+
+/// Checks if an address may receive a plot in the given estate. The registry's own
+/// KYC records are consulted first, then the external provider if one is configured.
+pub fn is_recipient_verified(env: &Env, estate_name: &String, recipient: &Address) -> bool {
+    let min_level = get_estate_kyc_level(env, estate_name);
+    if min_level == 0 {
+        return true;
+    }
+    if let Some(kyc_status) = get_kyc_status(env, recipient) {
+        if kyc_status.level >= min_level && kyc_status.expiry > env.ledger().timestamp() {
+            return true;
+        }
+    }
+    match get_kyc_provider(env) {
+        Some(provider) => KycProviderClient::new(env, &provider).is_kyc_verified(recipient),
+        None => false,
+    }
+}
+
+//********** Pause Utils **********//
+
+/// Checks if the whole registry is paused.
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().has(&SytemapDataKeys::Paused)
+}
+
+/// Pauses or resumes the whole registry.
+pub fn save_paused(env: &Env, paused: bool) {
+    if paused {
+        env.storage()
+            .instance()
+            .set(&SytemapDataKeys::Paused, &true);
+    } else {
+        env.storage().instance().remove(&SytemapDataKeys::Paused);
+    }
+}
+
+/// Checks if a single estate is paused.
+pub fn is_estate_paused(env: &Env, estate_name: &String) -> bool {
+    env.storage()
+        .instance()
+        .has(&SytemapDataKeys::PausedEstate(estate_name.clone()))
+}
+
+/// Pauses or resumes a single estate.
+pub fn save_estate_paused(env: &Env, estate_name: &String, paused: bool) {
+    let key = SytemapDataKeys::PausedEstate(estate_name.clone());
+    if paused {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
@@ -0,0 +1,20 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors raised while reading thresholds or writing the report.
+#[derive(Debug, Error)]
+pub enum BenchError {
+    #[error("failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("{path}: {source}")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
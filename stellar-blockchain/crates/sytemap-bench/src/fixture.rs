@@ -0,0 +1,227 @@
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
+    xdr::{LedgerKey, ScVal},
+    Address, Env, String, Vec,
+};
+use sytemap::{
+    contract::{SytemapRegistry, SytemapRegistryClient},
+    types::{PropertyInfoPayload, Role},
+    util::SECONDS_PER_DAY,
+};
+
+/// Property the single-plot entry points are measured on. Its owner holds every tenth plot.
+pub const TARGET_PVN: u64 = 1;
+
+/// Ledger time the registry is seeded at
+const SEEDED_AT: u64 = 1_700_000_000;
+
+/// Plots minted per seeding call
+const SEED_BATCH: u64 = 200;
+
+/// Balance of the settlement token the buyer starts with
+pub const BUYER_BALANCE: i128 = 1_000_000;
+
+const HOLDERS: u64 = 10;
+
+/// Estate company every seeded plot is minted by
+pub const ESTATE_COMPANY_NAME: &str = "Palm Estates Ltd";
+
+/// Addresses with a part in the scenarios, plus the settlement token.
+#[derive(Debug, Clone)]
+pub struct Actors<A> {
+    pub admin: A,
+    pub owner: A,
+    pub buyer: A,
+    pub lienholder: A,
+    pub locker: A,
+    pub arbitrator: A,
+    pub verifier: A,
+    pub estate_company: A,
+    pub compliance: A,
+    pub pauser: A,
+    pub kyc_provider: A,
+    pub token: A,
+}
+
+impl<A> Actors<A> {
+    fn map<B>(&self, f: impl Fn(&A) -> B) -> Actors<B> {
+        Actors {
+            admin: f(&self.admin),
+            owner: f(&self.owner),
+            buyer: f(&self.buyer),
+            lienholder: f(&self.lienholder),
+            locker: f(&self.locker),
+            arbitrator: f(&self.arbitrator),
+            verifier: f(&self.verifier),
+            estate_company: f(&self.estate_company),
+            compliance: f(&self.compliance),
+            pauser: f(&self.pauser),
+            kyc_provider: f(&self.kyc_provider),
+            token: f(&self.token),
+        }
+    }
+}
+
+/// A registry seeded with `size` plots, kept as a ledger snapshot so every measurement
+/// starts from the same state.
+pub struct Fixture {
+    pub size: u64,
+    snapshot: LedgerSnapshot,
+    registry: std::string::String,
+    actors: Actors<std::string::String>,
+}
+
+impl Fixture {
+    /// Initializes a registry, grants every role once and mints `size` plots spread over
+    /// ten holders and four estates.
+    pub fn seed(size: u64) -> Self {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        env.budget().reset_unlimited();
+        env.ledger().with_mut(|ledger| ledger.timestamp = SEEDED_AT);
+
+        let token_admin = Address::generate(&env);
+        let actors = Actors {
+            admin: Address::generate(&env),
+            owner: Address::generate(&env),
+            buyer: Address::generate(&env),
+            lienholder: Address::generate(&env),
+            locker: Address::generate(&env),
+            arbitrator: Address::generate(&env),
+            verifier: Address::generate(&env),
+            estate_company: Address::generate(&env),
+            compliance: Address::generate(&env),
+            pauser: Address::generate(&env),
+            kyc_provider: Address::generate(&env),
+            token: env
+                .register_stellar_asset_contract_v2(token_admin)
+                .address(),
+        };
+        StellarAssetClient::new(&env, &actors.token).mint(&actors.buyer, &BUYER_BALANCE);
+
+        let registry = env.register_contract(None, SytemapRegistry);
+        let client = SytemapRegistryClient::new(&env, &registry);
+        client.initialize(
+            &actors.admin,
+            &String::from_str(&env, "Sytemap"),
+            &String::from_str(&env, "SYM"),
+            &String::from_str(&env, "https://sytemap.example/metadata"),
+        );
+        for (role, account) in [
+            (Role::Arbitrator, &actors.arbitrator),
+            (Role::Verifier, &actors.verifier),
+            (Role::EstateCompany, &actors.estate_company),
+            (Role::Compliance, &actors.compliance),
+            (Role::Pauser, &actors.pauser),
+        ] {
+            client.grant_role(&role, account);
+        }
+
+        let holders: std::vec::Vec<Address> =
+            (1..HOLDERS).map(|_| Address::generate(&env)).collect();
+        let mut pvn = 1;
+        while pvn <= size {
+            let mut batch = Vec::new(&env);
+            for pvn in pvn..=size.min(pvn + SEED_BATCH - 1) {
+                let holder = match pvn % HOLDERS {
+                    1 => &actors.owner,
+                    index => &holders[index as usize % holders.len()],
+                };
+                batch.push_back(payload(&env, pvn, holder));
+            }
+            client.batch_mint_new_property_info(&batch);
+            pvn += SEED_BATCH;
+        }
+
+        Fixture {
+            size,
+            snapshot: env.to_ledger_snapshot(),
+            registry: registry.to_string().to_string(),
+            actors: actors.map(|address| address.to_string().to_string()),
+        }
+    }
+
+    /// A fresh copy of the seeded registry.
+    pub fn fork(&self) -> Fork {
+        let mut snapshot = self.snapshot.clone();
+        // Mocked auth hands out the same nonces in every new Env, so the ones left by
+        // seeding would collide with the next signature
+        snapshot
+            .ledger_entries
+            .retain(|(key, _)| match key.as_ref() {
+                LedgerKey::ContractData(data) => !matches!(data.key, ScVal::LedgerKeyNonce(_)),
+                _ => true,
+            });
+        let env = Env::from_ledger_snapshot(snapshot);
+        env.mock_all_auths_allowing_non_root_auth();
+        env.budget().reset_unlimited();
+
+        let address = |strkey: &str| Address::from_string(&String::from_str(&env, strkey));
+        let registry = address(&self.registry);
+        let actors = self.actors.map(|strkey| address(strkey));
+        env.register_contract(Some(&registry), SytemapRegistry);
+        Fork {
+            client: SytemapRegistryClient::new(&env, &registry),
+            actors,
+            size: self.size,
+            env,
+        }
+    }
+}
+
+/// One measurement's copy of the registry.
+pub struct Fork {
+    pub env: Env,
+    pub client: SytemapRegistryClient<'static>,
+    pub actors: Actors<Address>,
+    pub size: u64,
+}
+
+impl Fork {
+    pub fn string(&self, value: &str) -> String {
+        String::from_str(&self.env, value)
+    }
+
+    /// A payload for the plot minted after the seeded ones, `offset` 0 being the first.
+    pub fn new_payload(&self, offset: u64) -> PropertyInfoPayload {
+        payload(&self.env, self.size + 1 + offset, &self.actors.owner)
+    }
+
+    pub fn advance_days(&self, days: u64) {
+        self.env
+            .ledger()
+            .with_mut(|ledger| ledger.timestamp += days * SECONDS_PER_DAY);
+    }
+}
+
+/// Outline every seeded plot is recorded with
+const PLOT_OUTLINE: &str = "6.5244N,3.3792E;6.5244N,3.3800E;6.5250N,3.3800E;6.5250N,3.3792E";
+
+/// Name of the estate a plot belongs to
+pub fn estate_name(property_verification_no: u64) -> std::string::String {
+    format!("Estate {}", property_verification_no % 4)
+}
+
+fn payload(env: &Env, property_verification_no: u64, owner: &Address) -> PropertyInfoPayload {
+    let string = |value: &str| String::from_str(env, value);
+    PropertyInfoPayload {
+        price_of_plot: 250_000,
+        property_verification_no,
+        buyer_wallet_id: owner.clone(),
+        plot_no: string(&format!("P-{property_verification_no}")),
+        size_of_plot: string("600sqm"),
+        coordinate_of_plot: string(PLOT_OUTLINE),
+        token_url: string(&format!(
+            "https://sytemap.example/token/{property_verification_no}"
+        )),
+        estate_name: string(&estate_name(property_verification_no)),
+        plot_url: string(&format!(
+            "https://sytemap.example/plot/{property_verification_no}"
+        )),
+        date_of_allocation: string("2024-03-01"),
+        estate_company_name: string(ESTATE_COMPANY_NAME),
+        nft_address: string(&format!("nft-{property_verification_no}")),
+    }
+}
@@ -0,0 +1,22 @@
+//! Measures what each `sytemap` registry entry point costs as the registry grows.
+//!
+//! Every entry point has a [`Scenario`] that is run against registries seeded with a given
+//! number of plots. Each call's CPU instructions, memory and ledger footprint are recorded
+//! in a [`BenchReport`] and checked against [`Thresholds`], which default to the public
+//! network's per-transaction limits and can be tightened or relaxed per entry point.
+
+mod error;
+mod fixture;
+mod measure;
+mod report;
+mod scenarios;
+
+pub use error::BenchError;
+pub use fixture::{Fixture, Fork, TARGET_PVN};
+pub use measure::{measure, Measurement};
+pub use report::{
+    run_bench, BenchReport, BenchResult, ThresholdConfig, ThresholdOverrides, Thresholds,
+};
+pub use scenarios::{scenarios, Scenario};
+
+mod test;
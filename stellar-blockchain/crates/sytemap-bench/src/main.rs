@@ -0,0 +1,99 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use sytemap_bench::{run_bench, BenchError, ThresholdConfig};
+
+/// Measure the cost of every sytemap registry entry point at several registry sizes.
+#[derive(Debug, Parser)]
+#[command(name = "sytemap-bench", version)]
+struct Args {
+    /// Registry sizes to measure at, in plots. Seeding grows with the square of the size,
+    /// so build with `--release` before measuring the larger ones
+    #[arg(long, value_delimiter = ',', default_value = "10,100,1000,10000")]
+    sizes: Vec<u64>,
+
+    /// JSON file overriding the default thresholds, globally or per entry point
+    #[arg(long)]
+    thresholds: Option<PathBuf>,
+
+    /// Only measure this entry point; may be repeated
+    #[arg(long = "entry-point")]
+    entry_points: Vec<String>,
+
+    /// Where to write the JSON report
+    #[arg(long, default_value = "bench-report.json")]
+    out: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<ExitCode, BenchError> {
+    let config = match &args.thresholds {
+        Some(path) => read_thresholds(path)?,
+        None => ThresholdConfig::default(),
+    };
+
+    let report = run_bench(&args.sizes, &config, &args.entry_points, |result| {
+        let measurement = &result.measurement;
+        let status = match (&measurement.error, result.exceeded.is_empty()) {
+            (Some(err), _) => format!("failed {err}"),
+            (None, false) => format!("exceeded {}", result.exceeded.join(", ")),
+            (None, true) => "ok".to_string(),
+        };
+        eprintln!(
+            "{:>6} {}: {status} (cpu {}, memory {}, read {}/{}B, write {}/{}B)",
+            measurement.registry_size,
+            measurement.entry_point,
+            measurement.cpu_instructions,
+            measurement.memory_bytes,
+            measurement.read_entries,
+            measurement.read_bytes,
+            measurement.write_entries,
+            measurement.write_bytes,
+        );
+    });
+
+    let json = serde_json::to_string_pretty(&report).map_err(|source| BenchError::Json {
+        path: args.out.clone(),
+        source,
+    })?;
+    fs::write(&args.out, json + "\n").map_err(|source| BenchError::Io {
+        path: args.out.clone(),
+        source,
+    })?;
+
+    let failed = report
+        .results
+        .iter()
+        .filter(|result| !result.passed())
+        .count();
+    eprintln!(
+        "{} measurements, {failed} failed or over threshold (see {})",
+        report.results.len(),
+        args.out.display()
+    );
+    Ok(if report.passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn read_thresholds(path: &PathBuf) -> Result<ThresholdConfig, BenchError> {
+    let json = fs::read_to_string(path).map_err(|source| BenchError::Io {
+        path: path.clone(),
+        source,
+    })?;
+    serde_json::from_str(&json).map_err(|source| BenchError::Json {
+        path: path.clone(),
+        source,
+    })
+}
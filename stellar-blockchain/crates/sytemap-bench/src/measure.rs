@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use soroban_env_host::storage::{AccessType, Footprint};
+use soroban_sdk::{
+    xdr::{LedgerKey, Limits, WriteXdr},
+    Env,
+};
+
+use crate::{fixture::Fixture, scenarios::Scenario};
+
+/// What one invocation of an entry point cost.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Measurement {
+    pub entry_point: &'static str,
+    pub registry_size: u64,
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+    /// Ledger entries in the footprint, read-only and read-write
+    pub read_entries: u64,
+    /// Ledger entries in the read-write part of the footprint
+    pub write_entries: u64,
+    /// Size of the footprint's entries before the call
+    pub read_bytes: u64,
+    /// Size of the read-write entries after the call
+    pub write_bytes: u64,
+    /// Size of the largest entry written, which has to fit the network's entry size limit
+    pub largest_entry_bytes: u64,
+    /// Why the call failed, when it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Runs `scenario` on a fresh fork of `fixture` and measures the call alone, without its
+/// setup. The budget is unlimited while the call runs, since the host aborts rather than
+/// failing a call that runs out, so costs are compared with the limits afterwards.
+///
+/// The footprint leaves out the contract code entry: the harness runs the native build of
+/// the registry, which has no Wasm on the ledger.
+pub fn measure(fixture: &Fixture, scenario: &Scenario) -> Measurement {
+    let fork = fixture.fork();
+    (scenario.setup)(&fork);
+
+    let sizes_before = entry_sizes(&fork.env);
+    with_footprint(&fork.env, |footprint| *footprint = Footprint::default());
+    let mut budget = fork.env.budget();
+    budget.reset_unlimited();
+
+    let error = (scenario.call)(&fork).err();
+
+    let cpu_instructions = budget.cpu_instruction_cost();
+    let memory_bytes = budget.memory_bytes_cost();
+    let footprint = with_footprint(&fork.env, |footprint| {
+        (&footprint.0)
+            .into_iter()
+            .map(|(key, access)| (key.as_ref().clone(), *access))
+            .collect::<Vec<_>>()
+    });
+    let sizes_after = entry_sizes(&fork.env);
+
+    let size = |sizes: &HashMap<LedgerKey, u64>, key: &LedgerKey| {
+        sizes.get(key).copied().unwrap_or_default()
+    };
+    let written: Vec<&LedgerKey> = footprint
+        .iter()
+        .filter(|(_, access)| *access == AccessType::ReadWrite)
+        .map(|(key, _)| key)
+        .collect();
+    Measurement {
+        entry_point: scenario.entry_point,
+        registry_size: fixture.size,
+        cpu_instructions,
+        memory_bytes,
+        read_entries: footprint.len() as u64,
+        write_entries: written.len() as u64,
+        read_bytes: footprint
+            .iter()
+            .map(|(key, _)| size(&sizes_before, key))
+            .sum(),
+        write_bytes: written.iter().map(|key| size(&sizes_after, key)).sum(),
+        largest_entry_bytes: written
+            .iter()
+            .map(|key| size(&sizes_after, key))
+            .max()
+            .unwrap_or_default(),
+        error,
+    }
+}
+
+fn with_footprint<T>(env: &Env, f: impl FnOnce(&mut Footprint) -> T) -> T {
+    env.host()
+        .with_mut_storage(|storage| Ok(f(&mut storage.footprint)))
+        .expect("ledger storage is not borrowed between invocations")
+}
+
+/// XDR size of every ledger entry, keyed by the entry's key.
+fn entry_sizes(env: &Env) -> HashMap<LedgerKey, u64> {
+    env.to_ledger_snapshot()
+        .ledger_entries
+        .into_iter()
+        .map(|(key, (entry, _))| {
+            let size = entry
+                .to_xdr(Limits::none())
+                .map(|xdr| xdr.len() as u64)
+                .unwrap_or_default();
+            (*key, size)
+        })
+        .collect()
+}
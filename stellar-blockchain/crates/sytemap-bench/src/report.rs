@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    fixture::Fixture,
+    measure::{measure, Measurement},
+    scenarios::{scenarios, Scenario},
+};
+
+/// Resource limits a single invocation must stay within.
+///
+/// The defaults are the per-transaction limits of the Stellar public network at the time of
+/// writing; check the network's current settings before relying on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Thresholds {
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+    pub read_entries: u64,
+    pub write_entries: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub largest_entry_bytes: u64,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            cpu_instructions: 100_000_000,
+            memory_bytes: 40 * 1024 * 1024,
+            read_entries: 40,
+            write_entries: 25,
+            read_bytes: 200 * 1024,
+            write_bytes: 129 * 1024,
+            largest_entry_bytes: 64 * 1024,
+        }
+    }
+}
+
+/// Limits to change from the ones they apply on top of; unset fields are inherited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThresholdOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_instructions: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_entries: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_entries: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub largest_entry_bytes: Option<u64>,
+}
+
+/// The thresholds file: limits for every entry point, then per entry point.
+///
+/// ```json
+/// {
+///   "defaults": { "cpu_instructions": 50000000 },
+///   "entry_points": { "get_all_minted_property_details": { "read_bytes": 1048576 } }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThresholdConfig {
+    pub defaults: ThresholdOverrides,
+    pub entry_points: BTreeMap<String, ThresholdOverrides>,
+}
+
+impl Thresholds {
+    fn with(self, overrides: &ThresholdOverrides) -> Self {
+        Thresholds {
+            cpu_instructions: overrides.cpu_instructions.unwrap_or(self.cpu_instructions),
+            memory_bytes: overrides.memory_bytes.unwrap_or(self.memory_bytes),
+            read_entries: overrides.read_entries.unwrap_or(self.read_entries),
+            write_entries: overrides.write_entries.unwrap_or(self.write_entries),
+            read_bytes: overrides.read_bytes.unwrap_or(self.read_bytes),
+            write_bytes: overrides.write_bytes.unwrap_or(self.write_bytes),
+            largest_entry_bytes: overrides
+                .largest_entry_bytes
+                .unwrap_or(self.largest_entry_bytes),
+        }
+    }
+}
+
+impl ThresholdConfig {
+    pub fn for_entry_point(&self, entry_point: &str) -> Thresholds {
+        let thresholds = Thresholds::default().with(&self.defaults);
+        match self.entry_points.get(entry_point) {
+            Some(overrides) => thresholds.with(overrides),
+            None => thresholds,
+        }
+    }
+}
+
+/// A measurement and the limits it broke.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BenchResult {
+    #[serde(flatten)]
+    pub measurement: Measurement,
+    /// Names of the exceeded limits, as in `Thresholds`
+    pub exceeded: Vec<&'static str>,
+}
+
+impl BenchResult {
+    pub fn new(measurement: Measurement, thresholds: &Thresholds) -> Self {
+        let exceeded = [
+            (
+                "cpu_instructions",
+                measurement.cpu_instructions,
+                thresholds.cpu_instructions,
+            ),
+            (
+                "memory_bytes",
+                measurement.memory_bytes,
+                thresholds.memory_bytes,
+            ),
+            (
+                "read_entries",
+                measurement.read_entries,
+                thresholds.read_entries,
+            ),
+            (
+                "write_entries",
+                measurement.write_entries,
+                thresholds.write_entries,
+            ),
+            ("read_bytes", measurement.read_bytes, thresholds.read_bytes),
+            (
+                "write_bytes",
+                measurement.write_bytes,
+                thresholds.write_bytes,
+            ),
+            (
+                "largest_entry_bytes",
+                measurement.largest_entry_bytes,
+                thresholds.largest_entry_bytes,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, value, limit)| value > limit)
+        .map(|(name, _, _)| name)
+        .collect();
+        BenchResult {
+            measurement,
+            exceeded,
+        }
+    }
+
+    /// Whether the call succeeded within its limits.
+    pub fn passed(&self) -> bool {
+        self.measurement.error.is_none() && self.exceeded.is_empty()
+    }
+}
+
+/// Machine-readable outcome of a benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BenchReport {
+    pub registry_sizes: Vec<u64>,
+    pub thresholds: ThresholdConfig,
+    pub results: Vec<BenchResult>,
+    /// False when any call failed or exceeded its limits
+    pub passed: bool,
+}
+
+/// Measures every scenario, or those whose entry point is in `filter` when it is not
+/// empty, at each of `registry_sizes`. `on_result` sees each result as it is taken, since
+/// seeding and measuring the larger registries takes a while.
+pub fn run_bench(
+    registry_sizes: &[u64],
+    config: &ThresholdConfig,
+    filter: &[String],
+    mut on_result: impl FnMut(&BenchResult),
+) -> BenchReport {
+    let scenarios: Vec<Scenario> = scenarios()
+        .into_iter()
+        .filter(|scenario| {
+            filter.is_empty() || filter.iter().any(|name| name == scenario.entry_point)
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for &size in registry_sizes {
+        let fixture = Fixture::seed(size);
+        for scenario in &scenarios {
+            let thresholds = config.for_entry_point(scenario.entry_point);
+            let result = BenchResult::new(measure(&fixture, scenario), &thresholds);
+            on_result(&result);
+            results.push(result);
+        }
+    }
+    BenchReport {
+        registry_sizes: registry_sizes.to_vec(),
+        thresholds: config.clone(),
+        passed: results.iter().all(BenchResult::passed),
+        results,
+    }
+}
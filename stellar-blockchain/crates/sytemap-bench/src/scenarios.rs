@@ -0,0 +1,560 @@
+use std::fmt::Debug;
+
+use soroban_sdk::{BytesN, InvokeError};
+use sytemap::{
+    contract::SytemapRegistryClient,
+    types::{DocumentType, PaymentSchedule, PropertyInfoPayload, PropertyStatus, Role},
+    util::{
+        get_all_property_verification_numbers, update_all_properties, MAX_INTEGRITY_PAGE_SIZE,
+        MAX_PAGE_SIZE, SECONDS_PER_DAY,
+    },
+};
+
+use crate::fixture::{estate_name, Fork, ESTATE_COMPANY_NAME, TARGET_PVN};
+
+/// Mint proposal ids start at 1 and the seeded registry has none
+const FIRST_PROPOSAL_ID: u64 = 1;
+
+const PLAN_TOTAL: i128 = 1_000;
+const LEASE_RENT: i128 = 500;
+
+/// How to measure one entry point: `setup` brings the registry into a state the call
+/// succeeds in and is not measured, `call` is the measured invocation.
+pub struct Scenario {
+    pub entry_point: &'static str,
+    pub setup: fn(&Fork),
+    pub call: fn(&Fork) -> Result<(), String>,
+}
+
+fn scenario(
+    entry_point: &'static str,
+    setup: fn(&Fork),
+    call: fn(&Fork) -> Result<(), String>,
+) -> Scenario {
+    Scenario {
+        entry_point,
+        setup,
+        call,
+    }
+}
+
+fn nothing(_: &Fork) {}
+
+/// Reduces the result of a generated `try_` client method to whether the call succeeded.
+fn outcome<T, C, E: Debug>(
+    result: Result<Result<T, C>, Result<E, InvokeError>>,
+) -> Result<(), String> {
+    match result {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(_)) => Err("returned a value that could not be decoded".to_string()),
+        Err(Ok(err)) => Err(format!("{err:?}")),
+        Err(Err(err)) => Err(format!("{err:?}")),
+    }
+}
+
+fn registry(fork: &Fork) -> &SytemapRegistryClient<'static> {
+    &fork.client
+}
+
+fn document_hash(fork: &Fork) -> BytesN<32> {
+    BytesN::from_array(&fork.env, &[7; 32])
+}
+
+/// Two monthly installments, the first due now, with no grace or notice period so the
+/// default and repossession paths can be reached by moving the clock a couple of days.
+fn schedule(fork: &Fork) -> PaymentSchedule {
+    PaymentSchedule {
+        token: fork.actors.token.clone(),
+        installment_count: 2,
+        interval: 30 * SECONDS_PER_DAY,
+        first_due_date: fork.env.ledger().timestamp(),
+        grace_period_days: 0,
+        notice_period_days: 0,
+        refund_percentage: 50,
+    }
+}
+
+fn create_payment_plan(fork: &Fork) {
+    registry(fork).create_payment_plan(
+        &TARGET_PVN,
+        &fork.actors.buyer,
+        &PLAN_TOTAL,
+        &schedule(fork),
+    );
+}
+
+fn declare_payment_default(fork: &Fork) {
+    create_payment_plan(fork);
+    fork.advance_days(2);
+    registry(fork).declare_payment_default(&TARGET_PVN);
+}
+
+fn register_lien(fork: &Fork) {
+    registry(fork).register_lien(
+        &TARGET_PVN,
+        &fork.actors.lienholder,
+        &50_000,
+        &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+    );
+}
+
+/// A year-long lease to the buyer, starting now.
+fn create_lease(fork: &Fork) {
+    registry(fork).create_lease(
+        &TARGET_PVN,
+        &fork.actors.buyer,
+        &fork.env.ledger().timestamp(),
+        &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+        &LEASE_RENT,
+        &fork.actors.token,
+    );
+}
+
+fn lock_as_collateral(fork: &Fork) {
+    registry(fork).lock_as_collateral(&TARGET_PVN, &fork.actors.locker);
+}
+
+fn freeze_property(fork: &Fork) {
+    registry(fork).freeze_property(
+        &TARGET_PVN,
+        &fork.actors.arbitrator,
+        &fork.string("FHC/ABJ/CS/123/2024"),
+    );
+}
+
+fn submit_for_survey(fork: &Fork) {
+    registry(fork).submit_for_survey(&TARGET_PVN);
+}
+
+fn verify_property(fork: &Fork) {
+    submit_for_survey(fork);
+    registry(fork).verify_property(&TARGET_PVN, &fork.actors.verifier);
+}
+
+fn attach_document(fork: &Fork) {
+    registry(fork).attach_document(
+        &TARGET_PVN,
+        &fork.actors.verifier,
+        &DocumentType::SurveyPlan,
+        &document_hash(fork),
+        &fork.string("ipfs://survey-plan"),
+    );
+}
+
+fn propose_mint(fork: &Fork) {
+    registry(fork).set_mint_approval_config(&1, &SECONDS_PER_DAY);
+    registry(fork).propose_mint(&fork.actors.estate_company, &fork.new_payload(0));
+}
+
+/// Two halves of the target plot, minted after the seeded plots.
+fn child_payloads(fork: &Fork) -> soroban_sdk::Vec<PropertyInfoPayload> {
+    let halves = [
+        "6.5244N,3.3792E;6.5244N,3.3796E;6.5250N,3.3796E;6.5250N,3.3792E",
+        "6.5244N,3.3796E;6.5244N,3.3800E;6.5250N,3.3800E;6.5250N,3.3796E",
+    ];
+    let mut children = soroban_sdk::Vec::new(&fork.env);
+    for (offset, outline) in halves.into_iter().enumerate() {
+        let mut child = fork.new_payload(offset as u64);
+        child.estate_name = fork.string(&estate_name(TARGET_PVN));
+        child.plot_no = fork.string(&format!("P-{TARGET_PVN}-{}", offset + 1));
+        child.size_of_plot = fork.string("300sqm");
+        child.coordinate_of_plot = fork.string(outline);
+        children.push_back(child);
+    }
+    children
+}
+
+fn subdivide(fork: &Fork) {
+    registry(fork).subdivide(&TARGET_PVN, &fork.actors.verifier, &child_payloads(fork));
+}
+
+/// Drops the target from `AllProperties`, so `repair_index` has to list it again.
+fn unlist_target(fork: &Fork) {
+    let env = &fork.env;
+    env.as_contract(&registry(fork).address, || {
+        let mut all_properties = get_all_property_verification_numbers(env);
+        let position = all_properties.first_index_of(TARGET_PVN).unwrap();
+        all_properties.remove(position);
+        update_all_properties(env, all_properties).unwrap();
+    });
+}
+
+/// Every entry point of the registry, in the order of `contract.rs`.
+pub fn scenarios() -> Vec<Scenario> {
+    vec![
+        scenario("initialize", nothing, |fork| {
+            // Only an empty registry can be initialized, so this one runs on a second
+            // instance deployed next to the seeded one
+            let env = &fork.env;
+            let fresh = SytemapRegistryClient::new(
+                env,
+                &env.register_contract(None, sytemap::contract::SytemapRegistry),
+            );
+            outcome(fresh.try_initialize(
+                &fork.actors.admin,
+                &fork.string("Sytemap"),
+                &fork.string("SYM"),
+                &fork.string("https://sytemap.example/metadata"),
+            ))
+        }),
+        scenario("get_admin", nothing, |fork| {
+            outcome(registry(fork).try_get_admin())
+        }),
+        scenario("update_metadata_uri", nothing, |fork| {
+            outcome(registry(fork).try_update_metadata_uri(&fork.string("ipfs://metadata-v2")))
+        }),
+        scenario("grant_role", nothing, |fork| {
+            outcome(registry(fork).try_grant_role(&Role::Verifier, &fork.actors.buyer))
+        }),
+        scenario("revoke_role", nothing, |fork| {
+            outcome(registry(fork).try_revoke_role(&Role::Verifier, &fork.actors.verifier))
+        }),
+        scenario("has_role", nothing, |fork| {
+            outcome(registry(fork).try_has_role(&Role::Verifier, &fork.actors.verifier))
+        }),
+        scenario("set_estate_company_account", nothing, |fork| {
+            outcome(registry(fork).try_set_estate_company_account(
+                &fork.string(ESTATE_COMPANY_NAME),
+                &fork.actors.estate_company,
+            ))
+        }),
+        scenario("get_estate_company_account", nothing, |fork| {
+            outcome(
+                registry(fork).try_get_estate_company_account(&fork.string(ESTATE_COMPANY_NAME)),
+            )
+        }),
+        scenario("safe_mint_new_property_info", nothing, |fork| {
+            outcome(registry(fork).try_safe_mint_new_property_info(&fork.new_payload(0)))
+        }),
+        scenario("batch_mint_new_property_info", nothing, |fork| {
+            let mut payloads = soroban_sdk::Vec::new(&fork.env);
+            for offset in 0..2 {
+                payloads.push_back(fork.new_payload(offset));
+            }
+            outcome(registry(fork).try_batch_mint_new_property_info(&payloads))
+        }),
+        scenario("set_mint_approval_config", nothing, |fork| {
+            outcome(registry(fork).try_set_mint_approval_config(&2, &SECONDS_PER_DAY))
+        }),
+        scenario(
+            "propose_mint",
+            |fork| {
+                registry(fork).set_mint_approval_config(&1, &SECONDS_PER_DAY);
+            },
+            |fork| {
+                outcome(
+                    registry(fork)
+                        .try_propose_mint(&fork.actors.estate_company, &fork.new_payload(0)),
+                )
+            },
+        ),
+        scenario("approve_mint", propose_mint, |fork| {
+            outcome(registry(fork).try_approve_mint(&FIRST_PROPOSAL_ID, &fork.actors.verifier))
+        }),
+        scenario("get_pending_mints", propose_mint, |fork| {
+            outcome(registry(fork).try_get_pending_mints())
+        }),
+        scenario("change_property_price_by_owner", nothing, |fork| {
+            outcome(registry(fork).try_change_property_price_by_owner(&TARGET_PVN, &300_000))
+        }),
+        scenario("transfer_property_by_owner", nothing, |fork| {
+            outcome(registry(fork).try_transfer_property_by_owner(&TARGET_PVN, &fork.actors.buyer))
+        }),
+        scenario("create_payment_plan", nothing, |fork| {
+            outcome(registry(fork).try_create_payment_plan(
+                &TARGET_PVN,
+                &fork.actors.buyer,
+                &PLAN_TOTAL,
+                &schedule(fork),
+            ))
+        }),
+        scenario("pay_installment", create_payment_plan, |fork| {
+            outcome(registry(fork).try_pay_installment(&TARGET_PVN, &(PLAN_TOTAL / 2)))
+        }),
+        scenario(
+            "declare_payment_default",
+            |fork| {
+                create_payment_plan(fork);
+                fork.advance_days(2);
+            },
+            |fork| outcome(registry(fork).try_declare_payment_default(&TARGET_PVN)),
+        ),
+        scenario("repossess", declare_payment_default, |fork| {
+            outcome(registry(fork).try_repossess(&TARGET_PVN))
+        }),
+        scenario("get_payment_status", create_payment_plan, |fork| {
+            outcome(registry(fork).try_get_payment_status(&TARGET_PVN))
+        }),
+        scenario("register_lien", nothing, |fork| {
+            outcome(registry(fork).try_register_lien(
+                &TARGET_PVN,
+                &fork.actors.lienholder,
+                &50_000,
+                &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+            ))
+        }),
+        scenario("release_lien", register_lien, |fork| {
+            outcome(registry(fork).try_release_lien(&TARGET_PVN, &fork.actors.lienholder))
+        }),
+        scenario(
+            "clear_expired_liens",
+            |fork| {
+                register_lien(fork);
+                fork.advance_days(366);
+            },
+            |fork| outcome(registry(fork).try_clear_expired_liens(&TARGET_PVN)),
+        ),
+        scenario("consent_to_lien_transfer", register_lien, |fork| {
+            outcome(registry(fork).try_consent_to_lien_transfer(
+                &TARGET_PVN,
+                &fork.actors.lienholder,
+                &fork.actors.buyer,
+            ))
+        }),
+        scenario("get_encumbrances", register_lien, |fork| {
+            outcome(registry(fork).try_get_encumbrances(&TARGET_PVN))
+        }),
+        scenario("create_lease", nothing, |fork| {
+            outcome(registry(fork).try_create_lease(
+                &TARGET_PVN,
+                &fork.actors.buyer,
+                &fork.env.ledger().timestamp(),
+                &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+                &LEASE_RENT,
+                &fork.actors.token,
+            ))
+        }),
+        scenario("terminate_lease", create_lease, |fork| {
+            outcome(registry(fork).try_terminate_lease(&TARGET_PVN))
+        }),
+        scenario("get_active_lease", create_lease, |fork| {
+            outcome(registry(fork).try_get_active_lease(&TARGET_PVN))
+        }),
+        scenario("lock_as_collateral", nothing, |fork| {
+            outcome(registry(fork).try_lock_as_collateral(&TARGET_PVN, &fork.actors.locker))
+        }),
+        scenario("unlock", lock_as_collateral, |fork| {
+            outcome(registry(fork).try_unlock(&TARGET_PVN))
+        }),
+        scenario("liquidate_to", lock_as_collateral, |fork| {
+            outcome(registry(fork).try_liquidate_to(&TARGET_PVN, &fork.actors.buyer))
+        }),
+        scenario("is_locked", lock_as_collateral, |fork| {
+            outcome(registry(fork).try_is_locked(&TARGET_PVN))
+        }),
+        scenario("freeze_property", nothing, |fork| {
+            outcome(registry(fork).try_freeze_property(
+                &TARGET_PVN,
+                &fork.actors.arbitrator,
+                &fork.string("FHC/ABJ/CS/123/2024"),
+            ))
+        }),
+        scenario("unfreeze_property", freeze_property, |fork| {
+            outcome(registry(fork).try_unfreeze_property(
+                &TARGET_PVN,
+                &fork.actors.arbitrator,
+                &fork.string("struck out"),
+            ))
+        }),
+        scenario("resolve_dispute_transfer", freeze_property, |fork| {
+            outcome(registry(fork).try_resolve_dispute_transfer(
+                &TARGET_PVN,
+                &fork.actors.arbitrator,
+                &fork.actors.buyer,
+            ))
+        }),
+        scenario("get_dispute", freeze_property, |fork| {
+            outcome(registry(fork).try_get_dispute(&TARGET_PVN))
+        }),
+        scenario("submit_for_survey", nothing, |fork| {
+            outcome(registry(fork).try_submit_for_survey(&TARGET_PVN))
+        }),
+        scenario("verify_property", submit_for_survey, |fork| {
+            outcome(registry(fork).try_verify_property(&TARGET_PVN, &fork.actors.verifier))
+        }),
+        scenario("reject_survey", submit_for_survey, |fork| {
+            outcome(registry(fork).try_reject_survey(&TARGET_PVN, &fork.actors.verifier))
+        }),
+        scenario("allocate_property", verify_property, |fork| {
+            outcome(registry(fork).try_allocate_property(&TARGET_PVN))
+        }),
+        scenario("revoke_property", nothing, |fork| {
+            outcome(registry(fork).try_revoke_property(&TARGET_PVN))
+        }),
+        scenario("subdivide", nothing, |fork| {
+            outcome(registry(fork).try_subdivide(
+                &TARGET_PVN,
+                &fork.actors.verifier,
+                &child_payloads(fork),
+            ))
+        }),
+        scenario("merge_plots", subdivide, |fork| {
+            // Puts the two halves of the target back together
+            let mut merged = fork.new_payload(2);
+            merged.estate_name = fork.string(&estate_name(TARGET_PVN));
+            outcome(registry(fork).try_merge_plots(
+                &soroban_sdk::Vec::from_array(&fork.env, [fork.size + 1, fork.size + 2]),
+                &fork.actors.verifier,
+                &merged,
+            ))
+        }),
+        scenario("get_properties_by_status", nothing, |fork| {
+            outcome(registry(fork).try_get_properties_by_status(&PropertyStatus::Allocated))
+        }),
+        scenario("attach_document", nothing, |fork| {
+            outcome(registry(fork).try_attach_document(
+                &TARGET_PVN,
+                &fork.actors.verifier,
+                &DocumentType::SurveyPlan,
+                &document_hash(fork),
+                &fork.string("ipfs://survey-plan"),
+            ))
+        }),
+        scenario("get_documents", attach_document, |fork| {
+            outcome(registry(fork).try_get_documents(&TARGET_PVN))
+        }),
+        scenario("verify_document", attach_document, |fork| {
+            outcome(registry(fork).try_verify_document(
+                &TARGET_PVN,
+                &DocumentType::SurveyPlan,
+                &document_hash(fork),
+            ))
+        }),
+        scenario("set_kyc_status", nothing, |fork| {
+            outcome(registry(fork).try_set_kyc_status(
+                &fork.actors.compliance,
+                &fork.actors.buyer,
+                &2,
+                &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+            ))
+        }),
+        scenario(
+            "get_kyc_status",
+            |fork| {
+                registry(fork).set_kyc_status(
+                    &fork.actors.compliance,
+                    &fork.actors.buyer,
+                    &2,
+                    &(fork.env.ledger().timestamp() + 365 * SECONDS_PER_DAY),
+                );
+            },
+            |fork| outcome(registry(fork).try_get_kyc_status(&fork.actors.buyer)),
+        ),
+        scenario("set_kyc_provider", nothing, |fork| {
+            outcome(registry(fork).try_set_kyc_provider(&Some(fork.actors.kyc_provider.clone())))
+        }),
+        scenario("set_estate_kyc_level", nothing, |fork| {
+            outcome(registry(fork).try_set_estate_kyc_level(
+                &fork.actors.compliance,
+                &fork.string(&estate_name(TARGET_PVN)),
+                &1,
+            ))
+        }),
+        scenario("get_estate_kyc_level", nothing, |fork| {
+            outcome(registry(fork).try_get_estate_kyc_level(&fork.string(&estate_name(TARGET_PVN))))
+        }),
+        scenario("pause", nothing, |fork| {
+            outcome(registry(fork).try_pause(&fork.actors.pauser))
+        }),
+        scenario(
+            "unpause",
+            |fork| registry(fork).pause(&fork.actors.pauser),
+            |fork| outcome(registry(fork).try_unpause(&fork.actors.pauser)),
+        ),
+        scenario("pause_estate", nothing, |fork| {
+            outcome(
+                registry(fork)
+                    .try_pause_estate(&fork.actors.pauser, &fork.string(&estate_name(TARGET_PVN))),
+            )
+        }),
+        scenario(
+            "unpause_estate",
+            |fork| {
+                registry(fork)
+                    .pause_estate(&fork.actors.pauser, &fork.string(&estate_name(TARGET_PVN)));
+            },
+            |fork| {
+                outcome(registry(fork).try_unpause_estate(
+                    &fork.actors.pauser,
+                    &fork.string(&estate_name(TARGET_PVN)),
+                ))
+            },
+        ),
+        scenario("is_paused", nothing, |fork| {
+            outcome(registry(fork).try_is_paused())
+        }),
+        scenario("is_estate_paused", nothing, |fork| {
+            outcome(registry(fork).try_is_estate_paused(&fork.string(&estate_name(TARGET_PVN))))
+        }),
+        scenario("get_property_info_details_by_pvn", nothing, |fork| {
+            outcome(registry(fork).try_get_property_info_details_by_pvn(&TARGET_PVN))
+        }),
+        scenario("get_property_info_by_nft_address", nothing, |fork| {
+            outcome(
+                registry(fork).try_get_property_info_by_nft_address(
+                    &fork.string(&format!("nft-{TARGET_PVN}")),
+                ),
+            )
+        }),
+        scenario("get_property_by_estate_plot", nothing, |fork| {
+            outcome(registry(fork).try_get_property_by_estate_plot(
+                &fork.string(&estate_name(TARGET_PVN)),
+                &fork.string(&format!("P-{TARGET_PVN}")),
+            ))
+        }),
+        scenario("get_lineage", subdivide, |fork| {
+            outcome(registry(fork).try_get_lineage(&TARGET_PVN))
+        }),
+        scenario("get_no_of_property_by_address", nothing, |fork| {
+            outcome(registry(fork).try_get_no_of_property_by_address(&fork.actors.owner))
+        }),
+        scenario("get_number_of_property_minted", nothing, |fork| {
+            outcome(registry(fork).try_get_number_of_property_minted())
+        }),
+        scenario("get_owner_of_pvn", nothing, |fork| {
+            outcome(registry(fork).try_get_owner_of_pvn(&TARGET_PVN))
+        }),
+        scenario("get_all_property_by_owner", nothing, |fork| {
+            outcome(registry(fork).try_get_all_property_by_owner(&fork.actors.owner))
+        }),
+        scenario("get_properties_by_owner_page", nothing, |fork| {
+            outcome(registry(fork).try_get_properties_by_owner_page(
+                &fork.actors.owner,
+                &0,
+                &MAX_PAGE_SIZE,
+            ))
+        }),
+        scenario("get_properties_by_estate", nothing, |fork| {
+            outcome(registry(fork).try_get_properties_by_estate(
+                &fork.string(&estate_name(TARGET_PVN)),
+                &0,
+                &MAX_PAGE_SIZE,
+            ))
+        }),
+        scenario("get_properties_by_estate_company", nothing, |fork| {
+            outcome(registry(fork).try_get_properties_by_estate_company(
+                &fork.string(ESTATE_COMPANY_NAME),
+                &0,
+                &MAX_PAGE_SIZE,
+            ))
+        }),
+        scenario("count_properties_by_estate", nothing, |fork| {
+            outcome(
+                registry(fork)
+                    .try_count_properties_by_estate(&fork.string(&estate_name(TARGET_PVN))),
+            )
+        }),
+        scenario("get_estate_summary", nothing, |fork| {
+            outcome(registry(fork).try_get_estate_summary(&fork.string(&estate_name(TARGET_PVN))))
+        }),
+        scenario("get_all_minted_property_details", nothing, |fork| {
+            outcome(registry(fork).try_get_all_minted_property_details())
+        }),
+        scenario("verify_integrity", nothing, |fork| {
+            outcome(registry(fork).try_verify_integrity(&0, &MAX_INTEGRITY_PAGE_SIZE))
+        }),
+        scenario("repair_index", unlist_target, |fork| {
+            outcome(registry(fork).try_repair_index(&TARGET_PVN))
+        }),
+    ]
+}
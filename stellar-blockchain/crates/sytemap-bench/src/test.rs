@@ -0,0 +1,109 @@
+#![cfg(test)]
+
+use std::collections::BTreeSet;
+
+use crate::*;
+
+const CONTRACT: &str = include_str!("../../../contracts/sytemap/src/contract.rs");
+
+/// Names of the registry's entry points, read from the contract source so a new entry
+/// point without a scenario fails the build's tests rather than going unmeasured.
+fn entry_points() -> BTreeSet<String> {
+    let body = CONTRACT
+        .split("impl SytemapRegistry {")
+        .nth(1)
+        .expect("contract impl block");
+    body.lines()
+        .filter_map(|line| line.trim_start().strip_prefix("pub fn "))
+        .map(|rest| rest.split(['(', '<']).next().unwrap().to_string())
+        .collect()
+}
+
+fn measure_at(size: u64, entry_point: &str) -> Measurement {
+    let scenario = scenarios()
+        .into_iter()
+        .find(|scenario| scenario.entry_point == entry_point)
+        .unwrap();
+    measure(&Fixture::seed(size), &scenario)
+}
+
+#[test]
+fn test_every_entry_point_has_a_scenario() {
+    let covered: BTreeSet<String> = scenarios()
+        .iter()
+        .map(|scenario| scenario.entry_point.to_string())
+        .collect();
+    assert_eq!(covered.len(), scenarios().len(), "duplicate scenario");
+    assert_eq!(covered, entry_points());
+}
+
+#[test]
+fn test_small_registry_passes_default_thresholds() {
+    let report = run_bench(&[10], &ThresholdConfig::default(), &[], |_| {});
+
+    for result in &report.results {
+        assert_eq!(
+            result.measurement.error, None,
+            "{}",
+            result.measurement.entry_point
+        );
+        assert!(result.exceeded.is_empty(), "{result:?}");
+        assert!(result.measurement.cpu_instructions > 0);
+    }
+    assert!(report.passed);
+    assert_eq!(report.results.len(), scenarios().len());
+}
+
+#[test]
+fn test_exceeded_threshold_fails_the_run() {
+    let config: ThresholdConfig =
+        serde_json::from_str(r#"{"entry_points": {"get_admin": {"cpu_instructions": 1}}}"#)
+            .unwrap();
+    assert_eq!(config.for_entry_point("get_admin").cpu_instructions, 1);
+    assert_eq!(config.for_entry_point("has_role"), Thresholds::default());
+
+    let filter = ["get_admin".to_string(), "has_role".to_string()];
+    let report = run_bench(&[10], &config, &filter, |_| {});
+
+    assert_eq!(report.results.len(), 2);
+    let get_admin = &report.results[0];
+    assert_eq!(get_admin.measurement.entry_point, "get_admin");
+    assert_eq!(get_admin.exceeded, vec!["cpu_instructions"]);
+    assert!(report.results[1].passed());
+    assert!(!report.passed);
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["passed"], false);
+    assert_eq!(json["results"][0]["exceeded"][0], "cpu_instructions");
+    assert_eq!(json["results"][0]["registry_size"], 10);
+}
+
+#[test]
+fn test_unknown_threshold_is_rejected() {
+    let err = serde_json::from_str::<ThresholdConfig>(r#"{"defaults": {"cpu": 1}}"#);
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_cost_grows_with_registry_size() {
+    let small = measure_at(10, "get_all_minted_property_details");
+    let large = measure_at(20, "get_all_minted_property_details");
+
+    assert!(large.cpu_instructions > small.cpu_instructions);
+    assert!(large.read_bytes > small.read_bytes);
+}
+
+#[test]
+fn test_footprint_separates_reads_from_writes() {
+    let query = measure_at(10, "get_owner_of_pvn");
+    assert_eq!(query.error, None);
+    assert!(query.read_entries > 0);
+    assert_eq!(query.write_entries, 0);
+    assert_eq!(query.write_bytes, 0);
+
+    let transfer = measure_at(10, "transfer_property_by_owner");
+    assert_eq!(transfer.error, None);
+    assert!(transfer.write_entries > 0);
+    assert!(transfer.largest_entry_bytes > 0);
+    assert!(transfer.write_bytes >= transfer.largest_entry_bytes);
+}
@@ -0,0 +1,1168 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use clap::{Args, Subcommand, ValueEnum};
+use soroban_sdk::{BytesN, Env, IntoVal, String, TryFromVal, Val};
+use stellar_xdr::curr::ScVal;
+use sytemap::types::{DocumentType, PaymentSchedule, PropertyStatus, Role};
+use sytemap::util::{MAX_INTEGRITY_PAGE_SIZE, MAX_PAGE_SIZE, MAX_PAYMENT_PLAN_TOTAL};
+
+use crate::{
+    error::CliError,
+    payload::{parse_address, read_payloads, to_contract, validate_payloads, PropertyPayload},
+};
+
+/// A contract function call with its arguments encoded as XDR values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invocation {
+    pub function: &'static str,
+    pub args: Vec<ScVal>,
+}
+
+/// Every entry point of the registry contract.
+#[derive(Debug, Clone, Subcommand)]
+pub enum Call {
+    /// Initialize the registry with its admin and token metadata
+    Initialize {
+        #[arg(long)]
+        admin: std::string::String,
+        #[arg(long)]
+        sytemap_name: std::string::String,
+        #[arg(long)]
+        sytemap_symbol: std::string::String,
+        #[arg(long)]
+        metadata_uri: std::string::String,
+    },
+    GetAdmin,
+    /// Point the registry at a new metadata URI
+    UpdateMetadataUri {
+        #[arg(long)]
+        metadata_uri: std::string::String,
+    },
+    GrantRole {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        #[arg(long)]
+        account: std::string::String,
+    },
+    RevokeRole {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        #[arg(long)]
+        account: std::string::String,
+    },
+    HasRole {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        #[arg(long)]
+        account: std::string::String,
+    },
+    SetEstateCompanyAccount {
+        #[arg(long)]
+        estate_company_name: std::string::String,
+        #[arg(long)]
+        account: std::string::String,
+    },
+    GetEstateCompanyAccount {
+        #[arg(long)]
+        estate_company_name: std::string::String,
+    },
+    /// Mint one property per payload in a JSON or CSV file
+    SafeMintNewPropertyInfo {
+        #[arg(long)]
+        payload: PathBuf,
+    },
+    /// Mint every payload in a JSON or CSV file in one all-or-nothing call
+    BatchMintNewPropertyInfo {
+        #[arg(long)]
+        payload: PathBuf,
+    },
+    SetMintApprovalConfig {
+        #[arg(long)]
+        threshold: u32,
+        #[arg(long)]
+        proposal_ttl: u64,
+    },
+    /// Propose one mint per payload in a JSON or CSV file
+    ProposeMint {
+        #[arg(long)]
+        proposer: std::string::String,
+        #[arg(long)]
+        payload: PathBuf,
+    },
+    ApproveMint {
+        #[arg(long)]
+        proposal_id: u64,
+        #[arg(long)]
+        verifier: std::string::String,
+    },
+    GetPendingMints,
+    ChangePropertyPriceByOwner {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        new_price: u64,
+    },
+    TransferPropertyByOwner {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        new_owner: std::string::String,
+    },
+    CreatePaymentPlan {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        buyer: std::string::String,
+        #[arg(long)]
+        total: i128,
+        #[command(flatten)]
+        schedule: ScheduleArgs,
+    },
+    PayInstallment {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        amount: i128,
+    },
+    DeclarePaymentDefault {
+        #[arg(long)]
+        pvn: u64,
+    },
+    Repossess {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetPaymentStatus {
+        #[arg(long)]
+        pvn: u64,
+    },
+    RegisterLien {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        lienholder: std::string::String,
+        #[arg(long)]
+        amount: i128,
+        #[arg(long)]
+        expiry: u64,
+    },
+    ReleaseLien {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        lienholder: std::string::String,
+    },
+    ConsentToLienTransfer {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        lienholder: std::string::String,
+        #[arg(long)]
+        transferee: std::string::String,
+    },
+    /// Drop the liens on a plot that have passed their expiry
+    ClearExpiredLiens {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetEncumbrances {
+        #[arg(long)]
+        pvn: u64,
+    },
+    /// Let a plot to a tenant, who pays the rent up front
+    CreateLease {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        tenant: std::string::String,
+        #[arg(long)]
+        start: u64,
+        #[arg(long)]
+        end: u64,
+        #[arg(long)]
+        rent: i128,
+        #[arg(long)]
+        token: std::string::String,
+    },
+    TerminateLease {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetActiveLease {
+        #[arg(long)]
+        pvn: u64,
+    },
+    LockAsCollateral {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        locker_contract: std::string::String,
+    },
+    Unlock {
+        #[arg(long)]
+        pvn: u64,
+    },
+    LiquidateTo {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        new_owner: std::string::String,
+    },
+    IsLocked {
+        #[arg(long)]
+        pvn: u64,
+    },
+    FreezeProperty {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        arbitrator: std::string::String,
+        #[arg(long)]
+        case_ref: std::string::String,
+    },
+    UnfreezeProperty {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        arbitrator: std::string::String,
+        #[arg(long)]
+        resolution: std::string::String,
+    },
+    ResolveDisputeTransfer {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        arbitrator: std::string::String,
+        #[arg(long)]
+        new_owner: std::string::String,
+    },
+    GetDispute {
+        #[arg(long)]
+        pvn: u64,
+    },
+    SubmitForSurvey {
+        #[arg(long)]
+        pvn: u64,
+    },
+    VerifyProperty {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        verifier: std::string::String,
+    },
+    RejectSurvey {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        verifier: std::string::String,
+    },
+    AllocateProperty {
+        #[arg(long)]
+        pvn: u64,
+    },
+    RevokeProperty {
+        #[arg(long)]
+        pvn: u64,
+    },
+    /// Split a plot into the child plots in a JSON or CSV file
+    Subdivide {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        verifier: std::string::String,
+        #[arg(long)]
+        payload: PathBuf,
+    },
+    /// Merge the plots given by repeated `--pvn` into the single plot in a JSON or CSV file
+    MergePlots {
+        #[arg(long = "pvn", required = true)]
+        pvns: Vec<u64>,
+        #[arg(long)]
+        verifier: std::string::String,
+        #[arg(long)]
+        payload: PathBuf,
+    },
+    GetPropertiesByStatus {
+        #[arg(long, value_enum)]
+        status: StatusArg,
+    },
+    AttachDocument {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long)]
+        attester: std::string::String,
+        #[arg(long, value_enum)]
+        doc_type: DocumentTypeArg,
+        /// Hex encoded SHA-256 of the document
+        #[arg(long)]
+        sha256_hash: std::string::String,
+        #[arg(long)]
+        uri: std::string::String,
+    },
+    GetDocuments {
+        #[arg(long)]
+        pvn: u64,
+    },
+    VerifyDocument {
+        #[arg(long)]
+        pvn: u64,
+        #[arg(long, value_enum)]
+        doc_type: DocumentTypeArg,
+        /// Hex encoded SHA-256 of the document
+        #[arg(long)]
+        sha256_hash: std::string::String,
+    },
+    SetKycStatus {
+        #[arg(long)]
+        officer: std::string::String,
+        #[arg(long)]
+        account: std::string::String,
+        #[arg(long)]
+        level: u32,
+        #[arg(long)]
+        expiry: u64,
+    },
+    GetKycStatus {
+        #[arg(long)]
+        account: std::string::String,
+    },
+    /// Set the external KYC contract, or clear it when `--provider` is omitted
+    SetKycProvider {
+        #[arg(long)]
+        provider: Option<std::string::String>,
+    },
+    SetEstateKycLevel {
+        #[arg(long)]
+        caller: std::string::String,
+        #[arg(long)]
+        estate_name: std::string::String,
+        #[arg(long)]
+        min_level: u32,
+    },
+    GetEstateKycLevel {
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    Pause {
+        #[arg(long)]
+        pauser: std::string::String,
+    },
+    Unpause {
+        #[arg(long)]
+        pauser: std::string::String,
+    },
+    PauseEstate {
+        #[arg(long)]
+        pauser: std::string::String,
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    UnpauseEstate {
+        #[arg(long)]
+        pauser: std::string::String,
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    IsPaused,
+    IsEstatePaused {
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    GetPropertyInfoDetailsByPvn {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetPropertyInfoByNftAddress {
+        #[arg(long)]
+        nft_address: std::string::String,
+    },
+    GetPropertyByEstatePlot {
+        #[arg(long)]
+        estate_name: std::string::String,
+        #[arg(long)]
+        plot_no: std::string::String,
+    },
+    GetLineage {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetNoOfPropertyByAddress {
+        #[arg(long)]
+        owner_address: std::string::String,
+    },
+    GetNumberOfPropertyMinted,
+    GetOwnerOfPvn {
+        #[arg(long)]
+        pvn: u64,
+    },
+    GetAllPropertyByOwner {
+        #[arg(long)]
+        owner_address: std::string::String,
+    },
+    GetPropertiesByOwnerPage {
+        #[arg(long)]
+        owner_address: std::string::String,
+        /// `next_cursor` of the previous page, 0 for the first
+        #[arg(long, default_value = "0")]
+        cursor: u32,
+        #[arg(long)]
+        limit: u32,
+    },
+    GetPropertiesByEstate {
+        #[arg(long)]
+        estate_name: std::string::String,
+        /// `next_cursor` of the previous page, 0 for the first
+        #[arg(long, default_value = "0")]
+        cursor: u32,
+        #[arg(long)]
+        limit: u32,
+    },
+    GetPropertiesByEstateCompany {
+        #[arg(long)]
+        estate_company_name: std::string::String,
+        /// `next_cursor` of the previous page, 0 for the first
+        #[arg(long, default_value = "0")]
+        cursor: u32,
+        #[arg(long)]
+        limit: u32,
+    },
+    CountPropertiesByEstate {
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    GetEstateSummary {
+        #[arg(long)]
+        estate_name: std::string::String,
+    },
+    GetAllMintedPropertyDetails,
+    VerifyIntegrity {
+        /// `next_cursor` of the previous report, 0 for the first
+        #[arg(long, default_value = "0")]
+        cursor: u32,
+        #[arg(long)]
+        limit: u32,
+    },
+    RepairIndex {
+        #[arg(long)]
+        pvn: u64,
+    },
+}
+
+/// Installment plan terms, see `PaymentSchedule`.
+#[derive(Debug, Clone, Args)]
+pub struct ScheduleArgs {
+    /// Token contract the installments are paid in
+    #[arg(long)]
+    pub token: std::string::String,
+    #[arg(long)]
+    pub installment_count: u32,
+    /// Seconds between due dates
+    #[arg(long)]
+    pub interval: u64,
+    /// Ledger timestamp of the first due date
+    #[arg(long)]
+    pub first_due_date: u64,
+    #[arg(long)]
+    pub grace_period_days: u64,
+    #[arg(long)]
+    pub notice_period_days: u64,
+    #[arg(long)]
+    pub refund_percentage: u32,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum RoleArg {
+    Arbitrator,
+    Verifier,
+    EstateCompany,
+    Compliance,
+    Pauser,
+}
+
+impl From<RoleArg> for Role {
+    fn from(role: RoleArg) -> Self {
+        match role {
+            RoleArg::Arbitrator => Role::Arbitrator,
+            RoleArg::Verifier => Role::Verifier,
+            RoleArg::EstateCompany => Role::EstateCompany,
+            RoleArg::Compliance => Role::Compliance,
+            RoleArg::Pauser => Role::Pauser,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum StatusArg {
+    Draft,
+    PendingSurvey,
+    Verified,
+    Allocated,
+    Encumbered,
+    InPayment,
+    InDefault,
+    Frozen,
+    Revoked,
+    Subdivided,
+    Merged,
+}
+
+impl From<StatusArg> for PropertyStatus {
+    fn from(status: StatusArg) -> Self {
+        match status {
+            StatusArg::Draft => PropertyStatus::Draft,
+            StatusArg::PendingSurvey => PropertyStatus::PendingSurvey,
+            StatusArg::Verified => PropertyStatus::Verified,
+            StatusArg::Allocated => PropertyStatus::Allocated,
+            StatusArg::Encumbered => PropertyStatus::Encumbered,
+            StatusArg::InPayment => PropertyStatus::InPayment,
+            StatusArg::InDefault => PropertyStatus::InDefault,
+            StatusArg::Frozen => PropertyStatus::Frozen,
+            StatusArg::Revoked => PropertyStatus::Revoked,
+            StatusArg::Subdivided => PropertyStatus::Subdivided,
+            StatusArg::Merged => PropertyStatus::Merged,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DocumentTypeArg {
+    SurveyPlan,
+    DeedOfAssignment,
+    CertificateOfOccupancy,
+    GovernorsConsent,
+    Receipt,
+}
+
+impl From<DocumentTypeArg> for DocumentType {
+    fn from(doc_type: DocumentTypeArg) -> Self {
+        match doc_type {
+            DocumentTypeArg::SurveyPlan => DocumentType::SurveyPlan,
+            DocumentTypeArg::DeedOfAssignment => DocumentType::DeedOfAssignment,
+            DocumentTypeArg::CertificateOfOccupancy => DocumentType::CertificateOfOccupancy,
+            DocumentTypeArg::GovernorsConsent => DocumentType::GovernorsConsent,
+            DocumentTypeArg::Receipt => DocumentType::Receipt,
+        }
+    }
+}
+
+impl Call {
+    /// Name of the contract function this subcommand calls.
+    pub fn function(&self) -> &'static str {
+        match self {
+            Call::Initialize { .. } => "initialize",
+            Call::GetAdmin => "get_admin",
+            Call::UpdateMetadataUri { .. } => "update_metadata_uri",
+            Call::GrantRole { .. } => "grant_role",
+            Call::RevokeRole { .. } => "revoke_role",
+            Call::HasRole { .. } => "has_role",
+            Call::SetEstateCompanyAccount { .. } => "set_estate_company_account",
+            Call::GetEstateCompanyAccount { .. } => "get_estate_company_account",
+            Call::SafeMintNewPropertyInfo { .. } => "safe_mint_new_property_info",
+            Call::BatchMintNewPropertyInfo { .. } => "batch_mint_new_property_info",
+            Call::SetMintApprovalConfig { .. } => "set_mint_approval_config",
+            Call::ProposeMint { .. } => "propose_mint",
+            Call::ApproveMint { .. } => "approve_mint",
+            Call::GetPendingMints => "get_pending_mints",
+            Call::ChangePropertyPriceByOwner { .. } => "change_property_price_by_owner",
+            Call::TransferPropertyByOwner { .. } => "transfer_property_by_owner",
+            Call::CreatePaymentPlan { .. } => "create_payment_plan",
+            Call::PayInstallment { .. } => "pay_installment",
+            Call::DeclarePaymentDefault { .. } => "declare_payment_default",
+            Call::Repossess { .. } => "repossess",
+            Call::GetPaymentStatus { .. } => "get_payment_status",
+            Call::RegisterLien { .. } => "register_lien",
+            Call::ReleaseLien { .. } => "release_lien",
+            Call::ConsentToLienTransfer { .. } => "consent_to_lien_transfer",
+            Call::ClearExpiredLiens { .. } => "clear_expired_liens",
+            Call::GetEncumbrances { .. } => "get_encumbrances",
+            Call::CreateLease { .. } => "create_lease",
+            Call::TerminateLease { .. } => "terminate_lease",
+            Call::GetActiveLease { .. } => "get_active_lease",
+            Call::LockAsCollateral { .. } => "lock_as_collateral",
+            Call::Unlock { .. } => "unlock",
+            Call::LiquidateTo { .. } => "liquidate_to",
+            Call::IsLocked { .. } => "is_locked",
+            Call::FreezeProperty { .. } => "freeze_property",
+            Call::UnfreezeProperty { .. } => "unfreeze_property",
+            Call::ResolveDisputeTransfer { .. } => "resolve_dispute_transfer",
+            Call::GetDispute { .. } => "get_dispute",
+            Call::SubmitForSurvey { .. } => "submit_for_survey",
+            Call::VerifyProperty { .. } => "verify_property",
+            Call::RejectSurvey { .. } => "reject_survey",
+            Call::AllocateProperty { .. } => "allocate_property",
+            Call::RevokeProperty { .. } => "revoke_property",
+            Call::Subdivide { .. } => "subdivide",
+            Call::MergePlots { .. } => "merge_plots",
+            Call::GetPropertiesByStatus { .. } => "get_properties_by_status",
+            Call::AttachDocument { .. } => "attach_document",
+            Call::GetDocuments { .. } => "get_documents",
+            Call::VerifyDocument { .. } => "verify_document",
+            Call::SetKycStatus { .. } => "set_kyc_status",
+            Call::GetKycStatus { .. } => "get_kyc_status",
+            Call::SetKycProvider { .. } => "set_kyc_provider",
+            Call::SetEstateKycLevel { .. } => "set_estate_kyc_level",
+            Call::GetEstateKycLevel { .. } => "get_estate_kyc_level",
+            Call::Pause { .. } => "pause",
+            Call::Unpause { .. } => "unpause",
+            Call::PauseEstate { .. } => "pause_estate",
+            Call::UnpauseEstate { .. } => "unpause_estate",
+            Call::IsPaused => "is_paused",
+            Call::IsEstatePaused { .. } => "is_estate_paused",
+            Call::GetPropertyInfoDetailsByPvn { .. } => "get_property_info_details_by_pvn",
+            Call::GetPropertyInfoByNftAddress { .. } => "get_property_info_by_nft_address",
+            Call::GetPropertyByEstatePlot { .. } => "get_property_by_estate_plot",
+            Call::GetLineage { .. } => "get_lineage",
+            Call::GetNoOfPropertyByAddress { .. } => "get_no_of_property_by_address",
+            Call::GetNumberOfPropertyMinted => "get_number_of_property_minted",
+            Call::GetOwnerOfPvn { .. } => "get_owner_of_pvn",
+            Call::GetAllPropertyByOwner { .. } => "get_all_property_by_owner",
+            Call::GetPropertiesByOwnerPage { .. } => "get_properties_by_owner_page",
+            Call::GetPropertiesByEstate { .. } => "get_properties_by_estate",
+            Call::GetPropertiesByEstateCompany { .. } => "get_properties_by_estate_company",
+            Call::CountPropertiesByEstate { .. } => "count_properties_by_estate",
+            Call::GetEstateSummary { .. } => "get_estate_summary",
+            Call::GetAllMintedPropertyDetails => "get_all_minted_property_details",
+            Call::VerifyIntegrity { .. } => "verify_integrity",
+            Call::RepairIndex { .. } => "repair_index",
+        }
+    }
+
+    /// Validates the arguments and encodes them. Mints read from a file produce one
+    /// invocation per payload; every other call produces exactly one.
+    pub fn invocations(&self, env: &Env) -> Result<Vec<Invocation>, CliError> {
+        let function = self.function();
+        let single = |args: Vec<ScVal>| Ok(vec![Invocation { function, args }]);
+        let address = |field, value: &str| parse_address(env, field, value);
+        let string = |value: &str| String::from_str(env, value);
+
+        match self {
+            Call::Initialize {
+                admin,
+                sytemap_name,
+                sytemap_symbol,
+                metadata_uri,
+            } => single(vec![
+                arg(env, "admin", address("admin", admin)?)?,
+                arg(env, "sytemap_name", string(sytemap_name))?,
+                arg(env, "sytemap_symbol", string(sytemap_symbol))?,
+                arg(env, "metadata_uri", string(metadata_uri))?,
+            ]),
+            Call::GetAdmin
+            | Call::GetPendingMints
+            | Call::IsPaused
+            | Call::GetNumberOfPropertyMinted
+            | Call::GetAllMintedPropertyDetails => single(vec![]),
+            Call::UpdateMetadataUri { metadata_uri } => {
+                single(vec![arg(env, "metadata_uri", string(metadata_uri))?])
+            }
+            Call::GrantRole { role, account }
+            | Call::RevokeRole { role, account }
+            | Call::HasRole { role, account } => single(vec![
+                arg(env, "role", Role::from(*role))?,
+                arg(env, "account", address("account", account)?)?,
+            ]),
+            Call::SetEstateCompanyAccount {
+                estate_company_name,
+                account,
+            } => single(vec![
+                arg(env, "estate_company_name", string(estate_company_name))?,
+                arg(env, "account", address("account", account)?)?,
+            ]),
+            Call::GetEstateCompanyAccount {
+                estate_company_name,
+            } => single(vec![arg(
+                env,
+                "estate_company_name",
+                string(estate_company_name),
+            )?]),
+            Call::SafeMintNewPropertyInfo { payload } => {
+                let payloads = read_payloads(payload)?;
+                validate_payloads(&payloads)?;
+                payloads
+                    .iter()
+                    .map(|payload| {
+                        Ok(Invocation {
+                            function,
+                            args: vec![arg(env, "payload", to_contract(env, payload)?)?],
+                        })
+                    })
+                    .collect()
+            }
+            Call::BatchMintNewPropertyInfo { payload } => {
+                let payloads = read_payloads(payload)?;
+                validate_payloads(&payloads)?;
+                Ok(vec![batch_mint_invocation(env, &payloads)?])
+            }
+            Call::SetMintApprovalConfig {
+                threshold,
+                proposal_ttl,
+            } => single(vec![
+                arg(env, "threshold", *threshold)?,
+                arg(env, "proposal_ttl", *proposal_ttl)?,
+            ]),
+            Call::ProposeMint { proposer, payload } => {
+                let proposer = address("proposer", proposer)?;
+                let payloads = read_payloads(payload)?;
+                validate_payloads(&payloads)?;
+                payloads
+                    .iter()
+                    .map(|payload| {
+                        Ok(Invocation {
+                            function,
+                            args: vec![
+                                arg(env, "proposer", proposer.clone())?,
+                                arg(env, "payload", to_contract(env, payload)?)?,
+                            ],
+                        })
+                    })
+                    .collect()
+            }
+            Call::ApproveMint {
+                proposal_id,
+                verifier,
+            } => single(vec![
+                arg(env, "proposal_id", *proposal_id)?,
+                arg(env, "verifier", address("verifier", verifier)?)?,
+            ]),
+            Call::ChangePropertyPriceByOwner { pvn, new_price } => {
+                if *new_price == 0 {
+                    return Err(invalid(
+                        "new_price",
+                        "must be greater than zero (InvalidPrice)",
+                    ));
+                }
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "new_price", *new_price)?,
+                ])
+            }
+            Call::TransferPropertyByOwner { pvn, new_owner } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "new_owner", address("new_owner", new_owner)?)?,
+            ]),
+            Call::CreatePaymentPlan {
+                pvn,
+                buyer,
+                total,
+                schedule,
+            } => {
+                validate_payment_plan(*total, schedule)?;
+                let schedule = PaymentSchedule {
+                    token: address("token", &schedule.token)?,
+                    installment_count: schedule.installment_count,
+                    interval: schedule.interval,
+                    first_due_date: schedule.first_due_date,
+                    grace_period_days: schedule.grace_period_days,
+                    notice_period_days: schedule.notice_period_days,
+                    refund_percentage: schedule.refund_percentage,
+                };
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "buyer", address("buyer", buyer)?)?,
+                    arg(env, "total", *total)?,
+                    arg(env, "schedule", schedule)?,
+                ])
+            }
+            Call::PayInstallment { pvn, amount } => {
+                if *amount <= 0 {
+                    return Err(invalid(
+                        "amount",
+                        "must be greater than zero (InvalidPaymentAmount)",
+                    ));
+                }
+                single(vec![pvn_arg(env, *pvn)?, arg(env, "amount", *amount)?])
+            }
+            Call::DeclarePaymentDefault { pvn }
+            | Call::Repossess { pvn }
+            | Call::GetPaymentStatus { pvn }
+            | Call::ClearExpiredLiens { pvn }
+            | Call::GetEncumbrances { pvn }
+            | Call::TerminateLease { pvn }
+            | Call::GetActiveLease { pvn }
+            | Call::Unlock { pvn }
+            | Call::IsLocked { pvn }
+            | Call::GetDispute { pvn }
+            | Call::SubmitForSurvey { pvn }
+            | Call::AllocateProperty { pvn }
+            | Call::RevokeProperty { pvn }
+            | Call::GetDocuments { pvn }
+            | Call::GetPropertyInfoDetailsByPvn { pvn }
+            | Call::GetOwnerOfPvn { pvn }
+            | Call::GetLineage { pvn }
+            | Call::RepairIndex { pvn } => single(vec![pvn_arg(env, *pvn)?]),
+            Call::RegisterLien {
+                pvn,
+                lienholder,
+                amount,
+                expiry,
+            } => {
+                if *amount <= 0 {
+                    return Err(invalid("amount", "must be greater than zero (InvalidLien)"));
+                }
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "lienholder", address("lienholder", lienholder)?)?,
+                    arg(env, "amount", *amount)?,
+                    arg(env, "expiry", *expiry)?,
+                ])
+            }
+            Call::ReleaseLien { pvn, lienholder } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "lienholder", address("lienholder", lienholder)?)?,
+            ]),
+            Call::ConsentToLienTransfer {
+                pvn,
+                lienholder,
+                transferee,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "lienholder", address("lienholder", lienholder)?)?,
+                arg(env, "transferee", address("transferee", transferee)?)?,
+            ]),
+            Call::CreateLease {
+                pvn,
+                tenant,
+                start,
+                end,
+                rent,
+                token,
+            } => {
+                if *rent <= 0 {
+                    return Err(invalid("rent", "must be greater than zero (InvalidLease)"));
+                }
+                if start >= end {
+                    return Err(invalid("end", "must be after start (InvalidLease)"));
+                }
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "tenant", address("tenant", tenant)?)?,
+                    arg(env, "start", *start)?,
+                    arg(env, "end", *end)?,
+                    arg(env, "rent", *rent)?,
+                    arg(env, "token", address("token", token)?)?,
+                ])
+            }
+            Call::LockAsCollateral {
+                pvn,
+                locker_contract,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(
+                    env,
+                    "locker_contract",
+                    address("locker_contract", locker_contract)?,
+                )?,
+            ]),
+            Call::LiquidateTo { pvn, new_owner } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "new_owner", address("new_owner", new_owner)?)?,
+            ]),
+            Call::FreezeProperty {
+                pvn,
+                arbitrator,
+                case_ref,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "arbitrator", address("arbitrator", arbitrator)?)?,
+                arg(env, "case_ref", string(case_ref))?,
+            ]),
+            Call::UnfreezeProperty {
+                pvn,
+                arbitrator,
+                resolution,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "arbitrator", address("arbitrator", arbitrator)?)?,
+                arg(env, "resolution", string(resolution))?,
+            ]),
+            Call::ResolveDisputeTransfer {
+                pvn,
+                arbitrator,
+                new_owner,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "arbitrator", address("arbitrator", arbitrator)?)?,
+                arg(env, "new_owner", address("new_owner", new_owner)?)?,
+            ]),
+            Call::VerifyProperty { pvn, verifier } | Call::RejectSurvey { pvn, verifier } => {
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "verifier", address("verifier", verifier)?)?,
+                ])
+            }
+            Call::Subdivide {
+                pvn,
+                verifier,
+                payload,
+            } => {
+                let payloads = read_payloads(payload)?;
+                validate_payloads(&payloads)?;
+                if payloads.len() < 2 {
+                    return Err(invalid(
+                        "payload",
+                        "must hold at least two child plots (InvalidSubdivision)",
+                    ));
+                }
+                let mut child_payloads = soroban_sdk::Vec::new(env);
+                for payload in &payloads {
+                    child_payloads.push_back(to_contract(env, payload)?);
+                }
+                single(vec![
+                    pvn_arg(env, *pvn)?,
+                    arg(env, "verifier", address("verifier", verifier)?)?,
+                    arg(env, "child_payloads", child_payloads)?,
+                ])
+            }
+            Call::MergePlots {
+                pvns,
+                verifier,
+                payload,
+            } => {
+                let unique: BTreeSet<_> = pvns.iter().collect();
+                if pvns.len() < 2 || unique.len() != pvns.len() {
+                    return Err(invalid(
+                        "pvns",
+                        "must name at least two distinct plots (InvalidMerge)",
+                    ));
+                }
+                let payloads = read_payloads(payload)?;
+                validate_payloads(&payloads)?;
+                let [new_payload] = payloads.as_slice() else {
+                    return Err(invalid("payload", "must hold exactly one plot"));
+                };
+                single(vec![
+                    arg(env, "pvns", soroban_sdk::Vec::from_slice(env, pvns))?,
+                    arg(env, "verifier", address("verifier", verifier)?)?,
+                    arg(env, "new_payload", to_contract(env, new_payload)?)?,
+                ])
+            }
+            Call::GetPropertiesByStatus { status } => {
+                single(vec![arg(env, "status", PropertyStatus::from(*status))?])
+            }
+            Call::AttachDocument {
+                pvn,
+                attester,
+                doc_type,
+                sha256_hash,
+                uri,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "attester", address("attester", attester)?)?,
+                arg(env, "doc_type", DocumentType::from(*doc_type))?,
+                arg(env, "sha256_hash", parse_hash(env, sha256_hash)?)?,
+                arg(env, "uri", string(uri))?,
+            ]),
+            Call::VerifyDocument {
+                pvn,
+                doc_type,
+                sha256_hash,
+            } => single(vec![
+                pvn_arg(env, *pvn)?,
+                arg(env, "doc_type", DocumentType::from(*doc_type))?,
+                arg(env, "sha256_hash", parse_hash(env, sha256_hash)?)?,
+            ]),
+            Call::SetKycStatus {
+                officer,
+                account,
+                level,
+                expiry,
+            } => single(vec![
+                arg(env, "officer", address("officer", officer)?)?,
+                arg(env, "account", address("account", account)?)?,
+                arg(env, "level", *level)?,
+                arg(env, "expiry", *expiry)?,
+            ]),
+            Call::GetKycStatus { account } => {
+                single(vec![arg(env, "account", address("account", account)?)?])
+            }
+            Call::SetKycProvider { provider } => {
+                let provider = provider
+                    .as_deref()
+                    .map(|provider| address("provider", provider))
+                    .transpose()?;
+                single(vec![arg(env, "provider", provider)?])
+            }
+            Call::SetEstateKycLevel {
+                caller,
+                estate_name,
+                min_level,
+            } => single(vec![
+                arg(env, "caller", address("caller", caller)?)?,
+                arg(env, "estate_name", string(estate_name))?,
+                arg(env, "min_level", *min_level)?,
+            ]),
+            Call::GetEstateKycLevel { estate_name } | Call::IsEstatePaused { estate_name } => {
+                single(vec![arg(env, "estate_name", string(estate_name))?])
+            }
+            Call::Pause { pauser } | Call::Unpause { pauser } => {
+                single(vec![arg(env, "pauser", address("pauser", pauser)?)?])
+            }
+            Call::PauseEstate {
+                pauser,
+                estate_name,
+            }
+            | Call::UnpauseEstate {
+                pauser,
+                estate_name,
+            } => single(vec![
+                arg(env, "pauser", address("pauser", pauser)?)?,
+                arg(env, "estate_name", string(estate_name))?,
+            ]),
+            Call::GetPropertyInfoByNftAddress { nft_address } => {
+                single(vec![arg(env, "nft_address", string(nft_address))?])
+            }
+            Call::GetPropertyByEstatePlot {
+                estate_name,
+                plot_no,
+            } => single(vec![
+                arg(env, "estate_name", string(estate_name))?,
+                arg(env, "plot_no", string(plot_no))?,
+            ]),
+            Call::GetNoOfPropertyByAddress { owner_address }
+            | Call::GetAllPropertyByOwner { owner_address } => single(vec![arg(
+                env,
+                "owner_address",
+                address("owner_address", owner_address)?,
+            )?]),
+            Call::GetPropertiesByOwnerPage {
+                owner_address,
+                cursor,
+                limit,
+            } => {
+                check_page_limit(*limit, MAX_PAGE_SIZE)?;
+                single(vec![
+                    arg(
+                        env,
+                        "owner_address",
+                        address("owner_address", owner_address)?,
+                    )?,
+                    arg(env, "cursor", *cursor)?,
+                    arg(env, "limit", *limit)?,
+                ])
+            }
+            Call::GetPropertiesByEstate {
+                estate_name: name,
+                cursor,
+                limit,
+            }
+            | Call::GetPropertiesByEstateCompany {
+                estate_company_name: name,
+                cursor,
+                limit,
+            } => {
+                check_page_limit(*limit, MAX_PAGE_SIZE)?;
+                let field = match self {
+                    Call::GetPropertiesByEstate { .. } => "estate_name",
+                    _ => "estate_company_name",
+                };
+                single(vec![
+                    arg(env, field, string(name))?,
+                    arg(env, "cursor", *cursor)?,
+                    arg(env, "limit", *limit)?,
+                ])
+            }
+            Call::CountPropertiesByEstate { estate_name }
+            | Call::GetEstateSummary { estate_name } => {
+                single(vec![arg(env, "estate_name", string(estate_name))?])
+            }
+            Call::VerifyIntegrity { cursor, limit } => {
+                check_page_limit(*limit, MAX_INTEGRITY_PAGE_SIZE)?;
+                single(vec![
+                    arg(env, "cursor", *cursor)?,
+                    arg(env, "limit", *limit)?,
+                ])
+            }
+        }
+    }
+}
+
+/// A `batch_mint_new_property_info` call minting `payloads` in order.
+pub fn batch_mint_invocation(
+    env: &Env,
+    payloads: &[PropertyPayload],
+) -> Result<Invocation, CliError> {
+    let mut batch = soroban_sdk::Vec::new(env);
+    for payload in payloads {
+        batch.push_back(to_contract(env, payload)?);
+    }
+    Ok(Invocation {
+        function: "batch_mint_new_property_info",
+        args: vec![arg(env, "payloads", batch)?],
+    })
+}
+
+/// Encodes a contract value as XDR.
+fn arg<T: IntoVal<Env, Val>>(env: &Env, name: &'static str, value: T) -> Result<ScVal, CliError> {
+    ScVal::try_from_val(env, &value.into_val(env)).map_err(|_| CliError::Conversion(name))
+}
+
+fn pvn_arg(env: &Env, pvn: u64) -> Result<ScVal, CliError> {
+    arg(env, "property_verification_no", pvn)
+}
+
+fn invalid(field: &'static str, reason: &str) -> CliError {
+    CliError::InvalidArgument {
+        field,
+        reason: reason.to_string(),
+    }
+}
+
+fn parse_hash(env: &Env, hash: &str) -> Result<BytesN<32>, CliError> {
+    let bytes: [u8; 32] = hex::decode(hash)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| invalid("sha256_hash", "must be 32 hex encoded bytes"))?;
+    Ok(BytesN::from_array(env, &bytes))
+}
+
+/// The page size check paginated queries make.
+fn check_page_limit(limit: u32, max: u32) -> Result<(), CliError> {
+    if limit == 0 || limit > max {
+        return Err(invalid(
+            "limit",
+            &format!("must be between 1 and {max} (InvalidPageLimit)"),
+        ));
+    }
+    Ok(())
+}
+
+/// The plan checks `create_payment_plan` makes that do not need ledger state.
+fn validate_payment_plan(total: i128, schedule: &ScheduleArgs) -> Result<(), CliError> {
+    if total <= 0 {
+        return Err(invalid(
+            "total",
+            "must be greater than zero (InvalidPaymentPlan)",
+        ));
+    }
+    if total > MAX_PAYMENT_PLAN_TOTAL {
+        return Err(invalid(
+            "total",
+            &format!("must be at most {MAX_PAYMENT_PLAN_TOTAL} (InvalidPaymentPlan)"),
+        ));
+    }
+    if schedule.installment_count == 0 {
+        return Err(invalid(
+            "installment_count",
+            "must be greater than zero (InvalidPaymentPlan)",
+        ));
+    }
+    if total < schedule.installment_count as i128 {
+        return Err(invalid(
+            "total",
+            "must be at least the installment count (InvalidPaymentPlan)",
+        ));
+    }
+    if schedule.installment_count > 1 && schedule.interval == 0 {
+        return Err(invalid(
+            "interval",
+            "must be set when there is more than one installment (InvalidPaymentPlan)",
+        ));
+    }
+    if schedule.refund_percentage > 100 {
+        return Err(invalid(
+            "refund_percentage",
+            "must be at most 100 (InvalidPaymentPlan)",
+        ));
+    }
+    Ok(())
+}
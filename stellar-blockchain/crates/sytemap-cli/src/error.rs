@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors raised while preparing or simulating a contract call.
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("{path}: {source}")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("{path}: {source}")]
+    Csv {
+        path: PathBuf,
+        #[source]
+        source: csv::Error,
+    },
+    #[error("{path}: unsupported payload format, expected .json or .csv")]
+    UnsupportedFormat { path: PathBuf },
+    #[error("`{field}`: `{value}` is not a valid Stellar address")]
+    InvalidAddress { field: &'static str, value: String },
+    #[error("`{field}` {reason}")]
+    InvalidArgument { field: &'static str, reason: String },
+    #[error("row {row}: {reason}")]
+    InvalidPayload { row: usize, reason: String },
+    #[error("{0} is required")]
+    MissingOption(&'static str),
+    #[error("failed to encode transaction: {0}")]
+    Xdr(#[from] stellar_xdr::curr::Error),
+    #[error("failed to convert argument `{0}` to XDR")]
+    Conversion(&'static str),
+    #[error("failed to load snapshot {path}: {reason}")]
+    Snapshot { path: PathBuf, reason: String },
+    #[error("`{function}` failed in simulation: {reason}")]
+    Simulation {
+        function: &'static str,
+        reason: String,
+    },
+    #[error("{path}: no column matches `{field}`; map one with --column {field}=<header>")]
+    MissingColumn { path: PathBuf, field: &'static str },
+}
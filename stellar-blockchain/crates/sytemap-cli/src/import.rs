@@ -0,0 +1,405 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::Path,
+    str::FromStr,
+};
+
+use csv::StringRecord;
+use serde::Serialize;
+use soroban_sdk::{TryFromVal, Val};
+use stellar_xdr::curr::{ScAddress, ScVal};
+use sytemap::types::PropertyInfo;
+use sytemap_types::mirror::Address;
+
+use crate::{
+    call::{batch_mint_invocation, Invocation},
+    error::CliError,
+    payload::PropertyPayload,
+    simulate::Simulator,
+};
+
+/// The `PropertyInfoPayload` fields, in the order the contract declares them.
+pub const PAYLOAD_FIELDS: [&str; 12] = [
+    "price_of_plot",
+    "property_verification_no",
+    "buyer_wallet_id",
+    "plot_no",
+    "size_of_plot",
+    "coordinate_of_plot",
+    "token_url",
+    "estate_name",
+    "plot_url",
+    "date_of_allocation",
+    "estate_company_name",
+    "nft_address",
+];
+
+/// Which spreadsheet column feeds each payload field. Headers are compared ignoring case,
+/// spacing and punctuation, so a `Plot No.` column fills `plot_no` without a mapping.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMap {
+    headers: BTreeMap<&'static str, String>,
+}
+
+impl ColumnMap {
+    /// Reads `field` from the column titled `header` instead of the one named after it.
+    pub fn map(&mut self, field: &str, header: &str) -> Result<&mut Self, CliError> {
+        let field = PAYLOAD_FIELDS
+            .into_iter()
+            .find(|known| *known == field)
+            .ok_or_else(|| CliError::InvalidArgument {
+                field: "column",
+                reason: format!("`{field}` is not a PropertyInfoPayload field"),
+            })?;
+        self.headers.insert(field, header.to_string());
+        Ok(self)
+    }
+
+    /// Index of the column feeding each field, in `PAYLOAD_FIELDS` order.
+    fn positions(&self, path: &Path, headers: &StringRecord) -> Result<Vec<usize>, CliError> {
+        let headers: Vec<String> = headers.iter().map(normalize_header).collect();
+        PAYLOAD_FIELDS
+            .into_iter()
+            .map(|field| {
+                let wanted =
+                    normalize_header(self.headers.get(field).map_or(field, String::as_str));
+                headers
+                    .iter()
+                    .position(|header| *header == wanted)
+                    .ok_or_else(|| CliError::MissingColumn {
+                        path: path.to_path_buf(),
+                        field,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Parses a `FIELD=HEADER` column mapping given on the command line.
+pub fn parse_column_mapping(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(field, header)| (field.trim().to_string(), header.trim().to_string()))
+        .ok_or_else(|| format!("expected FIELD=HEADER, got `{value}`"))
+}
+
+fn normalize_header(header: &str) -> String {
+    header
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// One spreadsheet row, mapped onto a payload or rejected with the reasons why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocationRow {
+    /// Line of the row in the file, counting the header as line 1
+    pub line: u64,
+    /// The PVN cell as written, to identify the row in the report
+    pub property_verification_no: String,
+    pub result: Result<PropertyPayload, Vec<String>>,
+}
+
+/// Reads a CSV export with a header row. Rows with missing or mistyped cells are kept as
+/// rejections rather than failing the whole file.
+pub fn read_allocations(path: &Path, columns: &ColumnMap) -> Result<Vec<AllocationRow>, CliError> {
+    let csv_error = |source| CliError::Csv {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_path(path)
+        .map_err(csv_error)?;
+    let positions = columns.positions(path, reader.headers().map_err(csv_error)?)?;
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let cells: Vec<&str> = positions
+            .iter()
+            .map(|&position| record.get(position).unwrap_or_default())
+            .collect();
+        rows.push(AllocationRow {
+            line: record.position().map_or(0, |position| position.line()),
+            property_verification_no: cells[1].to_string(),
+            result: parse_cells(&cells),
+        });
+    }
+    Ok(rows)
+}
+
+/// Builds a payload from cells in `PAYLOAD_FIELDS` order.
+fn parse_cells(cells: &[&str]) -> Result<PropertyPayload, Vec<String>> {
+    let mut reasons = Vec::new();
+    for (field, cell) in PAYLOAD_FIELDS.iter().zip(cells) {
+        if cell.is_empty() {
+            reasons.push(format!("`{field}` is empty"));
+        }
+    }
+    let mut whole_number = |field: &str, cell: &str| match cell.parse::<u64>() {
+        Ok(value) => value,
+        Err(_) => {
+            if !cell.is_empty() {
+                reasons.push(format!("`{field}` `{cell}` is not a whole number"));
+            }
+            0
+        }
+    };
+    let price_of_plot = whole_number(PAYLOAD_FIELDS[0], cells[0]);
+    let property_verification_no = whole_number(PAYLOAD_FIELDS[1], cells[1]);
+    if !cells[2].is_empty() && ScAddress::from_str(cells[2]).is_err() {
+        reasons.push(format!(
+            "`buyer_wallet_id` `{}` is not a valid Stellar address",
+            cells[2]
+        ));
+    }
+    if !reasons.is_empty() {
+        return Err(reasons);
+    }
+
+    Ok(PropertyPayload {
+        price_of_plot,
+        property_verification_no,
+        buyer_wallet_id: Address(cells[2].to_string()),
+        plot_no: cells[3].to_string(),
+        size_of_plot: cells[4].to_string(),
+        coordinate_of_plot: cells[5].to_string(),
+        token_url: cells[6].to_string(),
+        estate_name: cells[7].to_string(),
+        plot_url: cells[8].to_string(),
+        date_of_allocation: cells[9].to_string(),
+        estate_company_name: cells[10].to_string(),
+        nft_address: cells[11].to_string(),
+    })
+}
+
+/// How much of a transaction one batch mint may use. The budget defaults are half the
+/// network's per-transaction limits, leaving room for the registry to grow between the
+/// snapshot and submission. Every plot writes up to seven ledger entries of its own, so
+/// a batch holds no more than two rows to stay inside the entries a transaction may write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchLimits {
+    pub max_rows: usize,
+    pub max_cpu_instructions: u64,
+    pub max_memory_bytes: u64,
+}
+
+impl Default for BatchLimits {
+    fn default() -> Self {
+        BatchLimits {
+            max_rows: 2,
+            max_cpu_instructions: 50_000_000,
+            max_memory_bytes: 20 * 1024 * 1024,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RejectedRow {
+    pub line: u64,
+    pub property_verification_no: String,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BatchReport {
+    /// Lines of the rows minted by the batch, in mint order
+    pub lines: Vec<u64>,
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+}
+
+/// Machine-readable outcome of an import, written next to the envelopes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportReport {
+    pub rows: usize,
+    pub accepted: usize,
+    pub batches: Vec<BatchReport>,
+    pub rejected: Vec<RejectedRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportPlan {
+    /// One `batch_mint_new_property_info` call per entry of `report.batches`
+    pub invocations: Vec<Invocation>,
+    pub report: ImportReport,
+}
+
+/// Checks the rows against each other and against the registry held by `simulator`, then
+/// packs the accepted ones into batch mints that stay within `limits`.
+///
+/// Every batch is simulated on top of the ones before it. A batch that is over the limits or
+/// rejected by the contract is halved until it fits; a single row that still fails is moved
+/// to the report. On return `simulator` holds the registry with every batch minted.
+pub fn plan_import(
+    rows: Vec<AllocationRow>,
+    simulator: &mut Simulator,
+    limits: BatchLimits,
+) -> Result<ImportPlan, CliError> {
+    let (minted_pvns, minted_nft_addresses) = registered_properties(simulator)?;
+    let total_rows = rows.len();
+    let mut rejected = Vec::new();
+    let mut accepted = Vec::new();
+    let mut pvn_lines = HashMap::new();
+    let mut nft_address_lines = HashMap::new();
+
+    for row in rows {
+        let payload = match row.result {
+            Ok(payload) => payload,
+            Err(reasons) => {
+                rejected.push(RejectedRow {
+                    line: row.line,
+                    property_verification_no: row.property_verification_no,
+                    reasons,
+                });
+                continue;
+            }
+        };
+
+        let pvn = payload.property_verification_no;
+        let mut reasons = Vec::new();
+        if minted_pvns.contains(&pvn) {
+            reasons.push(format!(
+                "property_verification_no {pvn} is already in the registry (AlreadyMinted)"
+            ));
+        } else if let Some(line) = pvn_lines.get(&pvn) {
+            reasons.push(format!(
+                "property_verification_no {pvn} duplicates line {line} (AlreadyMinted)"
+            ));
+        }
+        if minted_nft_addresses.contains(&payload.nft_address) {
+            reasons.push(format!(
+                "nft_address `{}` is already in the registry (NftAddressAlreadyInUse)",
+                payload.nft_address
+            ));
+        } else if let Some(line) = nft_address_lines.get(&payload.nft_address) {
+            reasons.push(format!(
+                "nft_address `{}` duplicates line {line} (NftAddressAlreadyInUse)",
+                payload.nft_address
+            ));
+        }
+
+        if reasons.is_empty() {
+            pvn_lines.insert(pvn, row.line);
+            nft_address_lines.insert(payload.nft_address.clone(), row.line);
+            accepted.push((row.line, payload));
+        } else {
+            rejected.push(RejectedRow {
+                line: row.line,
+                property_verification_no: row.property_verification_no,
+                reasons,
+            });
+        }
+    }
+
+    let mut invocations = Vec::new();
+    let mut batches = Vec::new();
+    let mut pending = &accepted[..];
+    // Largest batch not yet known to exceed the budget; the cost per row only grows as
+    // the registry fills up, so it never has to go back up.
+    let mut budget_rows = limits.max_rows.max(1);
+    let mut batch_rows = budget_rows;
+    while !pending.is_empty() {
+        let batch = &pending[..batch_rows.min(pending.len())];
+        let payloads: Vec<PropertyPayload> =
+            batch.iter().map(|(_, payload)| payload.clone()).collect();
+        let invocation = batch_mint_invocation(simulator.env(), &payloads)?;
+
+        let trial = simulator.fork();
+        let outcome = trial.invoke_unmetered(&invocation)?;
+        let within_limits = outcome.cpu_instructions <= limits.max_cpu_instructions
+            && outcome.memory_bytes <= limits.max_memory_bytes;
+        match outcome.result {
+            Ok(_) if within_limits => {
+                *simulator = trial;
+                batches.push(BatchReport {
+                    lines: batch.iter().map(|(line, _)| *line).collect(),
+                    cpu_instructions: outcome.cpu_instructions,
+                    memory_bytes: outcome.memory_bytes,
+                });
+                invocations.push(invocation);
+                pending = &pending[batch.len()..];
+                batch_rows = budget_rows;
+            }
+            Ok(_) if batch.len() > 1 => {
+                budget_rows = (batch.len() / 2).max(1);
+                batch_rows = budget_rows;
+            }
+            Err(_) if batch.len() > 1 => batch_rows = batch.len() / 2,
+            result => {
+                let (line, payload) = &batch[0];
+                let reason = match result {
+                    Ok(_) => format!(
+                        "minting it alone takes {} CPU instructions and {} bytes of memory, \
+                         over the batch limits",
+                        outcome.cpu_instructions, outcome.memory_bytes
+                    ),
+                    Err(err) => format!("rejected by the contract: {err}"),
+                };
+                rejected.push(RejectedRow {
+                    line: *line,
+                    property_verification_no: payload.property_verification_no.to_string(),
+                    reasons: vec![reason],
+                });
+                pending = &pending[1..];
+                batch_rows = budget_rows;
+            }
+        }
+    }
+
+    rejected.sort_by_key(|row| row.line);
+    Ok(ImportPlan {
+        invocations,
+        report: ImportReport {
+            rows: total_rows,
+            accepted: batches.iter().map(|batch| batch.lines.len()).sum(),
+            batches,
+            rejected,
+        },
+    })
+}
+
+/// PVNs and NFT addresses already minted in the simulated registry.
+fn registered_properties(
+    simulator: &Simulator,
+) -> Result<(HashSet<u64>, HashSet<String>), CliError> {
+    // Fails early, with a clearer error than every row being rejected, when the snapshot
+    // does not hold an initialized registry
+    query(simulator, "get_admin")?;
+
+    let function = "get_all_minted_property_details";
+    let properties = query(simulator, function)?;
+    let env = simulator.env();
+    let properties = Val::try_from_val(env, &properties)
+        .ok()
+        .and_then(|value| soroban_sdk::Vec::<PropertyInfo>::try_from_val(env, &value).ok())
+        .ok_or(CliError::Conversion(function))?;
+
+    Ok(properties
+        .iter()
+        .map(|property| {
+            (
+                property.property_verification_no,
+                property.nft_address.to_string(),
+            )
+        })
+        .unzip())
+}
+
+fn query(simulator: &Simulator, function: &'static str) -> Result<ScVal, CliError> {
+    let invocation = Invocation {
+        function,
+        args: Vec::new(),
+    };
+    simulator
+        .invoke_unmetered(&invocation)?
+        .result
+        .map_err(|err| CliError::Simulation {
+            function,
+            reason: err.to_string(),
+        })
+}
@@ -0,0 +1,40 @@
+//! Builds unsigned invocations of the `sytemap` registry contract without a network
+//! connection, and optionally runs them against a local `Env` first.
+//!
+//! Every contract entry point is a subcommand of [`Call`]. Its arguments are checked
+//! against the rules the contract enforces without ledger state, then encoded as
+//! `InvokeHostFunction` transaction envelopes:
+//!
+//! ```ignore
+//! let env = soroban_sdk::Env::default();
+//! let invocations = call.invocations(&env)?;
+//! let options = TxOptions::parse(contract_id, source, sequence, 100)?;
+//! for envelope in build_envelopes(&options, &invocations)? {
+//!     println!("{envelope}");
+//! }
+//! ```
+//!
+//! Spreadsheet exports of plot allocations go through [`read_allocations`] and
+//! [`plan_import`], which check them against a registry snapshot and pack them into
+//! budget-safe `batch_mint_new_property_info` calls.
+
+mod call;
+mod error;
+mod import;
+mod payload;
+mod simulate;
+mod tx;
+
+pub use call::{
+    batch_mint_invocation, Call, DocumentTypeArg, Invocation, RoleArg, ScheduleArgs, StatusArg,
+};
+pub use error::CliError;
+pub use import::{
+    parse_column_mapping, plan_import, read_allocations, AllocationRow, BatchLimits, BatchReport,
+    ColumnMap, ImportPlan, ImportReport, RejectedRow, PAYLOAD_FIELDS,
+};
+pub use payload::{parse_address, read_payloads, to_contract, validate_payloads, PropertyPayload};
+pub use simulate::{display_scval, SimulationError, SimulationOutcome, Simulator};
+pub use tx::{build_envelope, build_envelopes, TxOptions};
+
+mod test;
@@ -0,0 +1,235 @@
+use std::{fs, path::PathBuf, process::ExitCode, str::FromStr};
+
+use clap::{Parser, Subcommand};
+use soroban_sdk::Env;
+use stellar_xdr::curr::ScAddress;
+use sytemap_cli::{
+    build_envelopes, display_scval, parse_column_mapping, plan_import, read_allocations,
+    BatchLimits, Call, CliError, ColumnMap, Invocation, Simulator, TxOptions,
+};
+
+/// Build unsigned sytemap registry transactions offline, one base64 envelope per line.
+#[derive(Debug, Parser)]
+#[command(name = "sytemap-cli", version)]
+struct Args {
+    /// Registry contract id (`C...`)
+    #[arg(long, global = true)]
+    contract_id: Option<String>,
+
+    /// Account paying for and sequencing the transactions (`G...` or `M...`)
+    #[arg(long, global = true)]
+    source: Option<String>,
+
+    /// Sequence number of the first transaction, usually the account's current sequence + 1
+    #[arg(long, global = true)]
+    sequence: Option<i64>,
+
+    /// Inclusion fee in stroops, before the resource fee added by simulation on RPC
+    #[arg(long, global = true, default_value_t = 100)]
+    fee: u32,
+
+    /// Write the envelopes to this file instead of stdout. Debug builds echo contract logs
+    /// to stdout while simulating, so `import` output should go to a file there
+    #[arg(long, global = true)]
+    out: Option<PathBuf>,
+
+    /// Run the invocations against a local Env instead of building envelopes
+    #[arg(long, global = true)]
+    simulate: bool,
+
+    /// Ledger snapshot holding the registry to simulate or import against; an empty
+    /// ledger otherwise
+    #[arg(long, global = true)]
+    snapshot: Option<PathBuf>,
+
+    /// Save the ledger state after simulating or importing
+    #[arg(long, global = true)]
+    snapshot_out: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Turn a CSV export of estate plot allocations into batch mints, checked against the
+    /// registry in `--snapshot`
+    Import(ImportArgs),
+    #[command(flatten)]
+    Call(Call),
+}
+
+#[derive(Debug, clap::Args)]
+struct ImportArgs {
+    /// CSV file with a header row
+    csv: PathBuf,
+
+    /// Read a payload field from a differently titled column
+    #[arg(long = "column", value_name = "FIELD=HEADER", value_parser = parse_column_mapping)]
+    columns: Vec<(String, String)>,
+
+    /// Most rows minted by one transaction
+    #[arg(long, default_value_t = BatchLimits::default().max_rows)]
+    batch_size: usize,
+
+    /// CPU instructions one batch may use
+    #[arg(long, default_value_t = BatchLimits::default().max_cpu_instructions)]
+    max_cpu_instructions: u64,
+
+    /// Memory in bytes one batch may use
+    #[arg(long, default_value_t = BatchLimits::default().max_memory_bytes)]
+    max_memory_bytes: u64,
+
+    /// Where to write the JSON report of batches and rejected rows
+    #[arg(long, default_value = "import-report.json")]
+    report: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<ExitCode, CliError> {
+    let contract_id = args
+        .contract_id
+        .as_deref()
+        .ok_or(CliError::MissingOption("--contract-id"))?;
+
+    match &args.command {
+        Command::Import(import) => run_import(&args, contract_id, import),
+        Command::Call(call) => {
+            let invocations = call.invocations(&Env::default())?;
+            if args.simulate {
+                simulate(&args, contract_id, &invocations)
+            } else {
+                write_envelopes(&args, &tx_options(&args, contract_id)?, &invocations)?;
+                Ok(ExitCode::SUCCESS)
+            }
+        }
+    }
+}
+
+fn run_import(args: &Args, contract_id: &str, import: &ImportArgs) -> Result<ExitCode, CliError> {
+    let snapshot = args
+        .snapshot
+        .as_deref()
+        .ok_or(CliError::MissingOption("--snapshot"))?;
+    let mut columns = ColumnMap::default();
+    for (field, header) in &import.columns {
+        columns.map(field, header)?;
+    }
+    let limits = BatchLimits {
+        max_rows: import.batch_size,
+        max_cpu_instructions: import.max_cpu_instructions,
+        max_memory_bytes: import.max_memory_bytes,
+    };
+    let options = if args.simulate {
+        None
+    } else {
+        Some(tx_options(args, contract_id)?)
+    };
+
+    let rows = read_allocations(&import.csv, &columns)?;
+    let mut simulator = Simulator::new(&parse_contract_id(contract_id)?, Some(snapshot))?;
+    let plan = plan_import(rows, &mut simulator, limits)?;
+
+    let report = serde_json::to_string_pretty(&plan.report).map_err(|source| CliError::Json {
+        path: import.report.clone(),
+        source,
+    })?;
+    fs::write(&import.report, report + "\n").map_err(|source| CliError::Io {
+        path: import.report.clone(),
+        source,
+    })?;
+    if let Some(path) = &args.snapshot_out {
+        simulator.write_snapshot(path)?;
+    }
+    if let Some(options) = &options {
+        write_envelopes(args, options, &plan.invocations)?;
+    }
+
+    eprintln!(
+        "{} rows: {} accepted in {} batches, {} rejected (see {})",
+        plan.report.rows,
+        plan.report.accepted,
+        plan.report.batches.len(),
+        plan.report.rejected.len(),
+        import.report.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+fn simulate(
+    args: &Args,
+    contract_id: &str,
+    invocations: &[Invocation],
+) -> Result<ExitCode, CliError> {
+    let simulator = Simulator::new(&parse_contract_id(contract_id)?, args.snapshot.as_deref())?;
+    let mut failed = false;
+    for invocation in invocations {
+        let outcome = simulator.invoke(invocation)?;
+        match &outcome.result {
+            Ok(value) => println!("{}: ok {}", outcome.function, display_scval(value)),
+            Err(err) => {
+                failed = true;
+                println!("{}: failed {err}", outcome.function);
+            }
+        }
+        println!(
+            "  cpu instructions: {}, memory bytes: {}",
+            outcome.cpu_instructions, outcome.memory_bytes
+        );
+    }
+    if let Some(path) = &args.snapshot_out {
+        simulator.write_snapshot(path)?;
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn tx_options(args: &Args, contract_id: &str) -> Result<TxOptions, CliError> {
+    let source = args
+        .source
+        .as_deref()
+        .ok_or(CliError::MissingOption("--source"))?;
+    let sequence = args.sequence.ok_or(CliError::MissingOption("--sequence"))?;
+    TxOptions::parse(contract_id, source, sequence, args.fee)
+}
+
+fn write_envelopes(
+    args: &Args,
+    options: &TxOptions,
+    invocations: &[Invocation],
+) -> Result<(), CliError> {
+    let mut output = String::new();
+    for envelope in build_envelopes(options, invocations)? {
+        output.push_str(&envelope);
+        output.push('\n');
+    }
+    match &args.out {
+        Some(path) => fs::write(path, output).map_err(|source| CliError::Io {
+            path: path.clone(),
+            source,
+        }),
+        None => {
+            print!("{output}");
+            Ok(())
+        }
+    }
+}
+
+fn parse_contract_id(contract_id: &str) -> Result<ScAddress, CliError> {
+    ScAddress::from_str(contract_id).map_err(|_| CliError::InvalidAddress {
+        field: "contract_id",
+        value: contract_id.to_string(),
+    })
+}
@@ -0,0 +1,136 @@
+use std::{collections::HashSet, fs, path::Path, str::FromStr};
+
+use serde::Deserialize;
+use soroban_sdk::{Address, Env, String};
+use stellar_xdr::curr::ScAddress;
+use sytemap::types::PropertyInfoPayload;
+
+use crate::error::CliError;
+
+/// `PropertyInfoPayload` as written in JSON or CSV files, one property per object or row.
+pub use sytemap_types::mirror::PropertyInfoPayload as PropertyPayload;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonPayloads {
+    Many(Vec<PropertyPayload>),
+    One(Box<PropertyPayload>),
+}
+
+/// The contract value of a payload, with addresses checked first since the SDK panics on
+/// bad strkeys.
+pub fn to_contract(env: &Env, payload: &PropertyPayload) -> Result<PropertyInfoPayload, CliError> {
+    Ok(PropertyInfoPayload {
+        price_of_plot: payload.price_of_plot,
+        property_verification_no: payload.property_verification_no,
+        buyer_wallet_id: parse_address(env, "buyer_wallet_id", &payload.buyer_wallet_id.0)?,
+        plot_no: String::from_str(env, &payload.plot_no),
+        size_of_plot: String::from_str(env, &payload.size_of_plot),
+        coordinate_of_plot: String::from_str(env, &payload.coordinate_of_plot),
+        token_url: String::from_str(env, &payload.token_url),
+        estate_name: String::from_str(env, &payload.estate_name),
+        plot_url: String::from_str(env, &payload.plot_url),
+        date_of_allocation: String::from_str(env, &payload.date_of_allocation),
+        estate_company_name: String::from_str(env, &payload.estate_company_name),
+        nft_address: String::from_str(env, &payload.nft_address),
+    })
+}
+
+/// Reads payloads from a `.json` file holding one object or an array, or from a `.csv` file
+/// whose header row names the payload fields.
+pub fn read_payloads(path: &Path) -> Result<Vec<PropertyPayload>, CliError> {
+    let io_error = |source| CliError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => {
+            let json = fs::read_to_string(path).map_err(io_error)?;
+            let payloads = serde_json::from_str(&json).map_err(|source| CliError::Json {
+                path: path.to_path_buf(),
+                source,
+            })?;
+            Ok(match payloads {
+                JsonPayloads::Many(payloads) => payloads,
+                JsonPayloads::One(payload) => vec![*payload],
+            })
+        }
+        Some("csv") => {
+            let csv_error = |source| CliError::Csv {
+                path: path.to_path_buf(),
+                source,
+            };
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_path(path)
+                .map_err(csv_error)?;
+            reader
+                .deserialize()
+                .collect::<Result<Vec<PropertyPayload>, _>>()
+                .map_err(csv_error)
+        }
+        _ => Err(CliError::UnsupportedFormat {
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+/// Applies the checks `safe_mint_new_property_info` makes that do not need ledger state:
+/// addresses must parse, and neither a PVN nor an NFT address may be minted twice.
+/// Rows are numbered from 1.
+pub fn validate_payloads(payloads: &[PropertyPayload]) -> Result<(), CliError> {
+    let mut pvns = HashSet::new();
+    let mut nft_addresses = HashSet::new();
+    let mut plots = HashSet::new();
+    for (index, payload) in payloads.iter().enumerate() {
+        let row = index + 1;
+        if ScAddress::from_str(&payload.buyer_wallet_id.0).is_err() {
+            return Err(CliError::InvalidPayload {
+                row,
+                reason: format!(
+                    "buyer_wallet_id `{}` is not a valid Stellar address",
+                    payload.buyer_wallet_id
+                ),
+            });
+        }
+        if !pvns.insert(payload.property_verification_no) {
+            return Err(CliError::InvalidPayload {
+                row,
+                reason: format!(
+                    "property_verification_no {} appears more than once (AlreadyMinted)",
+                    payload.property_verification_no
+                ),
+            });
+        }
+        if !nft_addresses.insert(payload.nft_address.as_str()) {
+            return Err(CliError::InvalidPayload {
+                row,
+                reason: format!(
+                    "nft_address `{}` appears more than once (NftAddressAlreadyInUse)",
+                    payload.nft_address
+                ),
+            });
+        }
+        if !plots.insert((payload.estate_name.as_str(), payload.plot_no.as_str())) {
+            return Err(CliError::InvalidPayload {
+                row,
+                reason: format!(
+                    "plot_no `{}` appears more than once in {} (PlotNoAlreadyInUse)",
+                    payload.plot_no, payload.estate_name
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Parses a `G...` or `C...` strkey into a contract `Address`.
+pub fn parse_address(env: &Env, field: &'static str, value: &str) -> Result<Address, CliError> {
+    if ScAddress::from_str(value).is_err() {
+        return Err(CliError::InvalidAddress {
+            field,
+            value: value.to_string(),
+        });
+    }
+    Ok(Address::from_string(&String::from_str(env, value)))
+}
@@ -0,0 +1,215 @@
+use std::{fmt, path::Path};
+
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::{Address, Env, String, Symbol, TryFromVal, Val, Vec};
+use stellar_xdr::curr::{LedgerKey, ScAddress, ScErrorType, ScVal};
+use sytemap::{contract::SytemapRegistry, error::SytemapRegistryError};
+
+use crate::{call::Invocation, error::CliError};
+
+/// What a simulated invocation returned and what it cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationOutcome {
+    pub function: &'static str,
+    /// The decoded return value, or the error the call failed with
+    pub result: Result<ScVal, SimulationError>,
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+}
+
+/// Why a simulated invocation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationError {
+    /// The registry rejected the call
+    Contract(SytemapRegistryError),
+    /// The host failed the call, e.g. on a missing entry or a contract panic
+    Host(std::string::String),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::Contract(err) => {
+                write!(f, "{err:?} (contract error #{})", *err as u32)
+            }
+            SimulationError::Host(err) => f.write_str(err),
+        }
+    }
+}
+
+/// Runs invocations against the registry inside a local `Env`.
+///
+/// The registry code is this build of the contract, registered at `contract_id` over
+/// whatever ledger state the snapshot holds. Every `require_auth` is mocked, so the
+/// simulation checks contract rules, not signatures.
+pub struct Simulator {
+    env: Env,
+    contract_address: ScAddress,
+    contract_id: Address,
+}
+
+impl Simulator {
+    /// Starts from an empty ledger, or from a snapshot written by `--snapshot-out` or a test.
+    pub fn new(contract_id: &ScAddress, snapshot: Option<&Path>) -> Result<Self, CliError> {
+        let snapshot = snapshot
+            .map(|path| {
+                LedgerSnapshot::read_file(path).map_err(|err| CliError::Snapshot {
+                    path: path.to_path_buf(),
+                    reason: err.to_string(),
+                })
+            })
+            .transpose()?;
+        Ok(Self::with_ledger(contract_id, snapshot))
+    }
+
+    fn with_ledger(contract_address: &ScAddress, snapshot: Option<LedgerSnapshot>) -> Self {
+        let env = match snapshot {
+            Some(mut snapshot) => {
+                // Mocked auth hands out the same nonces in every new Env, so the ones left
+                // by earlier simulations would collide with the next signature
+                snapshot
+                    .ledger_entries
+                    .retain(|(key, _)| match key.as_ref() {
+                        LedgerKey::ContractData(data) => {
+                            !matches!(data.key, ScVal::LedgerKeyNonce(_))
+                        }
+                        _ => true,
+                    });
+                Env::from_ledger_snapshot(snapshot)
+            }
+            None => Env::default(),
+        };
+        env.mock_all_auths_allowing_non_root_auth();
+
+        let contract_id =
+            Address::from_string(&String::from_str(&env, &contract_address.to_string()));
+        env.register_contract(Some(&contract_id), SytemapRegistry);
+        Simulator {
+            env,
+            contract_address: contract_address.clone(),
+            contract_id,
+        }
+    }
+
+    /// A copy of the current ledger state whose invocations leave this one untouched.
+    pub fn fork(&self) -> Self {
+        Self::with_ledger(&self.contract_address, Some(self.env.to_ledger_snapshot()))
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Invokes the contract within the default network budget, keeping any state changes
+    /// for the following invocations.
+    pub fn invoke(&self, invocation: &Invocation) -> Result<SimulationOutcome, CliError> {
+        self.invoke_with_budget(invocation, false)
+    }
+
+    /// Invokes the contract without budget limits, to measure calls that may not fit in a
+    /// transaction. The host aborts rather than failing the call when a budget runs out, so
+    /// the cost has to be compared against the limits afterwards.
+    pub fn invoke_unmetered(&self, invocation: &Invocation) -> Result<SimulationOutcome, CliError> {
+        self.invoke_with_budget(invocation, true)
+    }
+
+    fn invoke_with_budget(
+        &self,
+        invocation: &Invocation,
+        unmetered: bool,
+    ) -> Result<SimulationOutcome, CliError> {
+        let env = &self.env;
+        let mut args = Vec::<Val>::new(env);
+        for arg in &invocation.args {
+            args.push_back(
+                Val::try_from_val(env, arg)
+                    .map_err(|_| CliError::Conversion(invocation.function))?,
+            );
+        }
+
+        let mut budget = env.budget();
+        if unmetered {
+            budget.reset_unlimited();
+        } else {
+            budget.reset_default();
+        }
+        let result = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &self.contract_id,
+            &Symbol::new(env, invocation.function),
+            args,
+        );
+        let cpu_instructions = budget.cpu_instruction_cost();
+        let memory_bytes = budget.memory_bytes_cost();
+
+        let result = match result {
+            Ok(Ok(value)) => Ok(ScVal::try_from_val(env, &value)
+                .map_err(|_| CliError::Conversion(invocation.function))?),
+            Ok(Err(_)) => Err(SimulationError::Host(
+                "returned a value that could not be decoded".to_string(),
+            )),
+            Err(Ok(err)) if err.is_type(ScErrorType::Contract) => {
+                Err(match SytemapRegistryError::try_from(err) {
+                    Ok(err) => SimulationError::Contract(err),
+                    Err(_) => SimulationError::Host(format!("{err:?}")),
+                })
+            }
+            Err(Ok(err)) => Err(SimulationError::Host(format!("{err:?}"))),
+            Err(Err(err)) => Err(SimulationError::Host(format!("{err:?}"))),
+        };
+        Ok(SimulationOutcome {
+            function: invocation.function,
+            result,
+            cpu_instructions,
+            memory_bytes,
+        })
+    }
+
+    /// Writes the ledger state after the simulated invocations, to seed a later run.
+    pub fn write_snapshot(&self, path: &Path) -> Result<(), CliError> {
+        self.env
+            .to_ledger_snapshot()
+            .write_file(path)
+            .map_err(|err| CliError::Snapshot {
+                path: path.to_path_buf(),
+                reason: err.to_string(),
+            })
+    }
+}
+
+/// Renders a contract value compactly: addresses as strkeys, structs as `{field: value}`.
+pub fn display_scval(value: &ScVal) -> std::string::String {
+    match value {
+        ScVal::Bool(value) => value.to_string(),
+        ScVal::Void => "()".to_string(),
+        ScVal::U32(value) => value.to_string(),
+        ScVal::I32(value) => value.to_string(),
+        ScVal::U64(value) => value.to_string(),
+        ScVal::I64(value) => value.to_string(),
+        ScVal::Timepoint(value) => value.0.to_string(),
+        ScVal::Duration(value) => value.0.to_string(),
+        ScVal::U128(parts) => ((u128::from(parts.hi) << 64) | u128::from(parts.lo)).to_string(),
+        ScVal::I128(parts) => ((i128::from(parts.hi) << 64) | i128::from(parts.lo)).to_string(),
+        ScVal::Bytes(bytes) => hex::encode(bytes.as_slice()),
+        ScVal::String(value) => format!("{:?}", value.0.to_utf8_string_lossy()),
+        ScVal::Symbol(value) => value.0.to_utf8_string_lossy(),
+        ScVal::Address(address) => address.to_string(),
+        ScVal::Vec(Some(items)) => {
+            let items: std::vec::Vec<_> = items.iter().map(display_scval).collect();
+            format!("[{}]", items.join(", "))
+        }
+        ScVal::Map(Some(entries)) => {
+            let entries: std::vec::Vec<_> = entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{}: {}",
+                        display_scval(&entry.key),
+                        display_scval(&entry.val)
+                    )
+                })
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+        other => format!("{other:?}"),
+    }
+}
@@ -0,0 +1,553 @@
+#![cfg(test)]
+
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use soroban_sdk::Env;
+use stellar_xdr::curr::{HostFunction, Limits, OperationBody, ReadXdr, ScVal, TransactionEnvelope};
+use sytemap::error::SytemapRegistryError;
+use sytemap_types::mirror::Address;
+
+use crate::*;
+
+const CONTRACT_ID: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM";
+const SOURCE: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+const ADMIN: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAATYON";
+const OWNER_A: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4";
+const PAUSER: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4";
+
+#[derive(Parser)]
+struct TestCli {
+    #[command(subcommand)]
+    call: Call,
+}
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(name)
+}
+
+fn parse_call(args: &[&str]) -> Call {
+    TestCli::try_parse_from(std::iter::once("sytemap-cli").chain(args.iter().copied()))
+        .unwrap()
+        .call
+}
+
+fn options() -> TxOptions {
+    TxOptions::parse(CONTRACT_ID, SOURCE, 41, 100).unwrap()
+}
+
+fn run(simulator: &Simulator, args: &[&str]) {
+    for invocation in parse_call(args).invocations(&Env::default()).unwrap() {
+        assert_eq!(
+            simulator.invoke(&invocation).unwrap().result,
+            Ok(ScVal::Void)
+        );
+    }
+}
+
+/// An initialized registry holding the two properties of `payloads.json`.
+fn registry() -> Simulator {
+    let simulator = Simulator::new(&options().contract_id, None).unwrap();
+    run(
+        &simulator,
+        &[
+            "initialize",
+            "--admin",
+            ADMIN,
+            "--sytemap-name",
+            "Sytemap",
+            "--sytemap-symbol",
+            "SYT",
+            "--metadata-uri",
+            "https://sytemap.example/",
+        ],
+    );
+    let payload = fixture("payloads.json");
+    run(
+        &simulator,
+        &[
+            "safe-mint-new-property-info",
+            "--payload",
+            payload.to_str().unwrap(),
+        ],
+    );
+    simulator
+}
+
+fn allocations() -> Vec<AllocationRow> {
+    let mut columns = ColumnMap::default();
+    columns.map("property_verification_no", "PVN").unwrap();
+    read_allocations(&fixture("allocations.csv"), &columns).unwrap()
+}
+
+fn minted_count(simulator: &Simulator) -> ScVal {
+    let invocations = parse_call(&["get-number-of-property-minted"])
+        .invocations(&Env::default())
+        .unwrap();
+    simulator.invoke(&invocations[0]).unwrap().result.unwrap()
+}
+
+#[test]
+fn test_json_and_csv_payloads_match() {
+    let json = read_payloads(&fixture("payloads.json")).unwrap();
+    let csv = read_payloads(&fixture("payloads.csv")).unwrap();
+
+    assert_eq!(json.len(), 2);
+    assert_eq!(json, csv);
+    assert_eq!(json[0].property_verification_no, 12345);
+    assert_eq!(csv[1].coordinate_of_plot, "6.5301N,3.3810E");
+    validate_payloads(&json).unwrap();
+}
+
+#[test]
+fn test_duplicate_payloads_are_rejected() {
+    let mut payloads = read_payloads(&fixture("payloads.json")).unwrap();
+
+    let mut same_pvn = payloads[0].clone();
+    same_pvn.nft_address = "nft-other".to_string();
+    payloads.push(same_pvn);
+    let err = validate_payloads(&payloads).unwrap_err();
+    assert!(matches!(err, CliError::InvalidPayload { row: 3, .. }));
+    assert!(err.to_string().contains("AlreadyMinted"));
+
+    payloads[2].property_verification_no = 99999;
+    payloads[2].nft_address = payloads[1].nft_address.clone();
+    let err = validate_payloads(&payloads).unwrap_err();
+    assert!(err.to_string().contains("NftAddressAlreadyInUse"));
+
+    payloads[2].nft_address = "nft-99999".to_string();
+    payloads[2].buyer_wallet_id = Address("not-an-address".to_string());
+    let err = validate_payloads(&payloads).unwrap_err();
+    assert!(matches!(err, CliError::InvalidPayload { row: 3, .. }));
+
+    payloads[2].buyer_wallet_id = payloads[0].buyer_wallet_id.clone();
+    let err = validate_payloads(&payloads).unwrap_err();
+    assert!(err.to_string().contains("PlotNoAlreadyInUse"));
+
+    payloads[2].plot_no = "A-13".to_string();
+    validate_payloads(&payloads).unwrap();
+}
+
+#[test]
+fn test_stateless_contract_rules_are_checked() {
+    let env = Env::default();
+
+    let reprice = parse_call(&[
+        "change-property-price-by-owner",
+        "--pvn",
+        "1",
+        "--new-price",
+        "0",
+    ]);
+    assert!(matches!(
+        reprice.invocations(&env),
+        Err(CliError::InvalidArgument {
+            field: "new_price",
+            ..
+        })
+    ));
+
+    let plan = parse_call(&[
+        "create-payment-plan",
+        "--pvn",
+        "1",
+        "--buyer",
+        OWNER_A,
+        "--total",
+        "3",
+        "--token",
+        CONTRACT_ID,
+        "--installment-count",
+        "4",
+        "--interval",
+        "60",
+        "--first-due-date",
+        "100",
+        "--grace-period-days",
+        "7",
+        "--notice-period-days",
+        "30",
+        "--refund-percentage",
+        "50",
+    ]);
+    assert!(matches!(
+        plan.invocations(&env),
+        Err(CliError::InvalidArgument { field: "total", .. })
+    ));
+
+    let transfer = parse_call(&[
+        "transfer-property-by-owner",
+        "--pvn",
+        "1",
+        "--new-owner",
+        "G123",
+    ]);
+    assert!(matches!(
+        transfer.invocations(&env),
+        Err(CliError::InvalidAddress {
+            field: "new_owner",
+            ..
+        })
+    ));
+
+    let document = parse_call(&[
+        "attach-document",
+        "--pvn",
+        "1",
+        "--attester",
+        OWNER_A,
+        "--doc-type",
+        "survey-plan",
+        "--sha256-hash",
+        "abcd",
+        "--uri",
+        "ipfs://survey",
+    ]);
+    assert!(matches!(
+        document.invocations(&env),
+        Err(CliError::InvalidArgument {
+            field: "sha256_hash",
+            ..
+        })
+    ));
+
+    let payload = fixture("payloads.json");
+    let subdivide = parse_call(&[
+        "subdivide",
+        "--pvn",
+        "1",
+        "--verifier",
+        OWNER_A,
+        "--payload",
+        payload.to_str().unwrap(),
+    ]);
+    let invocations = subdivide.invocations(&env).unwrap();
+    assert_eq!(invocations.len(), 1);
+    assert_eq!(invocations[0].function, "subdivide");
+    assert!(matches!(&invocations[0].args[2], ScVal::Vec(Some(children)) if children.len() == 2));
+
+    // Merging takes distinct plots and a single new plot
+    let merge = parse_call(&[
+        "merge-plots",
+        "--pvn",
+        "1",
+        "--pvn",
+        "1",
+        "--verifier",
+        OWNER_A,
+        "--payload",
+        payload.to_str().unwrap(),
+    ]);
+    assert!(matches!(
+        merge.invocations(&env),
+        Err(CliError::InvalidArgument { field: "pvns", .. })
+    ));
+    let merge = parse_call(&[
+        "merge-plots",
+        "--pvn",
+        "1",
+        "--pvn",
+        "2",
+        "--verifier",
+        OWNER_A,
+        "--payload",
+        payload.to_str().unwrap(),
+    ]);
+    assert!(matches!(
+        merge.invocations(&env),
+        Err(CliError::InvalidArgument {
+            field: "payload",
+            ..
+        })
+    ));
+    let lease = parse_call(&[
+        "create-lease",
+        "--pvn",
+        "1",
+        "--tenant",
+        OWNER_A,
+        "--start",
+        "200",
+        "--end",
+        "100",
+        "--rent",
+        "10",
+        "--token",
+        CONTRACT_ID,
+    ]);
+    assert!(matches!(
+        lease.invocations(&env),
+        Err(CliError::InvalidArgument { field: "end", .. })
+    ));
+}
+
+#[test]
+fn test_envelopes_decode_to_the_invocation() {
+    let env = Env::default();
+    let payload = fixture("payloads.json");
+    let mint = parse_call(&[
+        "safe-mint-new-property-info",
+        "--payload",
+        payload.to_str().unwrap(),
+    ]);
+    let invocations = mint.invocations(&env).unwrap();
+    assert_eq!(invocations.len(), 2);
+
+    let envelopes = build_envelopes(&options(), &invocations).unwrap();
+    assert_eq!(envelopes.len(), 2);
+
+    for (sequence, (envelope, invocation)) in (41..).zip(envelopes.iter().zip(&invocations)) {
+        let TransactionEnvelope::Tx(envelope) =
+            TransactionEnvelope::from_xdr_base64(envelope, Limits::none()).unwrap()
+        else {
+            panic!("expected a v1 envelope");
+        };
+        assert!(envelope.signatures.is_empty());
+        assert_eq!(envelope.tx.seq_num.0, sequence);
+        assert_eq!(envelope.tx.fee, 100);
+        assert_eq!(envelope.tx.source_account.to_string(), SOURCE);
+
+        let OperationBody::InvokeHostFunction(op) = &envelope.tx.operations[0].body else {
+            panic!("expected an InvokeHostFunction operation");
+        };
+        let HostFunction::InvokeContract(args) = &op.host_function else {
+            panic!("expected a contract invocation");
+        };
+        assert_eq!(args.contract_address.to_string(), CONTRACT_ID);
+        assert_eq!(
+            args.function_name.0.to_utf8_string_lossy(),
+            "safe_mint_new_property_info"
+        );
+        assert_eq!(args.args.to_vec(), invocation.args);
+    }
+
+    let ScVal::Map(Some(fields)) = &invocations[1].args[0] else {
+        panic!("expected the payload struct");
+    };
+    assert!(fields.iter().any(
+        |entry| display_scval(&entry.key) == "property_verification_no"
+            && entry.val == ScVal::U64(67890)
+    ));
+}
+
+#[test]
+fn test_contract_id_must_be_a_contract() {
+    assert!(matches!(
+        TxOptions::parse(SOURCE, SOURCE, 1, 100),
+        Err(CliError::InvalidAddress {
+            field: "contract_id",
+            ..
+        })
+    ));
+    assert!(matches!(
+        TxOptions::parse(CONTRACT_ID, "nope", 1, 100),
+        Err(CliError::InvalidAddress {
+            field: "source",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_simulation_runs_against_snapshot() {
+    let env = Env::default();
+    let contract_id = options().contract_id;
+    let payload = fixture("payloads.json");
+    let invocations = |args: &[&str]| parse_call(args).invocations(&env).unwrap();
+
+    let simulator = Simulator::new(&contract_id, None).unwrap();
+    let initialize = invocations(&[
+        "initialize",
+        "--admin",
+        ADMIN,
+        "--sytemap-name",
+        "Sytemap",
+        "--sytemap-symbol",
+        "SYT",
+        "--metadata-uri",
+        "https://sytemap.example/",
+    ]);
+    let mint = invocations(&[
+        "safe-mint-new-property-info",
+        "--payload",
+        payload.to_str().unwrap(),
+    ]);
+    for invocation in initialize.iter().chain(&mint) {
+        let outcome = simulator.invoke(invocation).unwrap();
+        assert_eq!(outcome.result, Ok(ScVal::Void));
+        assert!(outcome.cpu_instructions > 0);
+    }
+
+    let duplicate = simulator.invoke(&mint[0]).unwrap();
+    let err = duplicate.result.unwrap_err();
+    assert_eq!(
+        err,
+        SimulationError::Contract(SytemapRegistryError::AlreadyMinted)
+    );
+    assert_eq!(err.to_string(), "AlreadyMinted (contract error #1)");
+
+    let snapshot = std::env::temp_dir().join(format!("sytemap-cli-{}.json", std::process::id()));
+    simulator.write_snapshot(&snapshot).unwrap();
+
+    let resumed = Simulator::new(&contract_id, Some(&snapshot)).unwrap();
+    std::fs::remove_file(&snapshot).unwrap();
+    let owner = resumed
+        .invoke(&invocations(&["get-owner-of-pvn", "--pvn", "12345"])[0])
+        .unwrap();
+    assert_eq!(display_scval(&owner.result.unwrap()), OWNER_A);
+    let minted = resumed
+        .invoke(&invocations(&["get-number-of-property-minted"])[0])
+        .unwrap();
+    assert_eq!(minted.result, Ok(ScVal::U64(2)));
+
+    // The admin signs again after resuming
+    let update = resumed
+        .invoke(&invocations(&["update-metadata-uri", "--metadata-uri", "ipfs://sytemap"])[0])
+        .unwrap();
+    assert_eq!(update.result, Ok(ScVal::Void));
+}
+
+#[test]
+fn test_import_columns_are_matched_by_header() {
+    let err = read_allocations(&fixture("allocations.csv"), &ColumnMap::default()).unwrap_err();
+    assert!(matches!(
+        err,
+        CliError::MissingColumn {
+            field: "property_verification_no",
+            ..
+        }
+    ));
+    assert!(ColumnMap::default().map("plot_number", "Plot No.").is_err());
+
+    let rows = allocations();
+    assert_eq!(rows.len(), 12);
+    assert_eq!(rows[0].line, 2);
+    let payload = rows[0].result.as_ref().unwrap();
+    assert_eq!(payload.property_verification_no, 12345);
+    assert_eq!(payload.plot_no, "A-12");
+    assert_eq!(payload.coordinate_of_plot, "6.5244N,3.3792E");
+
+    assert_eq!(
+        rows[2].result,
+        Err(vec![
+            "`price_of_plot` `1,200,000` is not a whole number".to_string()
+        ])
+    );
+    assert_eq!(rows[6].line, 8);
+    assert_eq!(rows[6].result.as_ref().unwrap_err().len(), 7);
+}
+
+#[test]
+fn test_import_rejects_rows_and_batches_the_rest() {
+    let mut simulator = registry();
+    run(
+        &simulator,
+        &["grant-role", "--role", "pauser", "--account", PAUSER],
+    );
+    run(
+        &simulator,
+        &[
+            "pause-estate",
+            "--pauser",
+            PAUSER,
+            "--estate-name",
+            "Lekki Gardens",
+        ],
+    );
+
+    let limits = BatchLimits {
+        max_rows: 4,
+        ..BatchLimits::default()
+    };
+    let plan = plan_import(allocations(), &mut simulator, limits).unwrap();
+    let report = &plan.report;
+
+    assert_eq!(report.rows, 12);
+    assert_eq!(report.accepted, 4);
+    let batches: Vec<_> = report
+        .batches
+        .iter()
+        .map(|batch| batch.lines.clone())
+        .collect();
+    assert_eq!(batches, vec![vec![3], vec![10, 11], vec![13]]);
+    assert_eq!(plan.invocations.len(), 3);
+    assert!(plan
+        .invocations
+        .iter()
+        .all(|invocation| invocation.function == "batch_mint_new_property_info"));
+
+    let rejected: Vec<_> = report.rejected.iter().map(|row| row.line).collect();
+    assert_eq!(rejected, vec![2, 4, 5, 6, 7, 8, 9, 12]);
+    let reasons = |line: u64| {
+        report
+            .rejected
+            .iter()
+            .find(|row| row.line == line)
+            .unwrap()
+            .reasons
+            .join("; ")
+    };
+    assert!(reasons(2).contains("already in the registry (AlreadyMinted)"));
+    assert!(reasons(5).contains("`not-a-wallet` is not a valid Stellar address"));
+    assert!(reasons(6).contains("duplicates line 3 (AlreadyMinted)"));
+    assert!(reasons(7).contains("duplicates line 3 (NftAddressAlreadyInUse)"));
+    assert!(reasons(9).contains("ContractPaused"));
+
+    // The simulator keeps the minted batches
+    assert_eq!(minted_count(&simulator), ScVal::U64(6));
+
+    let json = serde_json::to_value(report).unwrap();
+    assert_eq!(json["rejected"][0]["line"], 2);
+    assert_eq!(json["rejected"][0]["property_verification_no"], "12345");
+    assert_eq!(json["batches"][1]["lines"], serde_json::json!([10, 11]));
+}
+
+#[test]
+fn test_import_batches_stay_within_budget() {
+    let limits = BatchLimits {
+        max_rows: 4,
+        max_cpu_instructions: u64::MAX,
+        max_memory_bytes: u64::MAX,
+    };
+    let plan = plan_import(allocations(), &mut registry(), limits).unwrap();
+    assert_eq!(plan.report.accepted, 6);
+    assert_eq!(plan.report.batches.len(), 2);
+
+    // A mint only touches the entries of its own plot and the indices, so the bigger
+    // batch costs the most
+    let most_expensive = plan
+        .report
+        .batches
+        .iter()
+        .map(|batch| batch.cpu_instructions)
+        .max()
+        .unwrap();
+    assert_eq!(most_expensive, plan.report.batches[0].cpu_instructions);
+    let limits = BatchLimits {
+        max_cpu_instructions: most_expensive - 1,
+        ..limits
+    };
+    let mut simulator = registry();
+    let plan = plan_import(allocations(), &mut simulator, limits).unwrap();
+    assert_eq!(plan.report.accepted, 6);
+    assert!(plan.report.batches.len() > 2);
+    assert!(plan
+        .report
+        .batches
+        .iter()
+        .all(|batch| batch.cpu_instructions <= limits.max_cpu_instructions));
+    assert_eq!(minted_count(&simulator), ScVal::U64(8));
+
+    let limits = BatchLimits {
+        max_cpu_instructions: 1_000,
+        ..limits
+    };
+    let plan = plan_import(allocations(), &mut registry(), limits).unwrap();
+    assert_eq!(plan.report.accepted, 0);
+    assert!(plan.report.rejected[1]
+        .reasons
+        .join("")
+        .contains("over the batch limits"));
+}
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use stellar_xdr::curr::{
+    HostFunction, InvokeContractArgs, InvokeHostFunctionOp, Limits, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, ScAddress, ScSymbol, SequenceNumber, Transaction,
+    TransactionEnvelope, TransactionExt, TransactionV1Envelope, WriteXdr,
+};
+
+use crate::{call::Invocation, error::CliError};
+
+/// Who pays for and sequences the transactions, and which registry they call.
+#[derive(Debug, Clone)]
+pub struct TxOptions {
+    pub contract_id: ScAddress,
+    pub source: MuxedAccount,
+    /// Sequence number of the first transaction; later ones follow on consecutively
+    pub sequence: i64,
+    pub fee: u32,
+}
+
+impl TxOptions {
+    pub fn parse(
+        contract_id: &str,
+        source: &str,
+        sequence: i64,
+        fee: u32,
+    ) -> Result<Self, CliError> {
+        let contract_id = match ScAddress::from_str(contract_id) {
+            Ok(address @ ScAddress::Contract(_)) => address,
+            _ => {
+                return Err(CliError::InvalidAddress {
+                    field: "contract_id",
+                    value: contract_id.to_string(),
+                })
+            }
+        };
+        let source = MuxedAccount::from_str(source).map_err(|_| CliError::InvalidAddress {
+            field: "source",
+            value: source.to_string(),
+        })?;
+        Ok(TxOptions {
+            contract_id,
+            source,
+            sequence,
+            fee,
+        })
+    }
+}
+
+/// Wraps a single invocation in an unsigned transaction envelope.
+///
+/// The envelope carries no Soroban footprint or resource fee, so it has to go through
+/// `simulateTransaction` (or `stellar tx simulate`) before it is signed and submitted.
+pub fn build_envelope(
+    options: &TxOptions,
+    invocation: &Invocation,
+    sequence: i64,
+) -> Result<TransactionEnvelope, CliError> {
+    let host_function = HostFunction::InvokeContract(InvokeContractArgs {
+        contract_address: options.contract_id.clone(),
+        function_name: ScSymbol(invocation.function.try_into()?),
+        args: invocation.args.clone().try_into()?,
+    });
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+            host_function,
+            auth: Default::default(),
+        }),
+    };
+    let tx = Transaction {
+        source_account: options.source.clone(),
+        fee: options.fee,
+        seq_num: SequenceNumber(sequence),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![operation].try_into()?,
+        ext: TransactionExt::V0,
+    };
+    Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx,
+        signatures: Default::default(),
+    }))
+}
+
+/// Builds one base64 encoded envelope per invocation, in order.
+pub fn build_envelopes(
+    options: &TxOptions,
+    invocations: &[Invocation],
+) -> Result<Vec<String>, CliError> {
+    invocations
+        .iter()
+        .zip(options.sequence..)
+        .map(|(invocation, sequence)| {
+            Ok(build_envelope(options, invocation, sequence)?.to_xdr_base64(Limits::none())?)
+        })
+        .collect()
+}
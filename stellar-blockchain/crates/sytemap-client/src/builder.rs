@@ -0,0 +1,188 @@
+use soroban_sdk::{Address, Env, String};
+use sytemap::types::PropertyInfoPayload;
+
+use crate::error::ClientError;
+
+/// Collects the fields of a `PropertyInfoPayload` from plain Rust values and checks them
+/// before anything is submitted.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyPayloadBuilder {
+    price_of_plot: Option<u64>,
+    property_verification_no: Option<u64>,
+    buyer_wallet_id: Option<Address>,
+    plot_no: Option<std::string::String>,
+    size_of_plot: Option<std::string::String>,
+    coordinate_of_plot: Option<std::string::String>,
+    token_url: Option<std::string::String>,
+    estate_name: Option<std::string::String>,
+    plot_url: Option<std::string::String>,
+    date_of_allocation: Option<std::string::String>,
+    estate_company_name: Option<std::string::String>,
+    nft_address: Option<std::string::String>,
+}
+
+impl PropertyPayloadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn price_of_plot(mut self, price_of_plot: u64) -> Self {
+        self.price_of_plot = Some(price_of_plot);
+        self
+    }
+
+    pub fn property_verification_no(mut self, property_verification_no: u64) -> Self {
+        self.property_verification_no = Some(property_verification_no);
+        self
+    }
+
+    pub fn buyer_wallet_id(mut self, buyer_wallet_id: &Address) -> Self {
+        self.buyer_wallet_id = Some(buyer_wallet_id.clone());
+        self
+    }
+
+    pub fn plot_no(mut self, plot_no: &str) -> Self {
+        self.plot_no = Some(plot_no.to_string());
+        self
+    }
+
+    pub fn size_of_plot(mut self, size_of_plot: &str) -> Self {
+        self.size_of_plot = Some(size_of_plot.to_string());
+        self
+    }
+
+    pub fn coordinate_of_plot(mut self, coordinate_of_plot: &str) -> Self {
+        self.coordinate_of_plot = Some(coordinate_of_plot.to_string());
+        self
+    }
+
+    pub fn token_url(mut self, token_url: &str) -> Self {
+        self.token_url = Some(token_url.to_string());
+        self
+    }
+
+    pub fn estate_name(mut self, estate_name: &str) -> Self {
+        self.estate_name = Some(estate_name.to_string());
+        self
+    }
+
+    pub fn plot_url(mut self, plot_url: &str) -> Self {
+        self.plot_url = Some(plot_url.to_string());
+        self
+    }
+
+    /// The allocation date, as `YYYY-MM-DD`
+    pub fn date_of_allocation(mut self, date_of_allocation: &str) -> Self {
+        self.date_of_allocation = Some(date_of_allocation.to_string());
+        self
+    }
+
+    pub fn estate_company_name(mut self, estate_company_name: &str) -> Self {
+        self.estate_company_name = Some(estate_company_name.to_string());
+        self
+    }
+
+    pub fn nft_address(mut self, nft_address: &str) -> Self {
+        self.nft_address = Some(nft_address.to_string());
+        self
+    }
+
+    /// Checks every field and builds the contract payload.
+    ///
+    /// All fields are required. Besides the checks the registry makes on mint, text fields
+    /// may not be blank or padded with whitespace, URLs need a scheme and the allocation
+    /// date must be a `YYYY-MM-DD` calendar date.
+    pub fn build(&self, env: &Env) -> Result<PropertyInfoPayload, ClientError> {
+        let price_of_plot = required("price_of_plot", self.price_of_plot)?;
+        if price_of_plot == 0 {
+            return Err(invalid("price_of_plot", "must be greater than zero"));
+        }
+        let property_verification_no =
+            required("property_verification_no", self.property_verification_no)?;
+        let buyer_wallet_id = required("buyer_wallet_id", self.buyer_wallet_id.clone())?;
+
+        let text = |field: &'static str, value: &Option<std::string::String>| {
+            let value = value.as_deref().ok_or(ClientError::MissingField(field))?;
+            if value.trim().is_empty() {
+                return Err(invalid(field, "must not be blank"));
+            }
+            if value.trim() != value {
+                return Err(invalid(field, "must not start or end with whitespace"));
+            }
+            Ok(String::from_str(env, value))
+        };
+        let url = |field: &'static str, value: &Option<std::string::String>| {
+            let checked = text(field, value)?;
+            match value.as_deref().and_then(|value| value.split_once("://")) {
+                Some((scheme, rest)) if !scheme.is_empty() && !rest.is_empty() => Ok(checked),
+                _ => Err(invalid(
+                    field,
+                    "must be a URL such as https://... or ipfs://...",
+                )),
+            }
+        };
+
+        let date_of_allocation = text("date_of_allocation", &self.date_of_allocation)?;
+        if !is_calendar_date(self.date_of_allocation.as_deref().unwrap_or_default()) {
+            return Err(invalid(
+                "date_of_allocation",
+                "must be a date written as YYYY-MM-DD",
+            ));
+        }
+
+        Ok(PropertyInfoPayload {
+            price_of_plot,
+            property_verification_no,
+            buyer_wallet_id,
+            plot_no: text("plot_no", &self.plot_no)?,
+            size_of_plot: text("size_of_plot", &self.size_of_plot)?,
+            coordinate_of_plot: text("coordinate_of_plot", &self.coordinate_of_plot)?,
+            token_url: url("token_url", &self.token_url)?,
+            estate_name: text("estate_name", &self.estate_name)?,
+            plot_url: url("plot_url", &self.plot_url)?,
+            date_of_allocation,
+            estate_company_name: text("estate_company_name", &self.estate_company_name)?,
+            nft_address: text("nft_address", &self.nft_address)?,
+        })
+    }
+}
+
+fn required<T>(field: &'static str, value: Option<T>) -> Result<T, ClientError> {
+    value.ok_or(ClientError::MissingField(field))
+}
+
+fn invalid(field: &'static str, reason: &'static str) -> ClientError {
+    ClientError::InvalidField { field, reason }
+}
+
+fn is_calendar_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+    if !value
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || byte == b'-')
+    {
+        return false;
+    }
+    let (Ok(year), Ok(month), Ok(day)) = (
+        year.parse::<u32>(),
+        month.parse::<u32>(),
+        day.parse::<u32>(),
+    ) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
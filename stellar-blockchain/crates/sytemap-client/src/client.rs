@@ -0,0 +1,153 @@
+use soroban_sdk::{Address, Env, Vec};
+use sytemap::{
+    contract::SytemapRegistryClient,
+    types::{PropertyInfo, PropertyInfoPayload},
+    util::MAX_PAGE_SIZE,
+};
+
+use crate::{builder::PropertyPayloadBuilder, error::ClientError};
+
+/// Page size `owner_plots` starts with
+const DEFAULT_PAGE_SIZE: u32 = 25;
+
+/// Higher-level calls on a deployed registry.
+///
+/// Each method invokes the contract through the generated client, so signatures are
+/// whatever the `Env` provides: mocked auths in tests, the invoker's auth in a contract.
+pub struct RegistryClient<'a> {
+    client: SytemapRegistryClient<'a>,
+}
+
+impl<'a> RegistryClient<'a> {
+    pub fn new(env: &Env, contract_id: &Address) -> Self {
+        RegistryClient {
+            client: SytemapRegistryClient::new(env, contract_id),
+        }
+    }
+
+    /// The generated client, for entry points this wrapper does not cover.
+    pub fn contract(&self) -> &SytemapRegistryClient<'a> {
+        &self.client
+    }
+
+    pub fn env(&self) -> &Env {
+        &self.client.env
+    }
+
+    /// Validates the payload and mints it with the admin key, returning the stored property.
+    pub fn mint(&self, payload: &PropertyPayloadBuilder) -> Result<PropertyInfo, ClientError> {
+        let payload = payload.build(self.env())?;
+        ClientError::check(self.client.try_safe_mint_new_property_info(&payload))?;
+        self.property(payload.property_verification_no)
+    }
+
+    /// Validates every payload, then mints them in a single all-or-nothing call.
+    pub fn mint_batch(&self, payloads: &[PropertyPayloadBuilder]) -> Result<(), ClientError> {
+        let mut batch = Vec::<PropertyInfoPayload>::new(self.env());
+        for payload in payloads {
+            batch.push_back(payload.build(self.env())?);
+        }
+        ClientError::check(self.client.try_batch_mint_new_property_info(&batch))
+    }
+
+    /// Moves a fully owned property to `new_owner`. The current owner must sign.
+    pub fn transfer(
+        &self,
+        property_verification_no: u64,
+        new_owner: &Address,
+    ) -> Result<(), ClientError> {
+        ClientError::check(
+            self.client
+                .try_transfer_property_by_owner(&property_verification_no, new_owner),
+        )
+    }
+
+    /// Changes the listed price of a property.
+    pub fn reprice(
+        &self,
+        property_verification_no: u64,
+        new_price: u64,
+    ) -> Result<(), ClientError> {
+        if new_price == 0 {
+            return Err(ClientError::InvalidField {
+                field: "new_price",
+                reason: "must be greater than zero",
+            });
+        }
+        ClientError::check(
+            self.client
+                .try_change_property_price_by_owner(&property_verification_no, &new_price),
+        )
+    }
+
+    pub fn property(&self, property_verification_no: u64) -> Result<PropertyInfo, ClientError> {
+        ClientError::check(
+            self.client
+                .try_get_property_info_details_by_pvn(&property_verification_no),
+        )
+    }
+
+    /// Every property `owner` holds, fetched a page at a time as the iterator advances.
+    pub fn owner_plots(&self, owner: &Address) -> OwnerPlots<'_, 'a> {
+        OwnerPlots {
+            client: &self.client,
+            owner: owner.clone(),
+            page_size: DEFAULT_PAGE_SIZE,
+            cursor: Some(0),
+            page: std::vec::Vec::new().into_iter(),
+        }
+    }
+}
+
+/// Iterator over an owner's properties, returned by [`RegistryClient::owner_plots`].
+///
+/// A failed page is yielded as an error and ends the iteration.
+pub struct OwnerPlots<'c, 'a> {
+    client: &'c SytemapRegistryClient<'a>,
+    owner: Address,
+    page_size: u32,
+    /// Where the next page starts, `None` once the registry has been read to the end
+    cursor: Option<u32>,
+    page: std::vec::IntoIter<PropertyInfo>,
+}
+
+impl OwnerPlots<'_, '_> {
+    /// Fetches `page_size` properties per call instead of the default 25. Sizes beyond the
+    /// registry's maximum are capped.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+}
+
+impl Iterator for OwnerPlots<'_, '_> {
+    type Item = Result<PropertyInfo, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(property) = self.page.next() {
+                return Some(Ok(property));
+            }
+            let cursor = self.cursor?;
+            let page = ClientError::check(self.client.try_get_properties_by_owner_page(
+                &self.owner,
+                &cursor,
+                &self.page_size,
+            ));
+            match page {
+                Ok(page) => {
+                    self.cursor = page.next_cursor;
+                    self.page = page
+                        .properties
+                        .iter()
+                        .collect::<std::vec::Vec<_>>()
+                        .into_iter();
+                }
+                Err(err) => {
+                    self.cursor = None;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
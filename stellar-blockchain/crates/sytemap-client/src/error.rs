@@ -0,0 +1,140 @@
+use soroban_sdk::InvokeError;
+use sytemap::error::SytemapRegistryError;
+use thiserror::Error;
+
+/// Why a registry call failed, or never left the client.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ClientError {
+    /// The registry rejected the call
+    #[error("{} ({:?})", describe(*.0), .0)]
+    Registry(SytemapRegistryError),
+    #[error("the registry failed with contract error #{0}, which this client does not know")]
+    UnknownContractError(u32),
+    #[error("the host aborted the call, e.g. on a missing signature or a contract panic")]
+    Aborted,
+    #[error("the registry returned a value that could not be decoded")]
+    Conversion,
+    #[error("`{0}` is required")]
+    MissingField(&'static str),
+    #[error("`{field}` {reason}")]
+    InvalidField {
+        field: &'static str,
+        reason: &'static str,
+    },
+}
+
+impl ClientError {
+    /// The registry error behind this one, if the contract rejected the call.
+    pub fn registry_error(&self) -> Option<SytemapRegistryError> {
+        match self {
+            ClientError::Registry(err) => Some(*err),
+            _ => None,
+        }
+    }
+
+    /// Flattens the nested result of a generated `try_` client method.
+    pub(crate) fn check<T, E>(
+        result: Result<Result<T, E>, Result<SytemapRegistryError, InvokeError>>,
+    ) -> Result<T, ClientError> {
+        match result {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(ClientError::Conversion),
+            Err(Ok(err)) => Err(ClientError::Registry(err)),
+            Err(Err(InvokeError::Contract(code))) => Err(ClientError::UnknownContractError(code)),
+            Err(Err(InvokeError::Abort)) => Err(ClientError::Aborted),
+        }
+    }
+}
+
+/// What a registry error means, in words a user of the registry can act on.
+pub fn describe(err: SytemapRegistryError) -> &'static str {
+    match err {
+        SytemapRegistryError::AlreadyMinted => {
+            "a property with this verification number has already been minted"
+        }
+        SytemapRegistryError::PropertyAlreadyExists => "the property already exists",
+        SytemapRegistryError::InvalidPrice => "the price must be greater than zero",
+        SytemapRegistryError::PropertyNotFound => "no property is registered under this number",
+        SytemapRegistryError::NftAddressAlreadyInUse => {
+            "the NFT address is already used by another property"
+        }
+        SytemapRegistryError::AlreadyInitialized => "the registry has already been initialized",
+        SytemapRegistryError::PaymentPlanAlreadyExists => {
+            "the property is already being sold on an installment plan"
+        }
+        SytemapRegistryError::PaymentPlanNotFound => "the property has no installment plan",
+        SytemapRegistryError::InvalidPaymentPlan => "the installment plan terms are invalid",
+        SytemapRegistryError::InvalidPaymentAmount => {
+            "the payment must be positive and no more than the balance left"
+        }
+        SytemapRegistryError::PropertyNotTransferable => {
+            "the property cannot change hands in its current status"
+        }
+        SytemapRegistryError::PaymentNotOverdue => {
+            "the installment plan is not past its grace period yet"
+        }
+        SytemapRegistryError::PropertyNotInDefault => "the installment plan is not in default",
+        SytemapRegistryError::NoticePeriodNotElapsed => {
+            "the notice period after the default has not elapsed yet"
+        }
+        SytemapRegistryError::PropertyEncumbered => {
+            "the property carries a lien whose holder has not consented"
+        }
+        SytemapRegistryError::LienAlreadyExists => {
+            "this lien holder already has a lien on the property"
+        }
+        SytemapRegistryError::LienNotFound => "the property has no lien from this holder",
+        SytemapRegistryError::InvalidLien => {
+            "the lien amount must be positive and its expiry in the future"
+        }
+        SytemapRegistryError::PropertyLocked => "the property is locked as collateral",
+        SytemapRegistryError::PropertyNotLocked => "the property is not locked as collateral",
+        SytemapRegistryError::Unauthorized => {
+            "the caller does not hold the role this action requires"
+        }
+        SytemapRegistryError::PropertyFrozen => {
+            "the property is frozen by a dispute until an arbitrator lifts it"
+        }
+        SytemapRegistryError::PropertyNotFrozen => "the property is not frozen",
+        SytemapRegistryError::MintApprovalRequired => {
+            "mints need verifier approval: propose the mint instead"
+        }
+        SytemapRegistryError::MintProposalNotFound => "no pending mint proposal has this id",
+        SytemapRegistryError::MintProposalExpired => "the mint proposal has expired",
+        SytemapRegistryError::MintProposalAlreadyExists => {
+            "a mint proposal for this property is already pending"
+        }
+        SytemapRegistryError::AlreadyApproved => "this verifier has already approved the mint",
+        SytemapRegistryError::InvalidStateTransition => {
+            "the property cannot move to that status from its current one"
+        }
+        SytemapRegistryError::RecipientNotVerified => {
+            "the recipient has not passed the KYC level the estate requires"
+        }
+        SytemapRegistryError::ContractPaused => "the registry or this estate is paused",
+        SytemapRegistryError::InvalidPageLimit => {
+            "the page size must be between 1 and the registry's maximum"
+        }
+        SytemapRegistryError::PlotNoAlreadyInUse => {
+            "another plot in this estate already has this plot number"
+        }
+        SytemapRegistryError::InvalidSubdivision => {
+            "the child plots do not fit within the plot being subdivided"
+        }
+        SytemapRegistryError::InvalidMerge => "the plots cannot be merged into the new plot",
+        SytemapRegistryError::LeaseAlreadyExists => {
+            "the property is already leased for a term that has not ended"
+        }
+        SytemapRegistryError::LeaseNotFound => "the property has no current lease",
+        SytemapRegistryError::InvalidLease => "the lease term, rent or tenant is not valid",
+        SytemapRegistryError::TooManyPendingMints => {
+            "too many mint proposals are awaiting approval"
+        }
+        SytemapRegistryError::TooManyDocuments => {
+            "the property already holds as many documents as it may"
+        }
+        SytemapRegistryError::InvalidMintProposalTtl => {
+            "mint proposals may stay open for at most a year"
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! A typed wrapper around the generated `SytemapRegistryClient`.
+//!
+//! Payloads are put together with [`PropertyPayloadBuilder`] from plain Rust values and
+//! checked before they reach the contract, and every call returns a [`ClientError`] whose
+//! message says what the registry objected to:
+//!
+//! ```ignore
+//! let registry = sytemap_client::RegistryClient::new(&env, &contract_id);
+//! let property = registry.mint(
+//!     &PropertyPayloadBuilder::new()
+//!         .property_verification_no(12345)
+//!         .price_of_plot(250_000)
+//!         .buyer_wallet_id(&owner)
+//!         // ...
+//!         .nft_address("nft-12345"),
+//! )?;
+//! for property in registry.owner_plots(&owner) {
+//!     println!("{}", property?.property_verification_no);
+//! }
+//! ```
+
+mod builder;
+mod client;
+mod error;
+
+pub use builder::PropertyPayloadBuilder;
+pub use client::{OwnerPlots, RegistryClient};
+pub use error::{describe, ClientError};
+
+mod test;
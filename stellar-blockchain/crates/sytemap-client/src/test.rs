@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use sytemap::{contract::SytemapRegistry, error::SytemapRegistryError, types::PropertyStatus};
+
+use crate::*;
+
+fn registry(env: &Env) -> RegistryClient<'_> {
+    env.mock_all_auths();
+    let registry = RegistryClient::new(env, &env.register_contract(None, SytemapRegistry));
+    registry.contract().initialize(
+        &Address::generate(env),
+        &String::from_str(env, "Sytemap"),
+        &String::from_str(env, "SYM"),
+        &String::from_str(env, "http://metadata.url"),
+    );
+    registry
+}
+
+fn payload(property_verification_no: u64, owner: &Address) -> PropertyPayloadBuilder {
+    PropertyPayloadBuilder::new()
+        .price_of_plot(250_000)
+        .property_verification_no(property_verification_no)
+        .buyer_wallet_id(owner)
+        .plot_no(&format!("A-{property_verification_no}"))
+        .size_of_plot("600sqm")
+        .coordinate_of_plot("6.5244N,3.3792E")
+        .token_url("https://sytemap.example/token/12345")
+        .estate_name("Palm Estate")
+        .plot_url("https://sytemap.example/plot/12345")
+        .date_of_allocation("2024-03-01")
+        .estate_company_name("Palm Estates Ltd")
+        .nft_address(&format!("nft-{property_verification_no}"))
+}
+
+#[test]
+fn test_builder_validates_fields() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    assert!(payload(12345, &owner).build(&env).is_ok());
+
+    let err = |builder: PropertyPayloadBuilder| builder.build(&env).unwrap_err();
+    assert_eq!(
+        err(PropertyPayloadBuilder::new().price_of_plot(1)),
+        ClientError::MissingField("property_verification_no")
+    );
+    assert_eq!(
+        err(payload(12345, &owner).price_of_plot(0)).to_string(),
+        "`price_of_plot` must be greater than zero"
+    );
+    assert_eq!(
+        err(payload(12345, &owner).estate_name("  ")),
+        ClientError::InvalidField {
+            field: "estate_name",
+            reason: "must not be blank",
+        }
+    );
+    assert!(matches!(
+        err(payload(12345, &owner).plot_no("A-12 ")),
+        ClientError::InvalidField {
+            field: "plot_no",
+            ..
+        }
+    ));
+    assert!(matches!(
+        err(payload(12345, &owner).token_url("sytemap.example/token")),
+        ClientError::InvalidField {
+            field: "token_url",
+            ..
+        }
+    ));
+    for date in ["2024-02-30", "01-03-2024", "2024-3-1", "2024-13-01"] {
+        assert!(matches!(
+            err(payload(12345, &owner).date_of_allocation(date)),
+            ClientError::InvalidField {
+                field: "date_of_allocation",
+                ..
+            }
+        ));
+    }
+    assert!(payload(12345, &owner)
+        .date_of_allocation("2024-02-29")
+        .build(&env)
+        .is_ok());
+}
+
+#[test]
+fn test_mint_transfer_and_reprice() {
+    let env = Env::default();
+    let registry = registry(&env);
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    let property = registry.mint(&payload(12345, &owner)).unwrap();
+    assert_eq!(property.buyer_wallet_id, owner);
+    assert_eq!(property.status, PropertyStatus::Allocated);
+
+    registry.reprice(12345, 300_000).unwrap();
+    registry.transfer(12345, &new_owner).unwrap();
+    let property = registry.property(12345).unwrap();
+    assert_eq!(property.price_of_plot, 300_000);
+    assert_eq!(property.buyer_wallet_id, new_owner);
+
+    assert!(matches!(
+        registry.reprice(12345, 0),
+        Err(ClientError::InvalidField {
+            field: "new_price",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_registry_errors_are_described() {
+    let env = Env::default();
+    let registry = registry(&env);
+    let owner = Address::generate(&env);
+
+    registry.mint(&payload(12345, &owner)).unwrap();
+    let err = registry.mint(&payload(12345, &owner)).unwrap_err();
+    assert_eq!(
+        err.registry_error(),
+        Some(SytemapRegistryError::AlreadyMinted)
+    );
+    assert_eq!(
+        err.to_string(),
+        "a property with this verification number has already been minted (AlreadyMinted)"
+    );
+
+    let err = registry.transfer(99, &owner).unwrap_err();
+    assert_eq!(
+        err.registry_error(),
+        Some(SytemapRegistryError::PropertyNotFound)
+    );
+
+    // The batch is checked in full before anything is sent
+    let batch = [payload(1, &owner), payload(2, &owner).plot_url("")];
+    assert!(matches!(
+        registry.mint_batch(&batch),
+        Err(ClientError::InvalidField {
+            field: "plot_url",
+            ..
+        })
+    ));
+    assert!(registry.property(1).is_err());
+}
+
+#[test]
+fn test_owner_plots_pages_through_the_registry() {
+    let env = Env::default();
+    let registry = registry(&env);
+    let owner_a = Address::generate(&env);
+    let owner_b = Address::generate(&env);
+
+    let payloads: Vec<_> = (1..=7u64)
+        .map(|pvn| payload(pvn, if pvn % 3 == 0 { &owner_b } else { &owner_a }))
+        .collect();
+    registry.mint_batch(&payloads).unwrap();
+
+    let pvns = |owner: &Address, page_size: u32| -> Vec<u64> {
+        registry
+            .owner_plots(owner)
+            .page_size(page_size)
+            .map(|property| property.unwrap().property_verification_no)
+            .collect()
+    };
+    assert_eq!(pvns(&owner_a, 2), vec![1, 2, 4, 5, 7]);
+    assert_eq!(pvns(&owner_a, 100), vec![1, 2, 4, 5, 7]);
+    assert_eq!(pvns(&owner_b, 1), vec![3, 6]);
+    assert_eq!(pvns(&Address::generate(&env), 3), Vec::<u64>::new());
+}
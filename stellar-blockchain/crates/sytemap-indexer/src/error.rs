@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use sytemap_types::ScValError;
+use thiserror::Error;
+
+/// Errors raised while loading, decoding or folding registry events.
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("malformed getEvents dump: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("malformed event XDR: {0}")]
+    Xdr(#[from] stellar_xdr::curr::Error),
+    #[error(transparent)]
+    ScVal(#[from] ScValError),
+    #[error("event {id} has malformed topics")]
+    InvalidTopics { id: String },
+    #[error("event {id} has unknown name `{name}`")]
+    UnknownEvent { id: String, name: String },
+    #[error("event {id} uses schema version {version}, expected {expected}")]
+    UnsupportedVersion {
+        id: String,
+        version: u32,
+        expected: u32,
+    },
+    #[error("event {id} refers to property {property_verification_no} which was never minted")]
+    UnknownProperty {
+        id: String,
+        property_verification_no: u64,
+    },
+    #[error("event {id} mints property {property_verification_no} which already exists")]
+    DuplicateProperty {
+        id: String,
+        property_verification_no: u64,
+    },
+}
@@ -0,0 +1,456 @@
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal};
+use sytemap_types::{
+    mirror::{Address, DocumentType, IndexIssue, PropertyInfo, PropertyStatus, Role},
+    mirror_struct,
+    scval::{expect_map, field, symbol_to_string},
+    ScValField,
+};
+
+use crate::{error::IndexerError, rpc::RpcEvent};
+
+/// Event schema version this crate understands, see `EVENT_SCHEMA_VERSION` in the contract.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// First topic of every registry event.
+pub const EVENT_NAMESPACE: &str = "sytemap";
+
+mirror_struct! {
+    pub struct InitializedEvent {
+        pub admin: Address,
+        pub sytemap_name: String,
+        pub sytemap_symbol: String,
+        pub metadata_uri: String,
+    }
+}
+
+mirror_struct! {
+    pub struct MetadataUriUpdatedEvent {
+        pub old_metadata_uri: String,
+        pub new_metadata_uri: String,
+    }
+}
+
+mirror_struct! {
+    pub struct PropertyMintedEvent {
+        pub token_id: u64,
+        pub property: PropertyInfo,
+    }
+}
+
+mirror_struct! {
+    pub struct PriceChangedEvent {
+        pub owner: Address,
+        pub old_price: u64,
+        pub new_price: u64,
+    }
+}
+
+mirror_struct! {
+    pub struct OwnershipTransferredEvent {
+        pub from: Address,
+        pub to: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct StatusChangedEvent {
+        pub old_status: PropertyStatus,
+        pub new_status: PropertyStatus,
+    }
+}
+
+mirror_struct! {
+    pub struct PaymentPlanCreatedEvent {
+        pub seller: Address,
+        pub buyer: Address,
+        pub total_amount: i128,
+        pub installment_count: u32,
+    }
+}
+
+mirror_struct! {
+    pub struct InstallmentPaidEvent {
+        pub buyer: Address,
+        pub amount: i128,
+        pub amount_paid: i128,
+        pub outstanding: i128,
+    }
+}
+
+mirror_struct! {
+    pub struct PaymentPlanCompletedEvent {
+        pub buyer: Address,
+        pub total_amount: i128,
+    }
+}
+
+mirror_struct! {
+    pub struct PaymentDefaultDeclaredEvent {
+        pub buyer: Address,
+        pub days_overdue: u64,
+        pub outstanding: i128,
+    }
+}
+
+mirror_struct! {
+    pub struct PaymentDefaultCuredEvent {
+        pub buyer: Address,
+        pub amount_paid: i128,
+    }
+}
+
+mirror_struct! {
+    pub struct RepossessedEvent {
+        pub seller: Address,
+        pub buyer: Address,
+        pub refunded: i128,
+        pub retained: i128,
+    }
+}
+
+mirror_struct! {
+    pub struct LeaseCreatedEvent {
+        pub tenant: Address,
+        pub start: u64,
+        pub end: u64,
+        pub rent: i128,
+        pub token: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct LeaseTerminatedEvent {
+        pub tenant: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct LienRegisteredEvent {
+        pub lienholder: Address,
+        pub amount: i128,
+        pub expiry: u64,
+    }
+}
+
+mirror_struct! {
+    pub struct LienReleasedEvent {
+        pub lienholder: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct LienExpiredEvent {
+        pub lienholder: Address,
+        pub expiry: u64,
+    }
+}
+
+mirror_struct! {
+    pub struct LienConsentedEvent {
+        pub lienholder: Address,
+        pub transferee: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct CollateralLockedEvent {
+        pub owner: Address,
+        pub locker: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct CollateralUnlockedEvent {
+        pub locker: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct LiquidatedEvent {
+        pub locker: Address,
+        pub new_owner: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct FrozenEvent {
+        pub arbitrator: Address,
+        pub case_ref: String,
+    }
+}
+
+mirror_struct! {
+    pub struct UnfrozenEvent {
+        pub arbitrator: Address,
+        pub case_ref: String,
+        pub resolution: String,
+    }
+}
+
+mirror_struct! {
+    pub struct DisputeTransferEvent {
+        pub arbitrator: Address,
+        pub case_ref: String,
+        pub from: Address,
+        pub to: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct MintProposedEvent {
+        pub proposal_id: u64,
+        pub proposer: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct MintApprovedEvent {
+        pub proposal_id: u64,
+        pub verifier: Address,
+        pub approvals: u32,
+    }
+}
+
+mirror_struct! {
+    pub struct DocumentAttachedEvent {
+        pub doc_type: DocumentType,
+        pub sha256_hash: [u8; 32],
+        pub uri: String,
+        pub attached_by: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct RoleChangedEvent {
+        pub role: Role,
+        pub account: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct EstateCompanyBoundEvent {
+        pub estate_company_name: String,
+        pub account: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct MintConfigUpdatedEvent {
+        pub threshold: u32,
+        pub proposal_ttl: u64,
+    }
+}
+
+mirror_struct! {
+    pub struct KycStatusUpdatedEvent {
+        pub account: Address,
+        pub level: u32,
+        pub expiry: u64,
+        pub updated_by: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct EstateKycUpdatedEvent {
+        pub estate_name: String,
+        pub min_level: u32,
+    }
+}
+
+mirror_struct! {
+    pub struct KycProviderUpdatedEvent {
+        pub provider: Option<Address>,
+    }
+}
+
+mirror_struct! {
+    pub struct PauseChangedEvent {
+        pub pauser: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct EstatePauseChangedEvent {
+        pub estate_name: String,
+        pub pauser: Address,
+    }
+}
+
+mirror_struct! {
+    pub struct IndexRepairedEvent {
+        pub issues: Vec<IndexIssue>,
+    }
+}
+
+mirror_struct! {
+    pub struct PlotsMergedEvent {
+        pub owner: Address,
+        pub verifier: Address,
+        pub source_pvns: Vec<u64>,
+    }
+}
+
+mirror_struct! {
+    pub struct SubdividedEvent {
+        pub owner: Address,
+        pub verifier: Address,
+        pub child_pvns: Vec<u64>,
+    }
+}
+
+/// Maps every event name published by the contract to its typed payload.
+macro_rules! sytemap_events {
+    ($($name:literal => $variant:ident($payload:ty),)*) => {
+        /// A decoded registry event payload.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum SytemapEvent {
+            $($variant($payload),)*
+        }
+
+        impl SytemapEvent {
+            /// The event name, i.e. the second topic.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(SytemapEvent::$variant(_) => $name,)*
+                }
+            }
+
+            fn decode(name: &str, data: &ScVal) -> Result<Option<Self>, IndexerError> {
+                let event = match name {
+                    $($name => SytemapEvent::$variant(<$payload>::from_sc_val(data)?),)*
+                    _ => return Ok(None),
+                };
+                Ok(Some(event))
+            }
+        }
+    };
+}
+
+sytemap_events! {
+    "initialized" => Initialized(InitializedEvent),
+    "metadata_uri_updated" => MetadataUriUpdated(MetadataUriUpdatedEvent),
+    "property_minted" => PropertyMinted(PropertyMintedEvent),
+    "price_changed" => PriceChanged(PriceChangedEvent),
+    "ownership_transferred" => OwnershipTransferred(OwnershipTransferredEvent),
+    "status_changed" => StatusChanged(StatusChangedEvent),
+    "payment_plan_created" => PaymentPlanCreated(PaymentPlanCreatedEvent),
+    "installment_paid" => InstallmentPaid(InstallmentPaidEvent),
+    "payment_plan_completed" => PaymentPlanCompleted(PaymentPlanCompletedEvent),
+    "payment_default_declared" => PaymentDefaultDeclared(PaymentDefaultDeclaredEvent),
+    "payment_default_cured" => PaymentDefaultCured(PaymentDefaultCuredEvent),
+    "repossessed" => Repossessed(RepossessedEvent),
+    "lease_created" => LeaseCreated(LeaseCreatedEvent),
+    "lease_terminated" => LeaseTerminated(LeaseTerminatedEvent),
+    "lien_registered" => LienRegistered(LienRegisteredEvent),
+    "lien_released" => LienReleased(LienReleasedEvent),
+    "lien_expired" => LienExpired(LienExpiredEvent),
+    "lien_consented" => LienConsented(LienConsentedEvent),
+    "collateral_locked" => CollateralLocked(CollateralLockedEvent),
+    "collateral_unlocked" => CollateralUnlocked(CollateralUnlockedEvent),
+    "liquidated" => Liquidated(LiquidatedEvent),
+    "frozen" => Frozen(FrozenEvent),
+    "unfrozen" => Unfrozen(UnfrozenEvent),
+    "dispute_transfer" => DisputeTransfer(DisputeTransferEvent),
+    "mint_proposed" => MintProposed(MintProposedEvent),
+    "mint_approved" => MintApproved(MintApprovedEvent),
+    "document_attached" => DocumentAttached(DocumentAttachedEvent),
+    "role_granted" => RoleGranted(RoleChangedEvent),
+    "role_revoked" => RoleRevoked(RoleChangedEvent),
+    "estate_company_bound" => EstateCompanyBound(EstateCompanyBoundEvent),
+    "mint_config_updated" => MintConfigUpdated(MintConfigUpdatedEvent),
+    "kyc_status_updated" => KycStatusUpdated(KycStatusUpdatedEvent),
+    "estate_kyc_updated" => EstateKycUpdated(EstateKycUpdatedEvent),
+    "kyc_provider_updated" => KycProviderUpdated(KycProviderUpdatedEvent),
+    "paused" => Paused(PauseChangedEvent),
+    "unpaused" => Unpaused(PauseChangedEvent),
+    "estate_paused" => EstatePaused(EstatePauseChangedEvent),
+    "estate_unpaused" => EstateUnpaused(EstatePauseChangedEvent),
+    "index_repaired" => IndexRepaired(IndexRepairedEvent),
+    "subdivided" => Subdivided(SubdividedEvent),
+    "plots_merged" => PlotsMerged(PlotsMergedEvent),
+}
+
+/// A registry event together with where it was recorded on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedEvent {
+    /// RPC event id, ordered the same way as the ledger
+    pub id: String,
+    pub ledger: u32,
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    pub tx_hash: Option<String>,
+    /// Third topic of property events, `None` for registry wide events
+    pub property_verification_no: Option<u64>,
+    pub event: SytemapEvent,
+}
+
+/// Decodes a raw RPC event. Returns `Ok(None)` for events that were not published by the
+/// registry, such as token transfers made during the same transaction.
+pub fn decode_event(raw: &RpcEvent) -> Result<Option<DecodedEvent>, IndexerError> {
+    if raw.event_type != "contract" || !raw.in_successful_contract_call {
+        return Ok(None);
+    }
+
+    let topics = raw
+        .topic
+        .iter()
+        .map(|topic| ScVal::from_xdr_base64(topic, Limits::none()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let invalid_topics = || IndexerError::InvalidTopics { id: raw.id.clone() };
+
+    match topics.first() {
+        Some(ScVal::Symbol(namespace)) if namespace.as_slice() == EVENT_NAMESPACE.as_bytes() => {}
+        _ => return Ok(None),
+    }
+    let name = topics
+        .get(1)
+        .ok_or_else(invalid_topics)
+        .and_then(|topic| symbol_to_string(topic).map_err(|_| invalid_topics()))?;
+    let property_verification_no = match topics.get(2) {
+        Some(ScVal::U64(pvn)) => Some(*pvn),
+        Some(_) => return Err(invalid_topics()),
+        None => None,
+    };
+
+    let data = ScVal::from_xdr_base64(raw.value.xdr(), Limits::none())?;
+    let version = u32::from_sc_val(field(expect_map(&data)?, "version")?)?;
+    if version != EVENT_SCHEMA_VERSION {
+        return Err(IndexerError::UnsupportedVersion {
+            id: raw.id.clone(),
+            version,
+            expected: EVENT_SCHEMA_VERSION,
+        });
+    }
+
+    let event = SytemapEvent::decode(&name, &data)?.ok_or_else(|| IndexerError::UnknownEvent {
+        id: raw.id.clone(),
+        name,
+    })?;
+
+    Ok(Some(DecodedEvent {
+        id: raw.id.clone(),
+        ledger: raw.ledger,
+        ledger_closed_at: raw.ledger_closed_at.clone(),
+        contract_id: raw.contract_id.clone(),
+        tx_hash: raw.tx_hash.clone(),
+        property_verification_no,
+        event,
+    }))
+}
+
+/// Decodes every registry event in `raw`, optionally keeping only those of one contract.
+pub fn decode_events<'a>(
+    raw: impl IntoIterator<Item = &'a RpcEvent>,
+    contract_id: Option<&str>,
+) -> Result<Vec<DecodedEvent>, IndexerError> {
+    let mut events = Vec::new();
+    for raw in raw {
+        if contract_id.is_some_and(|contract_id| contract_id != raw.contract_id) {
+            continue;
+        }
+        if let Some(event) = decode_event(raw)? {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
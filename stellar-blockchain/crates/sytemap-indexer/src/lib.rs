@@ -0,0 +1,27 @@
+//! Decodes the events published by the `sytemap` registry contract and folds them into an
+//! in-memory view of the registry.
+//!
+//! Events are read from `getEvents` JSON-RPC dumps on disk:
+//!
+//! ```ignore
+//! let page = sytemap_indexer::load_events_file("events.json")?;
+//! let events = sytemap_indexer::decode_events(&page.events, None)?;
+//! let mut state = sytemap_indexer::RegistryState::new();
+//! state.replay(&events)?;
+//! ```
+
+mod error;
+mod events;
+mod rpc;
+mod state;
+
+pub use error::IndexerError;
+pub use events::*;
+pub use rpc::{load_events_file, parse_events, EventPage, RpcEvent, RpcEventValue};
+pub use state::{PropertyRecord, RegistryState};
+pub use sytemap_types::{
+    mirror::{Address, DocumentType, IndexIssue, PropertyInfo, PropertyStatus, Role},
+    ScValError, ScValField,
+};
+
+mod test;
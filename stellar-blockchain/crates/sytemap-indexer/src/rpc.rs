@@ -0,0 +1,89 @@
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::error::IndexerError;
+
+/// A single contract event as returned by the Soroban JSON-RPC `getEvents` method.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcEvent {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub ledger: u32,
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    pub id: String,
+    #[serde(default)]
+    pub paging_token: Option<String>,
+    /// Base64 encoded `ScVal` topics
+    pub topic: Vec<String>,
+    /// Base64 encoded `ScVal` data
+    pub value: RpcEventValue,
+    #[serde(default = "default_in_successful_contract_call")]
+    pub in_successful_contract_call: bool,
+    #[serde(default)]
+    pub tx_hash: Option<String>,
+}
+
+/// Event data, either as a bare base64 string or wrapped as `{ "xdr": ... }` by older RPC versions.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum RpcEventValue {
+    Xdr(String),
+    Wrapped { xdr: String },
+}
+
+impl RpcEventValue {
+    pub fn xdr(&self) -> &str {
+        match self {
+            RpcEventValue::Xdr(xdr) | RpcEventValue::Wrapped { xdr } => xdr,
+        }
+    }
+}
+
+/// The `result` object of a `getEvents` response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+    pub events: Vec<RpcEvent>,
+    #[serde(default)]
+    pub latest_ledger: Option<u32>,
+}
+
+/// Accepted layouts of a dump on disk: the full JSON-RPC response, its `result` object,
+/// or a bare array of events.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EventDump {
+    Response { result: EventPage },
+    Page(EventPage),
+    Events(Vec<RpcEvent>),
+}
+
+fn default_in_successful_contract_call() -> bool {
+    true
+}
+
+/// Parses a `getEvents` dump.
+pub fn parse_events(json: &str) -> Result<EventPage, IndexerError> {
+    let page = match serde_json::from_str::<EventDump>(json)? {
+        EventDump::Response { result } => result,
+        EventDump::Page(page) => page,
+        EventDump::Events(events) => EventPage {
+            events,
+            latest_ledger: None,
+        },
+    };
+    Ok(page)
+}
+
+/// Reads and parses a `getEvents` dump from disk.
+pub fn load_events_file(path: impl AsRef<Path>) -> Result<EventPage, IndexerError> {
+    let path = path.as_ref();
+    let json = fs::read_to_string(path).map_err(|source| IndexerError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    parse_events(&json)
+}
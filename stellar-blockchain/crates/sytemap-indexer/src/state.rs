@@ -0,0 +1,218 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use sytemap_types::mirror::{Address, PropertyInfo, PropertyStatus};
+
+use crate::{
+    error::IndexerError,
+    events::{DecodedEvent, LeaseCreatedEvent, SytemapEvent},
+};
+
+/// A property as currently known to the indexer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyRecord {
+    pub token_id: u64,
+    /// Kept up to date with owner, price and status changes
+    pub property: PropertyInfo,
+    pub minted_ledger: u32,
+    pub last_modified_ledger: u32,
+    /// PVNs of the plots this one was subdivided or merged from
+    pub parent_pvns: Vec<u64>,
+    /// Latest lease on the plot, kept until its tenant gives it up even once its term is over
+    pub lease: Option<LeaseCreatedEvent>,
+}
+
+/// In-memory view of the registry, rebuilt by folding its events in ledger order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryState {
+    pub admin: Option<Address>,
+    pub sytemap_name: Option<String>,
+    pub sytemap_symbol: Option<String>,
+    pub metadata_uri: Option<String>,
+    pub paused: bool,
+    pub paused_estates: BTreeSet<String>,
+    pub properties: BTreeMap<u64, PropertyRecord>,
+    /// Id of the last event folded into the state
+    pub cursor: Option<String>,
+    pub last_ledger: u32,
+}
+
+impl RegistryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds one event into the state. Events at or before the cursor are skipped, so
+    /// overlapping dumps can be replayed safely. Returns whether the event was applied.
+    pub fn apply(&mut self, event: &DecodedEvent) -> Result<bool, IndexerError> {
+        if self
+            .cursor
+            .as_deref()
+            .is_some_and(|cursor| event.id.as_str() <= cursor)
+        {
+            return Ok(false);
+        }
+
+        match &event.event {
+            SytemapEvent::Initialized(initialized) => {
+                self.admin = Some(initialized.admin.clone());
+                self.sytemap_name = Some(initialized.sytemap_name.clone());
+                self.sytemap_symbol = Some(initialized.sytemap_symbol.clone());
+                self.metadata_uri = Some(initialized.metadata_uri.clone());
+            }
+            SytemapEvent::MetadataUriUpdated(updated) => {
+                self.metadata_uri = Some(updated.new_metadata_uri.clone());
+            }
+            SytemapEvent::PropertyMinted(minted) => {
+                let pvn = minted.property.property_verification_no;
+                if self.properties.contains_key(&pvn) {
+                    return Err(IndexerError::DuplicateProperty {
+                        id: event.id.clone(),
+                        property_verification_no: pvn,
+                    });
+                }
+                self.properties.insert(
+                    pvn,
+                    PropertyRecord {
+                        token_id: minted.token_id,
+                        property: minted.property.clone(),
+                        minted_ledger: event.ledger,
+                        last_modified_ledger: event.ledger,
+                        parent_pvns: Vec::new(),
+                        lease: None,
+                    },
+                );
+            }
+            SytemapEvent::PriceChanged(changed) => {
+                self.property_mut(event)?.property.price_of_plot = changed.new_price;
+            }
+            SytemapEvent::OwnershipTransferred(transferred) => {
+                self.property_mut(event)?.property.buyer_wallet_id = transferred.to.clone();
+            }
+            SytemapEvent::StatusChanged(changed) => {
+                self.property_mut(event)?.property.status = changed.new_status;
+            }
+            SytemapEvent::Subdivided(subdivided) => {
+                // Check every child before touching the parent, so a bad event changes nothing
+                if let Some(child_pvn) = subdivided
+                    .child_pvns
+                    .iter()
+                    .find(|child_pvn| !self.properties.contains_key(child_pvn))
+                {
+                    return Err(IndexerError::UnknownProperty {
+                        id: event.id.clone(),
+                        property_verification_no: *child_pvn,
+                    });
+                }
+                let parent_pvn = self.property_mut(event)?.property.property_verification_no;
+                for child_pvn in &subdivided.child_pvns {
+                    if let Some(child) = self.properties.get_mut(child_pvn) {
+                        child.parent_pvns = vec![parent_pvn];
+                    }
+                }
+            }
+            SytemapEvent::PlotsMerged(merged) => {
+                self.property_mut(event)?.parent_pvns = merged.source_pvns.clone();
+            }
+            SytemapEvent::LeaseCreated(created) => {
+                self.property_mut(event)?.lease = Some(created.clone());
+            }
+            SytemapEvent::LeaseTerminated(_) => {
+                self.property_mut(event)?.lease = None;
+            }
+            SytemapEvent::Paused(_) => self.paused = true,
+            SytemapEvent::Unpaused(_) => self.paused = false,
+            SytemapEvent::EstatePaused(paused) => {
+                self.paused_estates.insert(paused.estate_name.clone());
+            }
+            SytemapEvent::EstateUnpaused(unpaused) => {
+                self.paused_estates.remove(&unpaused.estate_name);
+            }
+            _ => {}
+        }
+
+        self.cursor = Some(event.id.clone());
+        self.last_ledger = event.ledger;
+        Ok(true)
+    }
+
+    /// Folds a batch of events and returns how many were applied.
+    pub fn replay<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = &'a DecodedEvent>,
+    ) -> Result<usize, IndexerError> {
+        let mut applied = 0;
+        for event in events {
+            if self.apply(event)? {
+                applied += 1;
+            }
+        }
+        Ok(applied)
+    }
+
+    pub fn property(&self, property_verification_no: u64) -> Option<&PropertyRecord> {
+        self.properties.get(&property_verification_no)
+    }
+
+    pub fn owner_of(&self, property_verification_no: u64) -> Option<&Address> {
+        self.property(property_verification_no)
+            .map(|record| &record.property.buyer_wallet_id)
+    }
+
+    pub fn price_of(&self, property_verification_no: u64) -> Option<u64> {
+        self.property(property_verification_no)
+            .map(|record| record.property.price_of_plot)
+    }
+
+    pub fn status_of(&self, property_verification_no: u64) -> Option<PropertyStatus> {
+        self.property(property_verification_no)
+            .map(|record| record.property.status)
+    }
+
+    /// The lease in force on a plot at ledger time `now`, like the contract's
+    /// `get_active_lease`.
+    pub fn active_lease(
+        &self,
+        property_verification_no: u64,
+        now: u64,
+    ) -> Option<&LeaseCreatedEvent> {
+        self.property(property_verification_no)
+            .and_then(|record| record.lease.as_ref())
+            .filter(|lease| lease.start <= now && now < lease.end)
+    }
+
+    /// Verification numbers of the properties held by `owner`, in ascending order.
+    pub fn properties_owned_by(&self, owner: &Address) -> Vec<u64> {
+        self.properties
+            .iter()
+            .filter(|(_, record)| &record.property.buyer_wallet_id == owner)
+            .map(|(pvn, _)| *pvn)
+            .collect()
+    }
+
+    /// Verification numbers of the plots subdivided or merged out of a plot, in ascending
+    /// order.
+    pub fn children_of(&self, property_verification_no: u64) -> Vec<u64> {
+        self.properties
+            .iter()
+            .filter(|(_, record)| record.parent_pvns.contains(&property_verification_no))
+            .map(|(pvn, _)| *pvn)
+            .collect()
+    }
+
+    fn property_mut(&mut self, event: &DecodedEvent) -> Result<&mut PropertyRecord, IndexerError> {
+        let pvn = event
+            .property_verification_no
+            .ok_or_else(|| IndexerError::InvalidTopics {
+                id: event.id.clone(),
+            })?;
+        let record =
+            self.properties
+                .get_mut(&pvn)
+                .ok_or_else(|| IndexerError::UnknownProperty {
+                    id: event.id.clone(),
+                    property_verification_no: pvn,
+                })?;
+        record.last_modified_ledger = event.ledger;
+        Ok(record)
+    }
+}
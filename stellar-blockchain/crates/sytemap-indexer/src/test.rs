@@ -0,0 +1,434 @@
+#![cfg(test)]
+
+use stellar_xdr::curr::{Limits, ScMap, ScMapEntry, ScSymbol, ScVal, WriteXdr};
+
+use crate::*;
+
+const PAGE_1: &str = include_str!("../fixtures/get_events_page_1.json");
+const PAGE_2: &str = include_str!("../fixtures/get_events_page_2.json");
+
+const ADMIN: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAATYON";
+const OWNER_A: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4";
+const OWNER_B: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M";
+const OWNER_C: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4";
+const BUYER: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMDR4";
+const REGISTRY: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM";
+
+fn address(strkey: &str) -> Address {
+    Address(strkey.to_string())
+}
+
+fn decode_fixture(json: &str) -> Vec<DecodedEvent> {
+    let page = parse_events(json).unwrap();
+    decode_events(&page.events, None).unwrap()
+}
+
+fn encode(val: ScVal) -> String {
+    val.to_xdr_base64(Limits::none()).unwrap()
+}
+
+fn symbol(name: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+}
+
+#[test]
+fn test_decode_skips_foreign_events() {
+    let page = parse_events(PAGE_1).unwrap();
+    assert_eq!(page.latest_ledger, Some(500035));
+    assert_eq!(page.events.len(), 7);
+
+    let events = decode_events(&page.events, None).unwrap();
+    let names: Vec<_> = events.iter().map(|event| event.event.name()).collect();
+    assert_eq!(
+        names,
+        [
+            "initialized",
+            "property_minted",
+            "property_minted",
+            "price_changed",
+            "ownership_transferred"
+        ]
+    );
+    assert!(events.iter().all(|event| event.contract_id == REGISTRY));
+
+    let SytemapEvent::Initialized(initialized) = &events[0].event else {
+        panic!("expected initialized, got {:?}", events[0].event);
+    };
+    assert_eq!(initialized.admin, address(ADMIN));
+    assert_eq!(initialized.metadata_uri, "http://metadata.url");
+    assert_eq!(events[0].property_verification_no, None);
+
+    let SytemapEvent::PropertyMinted(minted) = &events[1].event else {
+        panic!("expected property_minted, got {:?}", events[1].event);
+    };
+    assert_eq!(events[1].property_verification_no, Some(12345));
+    assert_eq!(minted.token_id, 1);
+    assert_eq!(minted.property.buyer_wallet_id, address(OWNER_A));
+    assert_eq!(minted.property.estate_name, "Estate XYZ");
+    assert_eq!(minted.property.status, PropertyStatus::Allocated);
+
+    let SytemapEvent::PriceChanged(changed) = &events[3].event else {
+        panic!("expected price_changed, got {:?}", events[3].event);
+    };
+    assert_eq!((changed.old_price, changed.new_price), (100_000, 150_000));
+}
+
+#[test]
+fn test_decode_filters_by_contract() {
+    let page = parse_events(PAGE_1).unwrap();
+    let events = decode_events(&page.events, Some("CBOTHERCONTRACT")).unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_replay_folds_owners_prices_and_status() {
+    let mut state = RegistryState::new();
+    assert_eq!(state.replay(&decode_fixture(PAGE_1)).unwrap(), 5);
+    assert_eq!(state.owner_of(12345), Some(&address(OWNER_C)));
+    assert_eq!(state.price_of(12345), Some(150_000));
+    assert_eq!(state.last_ledger, 500035);
+
+    // The second page overlaps the first by one ledger; the repeated event is skipped
+    assert_eq!(state.replay(&decode_fixture(PAGE_2)).unwrap(), 10);
+
+    assert_eq!(state.admin, Some(address(ADMIN)));
+    assert_eq!(
+        state.metadata_uri.as_deref(),
+        Some("http://new.metadata.url")
+    );
+    assert_eq!(state.properties.len(), 3);
+
+    assert_eq!(state.owner_of(12345), Some(&address(OWNER_C)));
+    assert_eq!(state.status_of(12345), Some(PropertyStatus::Allocated));
+
+    assert_eq!(state.owner_of(67890), Some(&address(OWNER_B)));
+    assert_eq!(state.status_of(67890), Some(PropertyStatus::Encumbered));
+
+    // Fully paid installment plan releases the title to the buyer
+    assert_eq!(state.owner_of(11111), Some(&address(BUYER)));
+    assert_eq!(state.status_of(11111), Some(PropertyStatus::Allocated));
+    assert_eq!(
+        state.properties_owned_by(&address(OWNER_A)),
+        Vec::<u64>::new()
+    );
+
+    assert_eq!(state.last_ledger, 500070);
+    let record = state.property(11111).unwrap();
+    assert_eq!(record.token_id, 3);
+    assert_eq!(record.minted_ledger, 500049);
+    assert_eq!(record.last_modified_ledger, 500063);
+}
+
+#[test]
+fn test_replay_is_deterministic() {
+    let events: Vec<_> = decode_fixture(PAGE_1)
+        .into_iter()
+        .chain(decode_fixture(PAGE_2))
+        .collect();
+
+    let mut first = RegistryState::new();
+    first.replay(&events).unwrap();
+    let mut second = RegistryState::new();
+    second.replay(&events).unwrap();
+    assert_eq!(first, second);
+
+    // Replaying everything again on top is a no-op
+    assert_eq!(second.replay(&events).unwrap(), 0);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_replay_without_mint_fails() {
+    let mut state = RegistryState::new();
+    let result = state.replay(&decode_fixture(PAGE_2));
+    assert!(matches!(
+        result,
+        Err(IndexerError::UnknownProperty {
+            property_verification_no: 12345,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_parse_bare_event_array_with_wrapped_value() {
+    let page = parse_events(PAGE_1).unwrap();
+    let raw = &page.events[2];
+    let json = format!(
+        r#"[{{"type":"contract","ledger":{},"ledgerClosedAt":"{}","contractId":"{}","id":"{}","topic":{},"value":{{"xdr":"{}"}}}}]"#,
+        raw.ledger,
+        raw.ledger_closed_at,
+        raw.contract_id,
+        raw.id,
+        serde_json::to_string(&raw.topic).unwrap(),
+        raw.value.xdr(),
+    );
+
+    let parsed = parse_events(&json).unwrap();
+    assert_eq!(parsed.latest_ledger, None);
+    let events = decode_events(&parsed.events, None).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.name(), "initialized");
+}
+
+#[test]
+fn test_unsupported_schema_version() {
+    let page = parse_events(PAGE_1).unwrap();
+    let mut raw = page.events[2].clone();
+    let data = ScMap(
+        vec![ScMapEntry {
+            key: symbol("version"),
+            val: ScVal::U32(EVENT_SCHEMA_VERSION + 1),
+        }]
+        .try_into()
+        .unwrap(),
+    );
+    raw.value = RpcEventValue::Xdr(encode(ScVal::Map(Some(data))));
+
+    assert!(matches!(
+        decode_event(&raw),
+        Err(IndexerError::UnsupportedVersion { version: 2, .. })
+    ));
+}
+
+#[test]
+fn test_unknown_event_name() {
+    let page = parse_events(PAGE_1).unwrap();
+    let mut raw = page.events[2].clone();
+    raw.topic = vec![encode(symbol("sytemap")), encode(symbol("not_an_event"))];
+
+    assert!(matches!(
+        decode_event(&raw),
+        Err(IndexerError::UnknownEvent { name, .. }) if name == "not_an_event"
+    ));
+}
+
+#[test]
+fn test_decode_index_repaired() {
+    let page = parse_events(PAGE_1).unwrap();
+    let mut raw = page.events[2].clone();
+    raw.topic = vec![
+        encode(symbol("sytemap")),
+        encode(symbol("index_repaired")),
+        encode(ScVal::U64(7)),
+    ];
+    let issue = |name: &str| ScVal::Vec(Some(vec![symbol(name)].try_into().unwrap()));
+    let data = ScMap(
+        vec![
+            ScMapEntry {
+                key: symbol("issues"),
+                val: ScVal::Vec(Some(
+                    vec![issue("NotListed"), issue("MissingTokenId")]
+                        .try_into()
+                        .unwrap(),
+                )),
+            },
+            ScMapEntry {
+                key: symbol("version"),
+                val: ScVal::U32(EVENT_SCHEMA_VERSION),
+            },
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    raw.value = RpcEventValue::Xdr(encode(ScVal::Map(Some(data))));
+
+    let decoded = decode_event(&raw).unwrap().unwrap();
+    assert_eq!(decoded.property_verification_no, Some(7));
+    assert_eq!(
+        decoded.event,
+        SytemapEvent::IndexRepaired(IndexRepairedEvent {
+            issues: vec![IndexIssue::NotListed, IndexIssue::MissingTokenId],
+        })
+    );
+}
+
+#[test]
+fn test_replay_subdivided_and_merged_link_plots() {
+    let mut state = RegistryState::new();
+    state.replay(&decode_fixture(PAGE_1)).unwrap();
+    let parent = state.property(12345).unwrap().clone();
+
+    let page = parse_events(PAGE_1).unwrap();
+    let last = page.events.last().unwrap();
+    let later = |suffix: &str, pvn: u64, event: SytemapEvent| DecodedEvent {
+        id: format!("{}-{suffix}", last.id),
+        ledger: last.ledger + 1,
+        ledger_closed_at: last.ledger_closed_at.clone(),
+        contract_id: REGISTRY.to_string(),
+        tx_hash: None,
+        property_verification_no: Some(pvn),
+        event,
+    };
+    let minted = |pvn: u64, token_id: u64| {
+        let mut property = parent.property.clone();
+        property.property_verification_no = pvn;
+        SytemapEvent::PropertyMinted(PropertyMintedEvent { token_id, property })
+    };
+    let mut events = vec![
+        later("1-child", 20001, minted(20001, 10)),
+        later("2-child", 20002, minted(20002, 11)),
+    ];
+
+    let mut raw = page.events[2].clone();
+    raw.id = format!("{}-3-subdivided", last.id);
+    raw.ledger = last.ledger + 1;
+    raw.topic = vec![
+        encode(symbol("sytemap")),
+        encode(symbol("subdivided")),
+        encode(ScVal::U64(12345)),
+    ];
+    let address_val = |strkey: &str| ScVal::Address(strkey.parse().unwrap());
+    let data = ScMap(
+        vec![
+            ScMapEntry {
+                key: symbol("child_pvns"),
+                val: ScVal::Vec(Some(
+                    vec![ScVal::U64(20001), ScVal::U64(20002)]
+                        .try_into()
+                        .unwrap(),
+                )),
+            },
+            ScMapEntry {
+                key: symbol("owner"),
+                val: address_val(OWNER_C),
+            },
+            ScMapEntry {
+                key: symbol("verifier"),
+                val: address_val(ADMIN),
+            },
+            ScMapEntry {
+                key: symbol("version"),
+                val: ScVal::U32(EVENT_SCHEMA_VERSION),
+            },
+        ]
+        .try_into()
+        .unwrap(),
+    );
+    raw.value = RpcEventValue::Xdr(encode(ScVal::Map(Some(data))));
+    let subdivided = decode_event(&raw).unwrap().unwrap();
+    assert_eq!(
+        subdivided.event,
+        SytemapEvent::Subdivided(SubdividedEvent {
+            owner: address(OWNER_C),
+            verifier: address(ADMIN),
+            child_pvns: vec![20001, 20002],
+        })
+    );
+    events.push(subdivided);
+
+    // Both halves are merged back into one plot
+    events.push(later("4-merged", 20003, minted(20003, 12)));
+    events.push(later(
+        "5-merged",
+        20003,
+        SytemapEvent::PlotsMerged(PlotsMergedEvent {
+            owner: address(OWNER_C),
+            verifier: address(ADMIN),
+            source_pvns: vec![20001, 20002],
+        }),
+    ));
+
+    assert_eq!(state.replay(&events).unwrap(), 5);
+    assert_eq!(state.children_of(12345), vec![20001, 20002]);
+    assert_eq!(state.property(20002).unwrap().parent_pvns, vec![12345]);
+    assert!(state.property(12345).unwrap().parent_pvns.is_empty());
+    assert_eq!(state.children_of(20001), vec![20003]);
+    assert_eq!(
+        state.property(20003).unwrap().parent_pvns,
+        vec![20001, 20002]
+    );
+}
+
+#[test]
+fn test_subdivided_with_unknown_child_changes_nothing() {
+    let mut state = RegistryState::new();
+    state.replay(&decode_fixture(PAGE_1)).unwrap();
+    let parent = state.property(12345).unwrap().clone();
+
+    let page = parse_events(PAGE_1).unwrap();
+    let last = page.events.last().unwrap();
+    let later = |suffix: &str, pvn: u64, event: SytemapEvent| DecodedEvent {
+        id: format!("{}-{suffix}", last.id),
+        ledger: last.ledger + 1,
+        ledger_closed_at: last.ledger_closed_at.clone(),
+        contract_id: REGISTRY.to_string(),
+        tx_hash: None,
+        property_verification_no: Some(pvn),
+        event,
+    };
+    let mut child = parent.property.clone();
+    child.property_verification_no = 20001;
+    state
+        .apply(&later(
+            "1-child",
+            20001,
+            SytemapEvent::PropertyMinted(PropertyMintedEvent {
+                token_id: 10,
+                property: child,
+            }),
+        ))
+        .unwrap();
+    let before = state.clone();
+
+    // The second child was never minted
+    let result = state.apply(&later(
+        "2-subdivided",
+        12345,
+        SytemapEvent::Subdivided(SubdividedEvent {
+            owner: address(OWNER_C),
+            verifier: address(ADMIN),
+            child_pvns: vec![20001, 20002],
+        }),
+    ));
+    assert!(matches!(
+        result,
+        Err(IndexerError::UnknownProperty {
+            property_verification_no: 20002,
+            ..
+        })
+    ));
+    assert_eq!(state, before);
+    assert!(state.property(20001).unwrap().parent_pvns.is_empty());
+}
+
+#[test]
+fn test_replay_lease_survives_sale() {
+    let mut state = RegistryState::new();
+    let events = decode_fixture(PAGE_1);
+    // Lease 12345 before it is sold to OWNER_C
+    let mint = events
+        .iter()
+        .position(|event| event.event.name() == "property_minted")
+        .unwrap();
+    let mut lease = events[mint].clone();
+    lease.id = format!("{}-lease", lease.id);
+    lease.property_verification_no = Some(12345);
+    lease.event = SytemapEvent::LeaseCreated(LeaseCreatedEvent {
+        tenant: address(BUYER),
+        start: 1_000,
+        end: 2_000,
+        rent: 5_000,
+        token: address(REGISTRY),
+    });
+    let mut with_lease = events.clone();
+    with_lease.insert(mint + 1, lease);
+    state.replay(&with_lease).unwrap();
+
+    assert_eq!(state.owner_of(12345), Some(&address(OWNER_C)));
+    assert_eq!(
+        state.active_lease(12345, 1_500).map(|lease| &lease.tenant),
+        Some(&address(BUYER))
+    );
+    assert_eq!(state.active_lease(12345, 999), None);
+    assert_eq!(state.active_lease(12345, 2_000), None);
+
+    let mut terminated = with_lease.last().unwrap().clone();
+    terminated.id = format!("{}-terminated", terminated.id);
+    terminated.property_verification_no = Some(12345);
+    terminated.event = SytemapEvent::LeaseTerminated(LeaseTerminatedEvent {
+        tenant: address(BUYER),
+    });
+    state.apply(&terminated).unwrap();
+    assert_eq!(state.property(12345).unwrap().lease, None);
+}
@@ -0,0 +1,18 @@
+use sytemap_indexer::IndexerError;
+use thiserror::Error;
+
+/// Errors raised while building the SQLite projection.
+#[derive(Debug, Error)]
+pub enum ProjectionError {
+    #[error(transparent)]
+    Indexer(#[from] IndexerError),
+    #[error("sqlite: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("event {id} refers to property {property_verification_no} which was never minted")]
+    UnknownProperty {
+        id: String,
+        property_verification_no: u64,
+    },
+    #[error("event {id} has no property verification number")]
+    MissingProperty { id: String },
+}
@@ -0,0 +1,14 @@
+//! Builds a SQLite projection of the `sytemap` registry from exported `getEvents` dumps.
+//!
+//! The database holds `properties`, `owners`, `price_history` and `transfers` tables plus a
+//! `cursor` row recording the last event written, so later runs only add newer events.
+//! Rebuilding from the same dumps always produces the same rows.
+
+mod error;
+mod projection;
+mod schema;
+
+pub use error::ProjectionError;
+pub use projection::{Cursor, Projection, ProjectionReport};
+
+mod test;
@@ -0,0 +1,74 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+use sytemap_sqlite::Projection;
+
+/// Build or update a SQLite projection of the sytemap registry from `getEvents` dumps.
+#[derive(Debug, Parser)]
+#[command(name = "sytemap-sqlite", version)]
+struct Args {
+    /// SQLite database to create or update
+    #[arg(long, default_value = "sytemap.db")]
+    db: PathBuf,
+
+    /// Drop every table and rebuild the database from the given dumps
+    #[arg(long)]
+    rebuild: bool,
+
+    /// Only project events published by this contract
+    #[arg(long)]
+    contract_id: Option<String>,
+
+    /// `getEvents` JSON dumps, or directories of `.json` dumps
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let files = collect_files(&args.inputs)?;
+
+    let mut projection = Projection::open(&args.db)?;
+    if args.rebuild {
+        projection.reset()?;
+    }
+    let report = projection.project_files(&files, args.contract_id.as_deref())?;
+
+    println!(
+        "{}: {} events applied, {} already projected",
+        args.db.display(),
+        report.applied,
+        report.skipped
+    );
+    if let Some(cursor) = report.cursor {
+        println!("cursor: {} (ledger {})", cursor.event_id, cursor.ledger);
+    }
+    Ok(())
+}
+
+/// Expands directories into the `.json` files they contain, sorted by name.
+fn collect_files(inputs: &[PathBuf]) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let mut entries = fs::read_dir(input)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+            entries.sort();
+            files.extend(entries);
+        } else {
+            files.push(input.clone());
+        }
+    }
+    Ok(files)
+}
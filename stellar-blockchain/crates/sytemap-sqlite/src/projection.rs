@@ -0,0 +1,295 @@
+use std::{collections::BTreeMap, path::Path};
+
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use sytemap_indexer::{decode_events, load_events_file, DecodedEvent, SytemapEvent};
+
+use crate::{
+    error::ProjectionError,
+    schema::{CREATE_TABLES, DROP_TABLES},
+};
+
+/// Position of the last event written to the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub event_id: String,
+    pub ledger: u32,
+}
+
+/// Outcome of a projection run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectionReport {
+    /// Events written to the database
+    pub applied: usize,
+    /// Events at or before the stored cursor
+    pub skipped: usize,
+    pub cursor: Option<Cursor>,
+}
+
+/// SQLite database holding the registry history.
+pub struct Projection {
+    conn: Connection,
+}
+
+impl Projection {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ProjectionError> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, ProjectionError> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self, ProjectionError> {
+        conn.execute_batch(CREATE_TABLES)?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Drops every table, including the cursor, so the next run starts from scratch.
+    pub fn reset(&mut self) -> Result<(), ProjectionError> {
+        self.conn.execute_batch(DROP_TABLES)?;
+        self.conn.execute_batch(CREATE_TABLES)?;
+        Ok(())
+    }
+
+    pub fn cursor(&self) -> Result<Option<Cursor>, ProjectionError> {
+        let cursor = self
+            .conn
+            .query_row(
+                "SELECT event_id, ledger FROM cursor WHERE id = 0",
+                [],
+                |row| {
+                    Ok(Cursor {
+                        event_id: row.get(0)?,
+                        ledger: row.get(1)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(cursor)
+    }
+
+    /// Loads the given `getEvents` dumps and writes the events past the cursor.
+    pub fn project_files<P: AsRef<Path>>(
+        &mut self,
+        paths: &[P],
+        contract_id: Option<&str>,
+    ) -> Result<ProjectionReport, ProjectionError> {
+        let mut events = Vec::new();
+        for path in paths {
+            let page = load_events_file(path)?;
+            events.extend(decode_events(&page.events, contract_id)?);
+        }
+        self.project(events)
+    }
+
+    /// Writes the events past the cursor in event id order, within a single transaction.
+    /// Events repeated across overlapping dumps are written once.
+    pub fn project(
+        &mut self,
+        events: impl IntoIterator<Item = DecodedEvent>,
+    ) -> Result<ProjectionReport, ProjectionError> {
+        let ordered: BTreeMap<String, DecodedEvent> = events
+            .into_iter()
+            .map(|event| (event.id.clone(), event))
+            .collect();
+
+        let mut report = ProjectionReport {
+            cursor: self.cursor()?,
+            ..Default::default()
+        };
+
+        let tx = self.conn.transaction()?;
+        for (id, event) in ordered {
+            if report
+                .cursor
+                .as_ref()
+                .is_some_and(|cursor| id <= cursor.event_id)
+            {
+                report.skipped += 1;
+                continue;
+            }
+
+            apply_event(&tx, &event)?;
+            report.applied += 1;
+            report.cursor = Some(Cursor {
+                event_id: id,
+                ledger: event.ledger,
+            });
+        }
+
+        if let Some(cursor) = &report.cursor {
+            tx.execute(
+                "INSERT INTO cursor (id, event_id, ledger) VALUES (0, ?1, ?2)
+                 ON CONFLICT (id) DO UPDATE SET event_id = excluded.event_id, ledger = excluded.ledger",
+                params![cursor.event_id, cursor.ledger],
+            )?;
+        }
+        tx.commit()?;
+
+        Ok(report)
+    }
+}
+
+fn apply_event(tx: &Transaction, event: &DecodedEvent) -> Result<(), ProjectionError> {
+    match &event.event {
+        SytemapEvent::PropertyMinted(minted) => {
+            let property = &minted.property;
+            tx.execute(
+                "INSERT INTO properties (
+                    property_verification_no, token_id, plot_no, size_of_plot, coordinate_of_plot,
+                    estate_name, estate_company_name, date_of_allocation, nft_address, token_url,
+                    plot_url, owner, price, status, minted_at, minted_ledger, last_modified_ledger
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?16)",
+                params![
+                    property.property_verification_no,
+                    minted.token_id,
+                    property.plot_no,
+                    property.size_of_plot,
+                    property.coordinate_of_plot,
+                    property.estate_name,
+                    property.estate_company_name,
+                    property.date_of_allocation,
+                    property.nft_address,
+                    property.token_url,
+                    property.plot_url,
+                    property.buyer_wallet_id.0,
+                    property.price_of_plot,
+                    property.status.as_str(),
+                    property.timestamp,
+                    event.ledger,
+                ],
+            )?;
+            insert_price(
+                tx,
+                event,
+                property.property_verification_no,
+                None,
+                property.price_of_plot,
+            )?;
+            insert_transfer(
+                tx,
+                event,
+                property.property_verification_no,
+                None,
+                &property.buyer_wallet_id.0,
+            )?;
+            adjust_owner(tx, &property.buyer_wallet_id.0, 1, event.ledger)?;
+        }
+        SytemapEvent::PriceChanged(changed) => {
+            let pvn = touch_property(tx, event)?;
+            tx.execute(
+                "UPDATE properties SET price = ?2 WHERE property_verification_no = ?1",
+                params![pvn, changed.new_price],
+            )?;
+            insert_price(tx, event, pvn, Some(changed.old_price), changed.new_price)?;
+        }
+        SytemapEvent::OwnershipTransferred(transferred) => {
+            let pvn = touch_property(tx, event)?;
+            tx.execute(
+                "UPDATE properties SET owner = ?2 WHERE property_verification_no = ?1",
+                params![pvn, transferred.to.0],
+            )?;
+            insert_transfer(tx, event, pvn, Some(&transferred.from.0), &transferred.to.0)?;
+            adjust_owner(tx, &transferred.from.0, -1, event.ledger)?;
+            adjust_owner(tx, &transferred.to.0, 1, event.ledger)?;
+        }
+        SytemapEvent::StatusChanged(changed) => {
+            let pvn = touch_property(tx, event)?;
+            tx.execute(
+                "UPDATE properties SET status = ?2 WHERE property_verification_no = ?1",
+                params![pvn, changed.new_status.as_str()],
+            )?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Bumps `last_modified_ledger` of the property the event refers to and returns its number.
+fn touch_property(tx: &Transaction, event: &DecodedEvent) -> Result<u64, ProjectionError> {
+    let pvn = event
+        .property_verification_no
+        .ok_or_else(|| ProjectionError::MissingProperty {
+            id: event.id.clone(),
+        })?;
+    let updated = tx.execute(
+        "UPDATE properties SET last_modified_ledger = ?2 WHERE property_verification_no = ?1",
+        params![pvn, event.ledger],
+    )?;
+    if updated == 0 {
+        return Err(ProjectionError::UnknownProperty {
+            id: event.id.clone(),
+            property_verification_no: pvn,
+        });
+    }
+    Ok(pvn)
+}
+
+fn insert_price(
+    tx: &Transaction,
+    event: &DecodedEvent,
+    property_verification_no: u64,
+    old_price: Option<u64>,
+    new_price: u64,
+) -> Result<(), ProjectionError> {
+    tx.execute(
+        "INSERT INTO price_history (
+            event_id, property_verification_no, ledger, ledger_closed_at, old_price, new_price
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            event.id,
+            property_verification_no,
+            event.ledger,
+            event.ledger_closed_at,
+            old_price,
+            new_price,
+        ],
+    )?;
+    Ok(())
+}
+
+fn insert_transfer(
+    tx: &Transaction,
+    event: &DecodedEvent,
+    property_verification_no: u64,
+    from: Option<&str>,
+    to: &str,
+) -> Result<(), ProjectionError> {
+    tx.execute(
+        "INSERT INTO transfers (
+            event_id, property_verification_no, ledger, ledger_closed_at, tx_hash, from_address,
+            to_address
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            event.id,
+            property_verification_no,
+            event.ledger,
+            event.ledger_closed_at,
+            event.tx_hash,
+            from,
+            to,
+        ],
+    )?;
+    Ok(())
+}
+
+fn adjust_owner(
+    tx: &Transaction,
+    address: &str,
+    delta: i64,
+    ledger: u32,
+) -> Result<(), ProjectionError> {
+    tx.execute(
+        "INSERT INTO owners (address, property_count, first_seen_ledger, last_active_ledger)
+         VALUES (?1, ?2, ?3, ?3)
+         ON CONFLICT (address) DO UPDATE SET
+            property_count = property_count + excluded.property_count,
+            last_active_ledger = excluded.last_active_ledger",
+        params![address, delta, ledger],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+/// Tables of the projection. Every statement is idempotent so it can run on each open.
+pub const CREATE_TABLES: &str = "
+CREATE TABLE IF NOT EXISTS properties (
+    property_verification_no INTEGER PRIMARY KEY,
+    token_id INTEGER NOT NULL,
+    plot_no TEXT NOT NULL,
+    size_of_plot TEXT NOT NULL,
+    coordinate_of_plot TEXT NOT NULL,
+    estate_name TEXT NOT NULL,
+    estate_company_name TEXT NOT NULL,
+    date_of_allocation TEXT NOT NULL,
+    nft_address TEXT NOT NULL,
+    token_url TEXT NOT NULL,
+    plot_url TEXT NOT NULL,
+    owner TEXT NOT NULL,
+    price INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    minted_at INTEGER NOT NULL,
+    minted_ledger INTEGER NOT NULL,
+    last_modified_ledger INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS properties_owner ON properties (owner);
+CREATE INDEX IF NOT EXISTS properties_estate_name ON properties (estate_name);
+
+CREATE TABLE IF NOT EXISTS owners (
+    address TEXT PRIMARY KEY,
+    property_count INTEGER NOT NULL,
+    first_seen_ledger INTEGER NOT NULL,
+    last_active_ledger INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS price_history (
+    event_id TEXT PRIMARY KEY,
+    property_verification_no INTEGER NOT NULL,
+    ledger INTEGER NOT NULL,
+    ledger_closed_at TEXT NOT NULL,
+    old_price INTEGER,
+    new_price INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS price_history_property
+    ON price_history (property_verification_no, event_id);
+
+CREATE TABLE IF NOT EXISTS transfers (
+    event_id TEXT PRIMARY KEY,
+    property_verification_no INTEGER NOT NULL,
+    ledger INTEGER NOT NULL,
+    ledger_closed_at TEXT NOT NULL,
+    tx_hash TEXT,
+    from_address TEXT,
+    to_address TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS transfers_property
+    ON transfers (property_verification_no, event_id);
+
+CREATE TABLE IF NOT EXISTS cursor (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    event_id TEXT NOT NULL,
+    ledger INTEGER NOT NULL
+);
+";
+
+pub const DROP_TABLES: &str = "
+DROP TABLE IF EXISTS properties;
+DROP TABLE IF EXISTS owners;
+DROP TABLE IF EXISTS price_history;
+DROP TABLE IF EXISTS transfers;
+DROP TABLE IF EXISTS cursor;
+";
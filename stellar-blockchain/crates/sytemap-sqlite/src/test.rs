@@ -0,0 +1,182 @@
+#![cfg(test)]
+
+use rusqlite::Connection;
+use sytemap_indexer::{decode_events, parse_events, DecodedEvent};
+
+use crate::*;
+
+const PAGE_1: &str = include_str!("../../sytemap-indexer/fixtures/get_events_page_1.json");
+const PAGE_2: &str = include_str!("../../sytemap-indexer/fixtures/get_events_page_2.json");
+
+const OWNER_A: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4";
+const OWNER_C: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAITA4";
+const BUYER: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMDR4";
+
+fn decode_fixture(json: &str) -> Vec<DecodedEvent> {
+    let page = parse_events(json).unwrap();
+    decode_events(&page.events, None).unwrap()
+}
+
+/// Every row of every table, in a stable order.
+fn dump(conn: &Connection) -> Vec<String> {
+    let mut rows = Vec::new();
+    for (table, order_by) in [
+        ("properties", "property_verification_no"),
+        ("owners", "address"),
+        ("price_history", "event_id"),
+        ("transfers", "event_id"),
+        ("cursor", "id"),
+    ] {
+        let mut stmt = conn
+            .prepare(&format!("SELECT * FROM {table} ORDER BY {order_by}"))
+            .unwrap();
+        let columns = stmt.column_count();
+        let table_rows = stmt
+            .query_map([], |row| {
+                let values = (0..columns)
+                    .map(|i| {
+                        row.get::<_, rusqlite::types::Value>(i)
+                            .map(|v| format!("{v:?}"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("{table}: {}", values.join(", ")))
+            })
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        rows.extend(table_rows);
+    }
+    rows
+}
+
+#[test]
+fn test_projection_tables() {
+    let mut projection = Projection::open_in_memory().unwrap();
+    let report = projection
+        .project(
+            decode_fixture(PAGE_1)
+                .into_iter()
+                .chain(decode_fixture(PAGE_2)),
+        )
+        .unwrap();
+    assert_eq!(report.applied, 15);
+    assert_eq!(report.cursor.as_ref().unwrap().ledger, 500070);
+
+    let conn = projection.connection();
+    let (owner, price, status): (String, u64, String) = conn
+        .query_row(
+            "SELECT owner, price, status FROM properties WHERE property_verification_no = 12345",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        (owner.as_str(), price, status.as_str()),
+        (OWNER_C, 150_000, "Allocated")
+    );
+
+    let status: String = conn
+        .query_row(
+            "SELECT status FROM properties WHERE property_verification_no = 67890",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(status, "Encumbered");
+
+    let prices: Vec<(Option<u64>, u64)> = conn
+        .prepare(
+            "SELECT old_price, new_price FROM price_history
+             WHERE property_verification_no = 12345 ORDER BY event_id",
+        )
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(prices, [(None, 100_000), (Some(100_000), 150_000)]);
+
+    let transfers: Vec<(Option<String>, String)> = conn
+        .prepare(
+            "SELECT from_address, to_address FROM transfers
+             WHERE property_verification_no = 11111 ORDER BY event_id",
+        )
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        transfers,
+        [
+            (None, OWNER_A.to_string()),
+            (Some(OWNER_A.to_string()), BUYER.to_string())
+        ]
+    );
+
+    let owned_by_a: u32 = conn
+        .query_row(
+            "SELECT property_count FROM owners WHERE address = ?1",
+            [OWNER_A],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(owned_by_a, 0);
+}
+
+#[test]
+fn test_incremental_runs_match_full_rebuild() {
+    let mut incremental = Projection::open_in_memory().unwrap();
+    let first = incremental.project(decode_fixture(PAGE_1)).unwrap();
+    assert_eq!((first.applied, first.skipped), (5, 0));
+    assert_eq!(incremental.cursor().unwrap(), first.cursor);
+
+    // The second dump overlaps the first by one ledger
+    let second = incremental.project(decode_fixture(PAGE_2)).unwrap();
+    assert_eq!((second.applied, second.skipped), (10, 1));
+
+    // Nothing new on a repeated run
+    let third = incremental.project(decode_fixture(PAGE_2)).unwrap();
+    assert_eq!((third.applied, third.skipped), (0, 11));
+
+    let mut rebuilt = Projection::open_in_memory().unwrap();
+    rebuilt
+        .project(
+            decode_fixture(PAGE_2)
+                .into_iter()
+                .chain(decode_fixture(PAGE_1)),
+        )
+        .unwrap();
+
+    assert_eq!(dump(incremental.connection()), dump(rebuilt.connection()));
+}
+
+#[test]
+fn test_reset_clears_cursor() {
+    let mut projection = Projection::open_in_memory().unwrap();
+    projection.project(decode_fixture(PAGE_1)).unwrap();
+    let before = dump(projection.connection());
+
+    projection.reset().unwrap();
+    assert_eq!(projection.cursor().unwrap(), None);
+    assert!(dump(projection.connection()).is_empty());
+
+    let report = projection.project(decode_fixture(PAGE_1)).unwrap();
+    assert_eq!(report.applied, 5);
+    assert_eq!(dump(projection.connection()), before);
+}
+
+#[test]
+fn test_events_for_unknown_property_are_rejected() {
+    let mut projection = Projection::open_in_memory().unwrap();
+    let result = projection.project(decode_fixture(PAGE_2));
+    assert!(matches!(
+        result,
+        Err(ProjectionError::UnknownProperty {
+            property_verification_no: 12345,
+            ..
+        })
+    ));
+    // The failed run leaves nothing behind
+    assert_eq!(projection.cursor().unwrap(), None);
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::contracterror;
+
+/// Errors returned by the registry contract, numbered as they appear on chain
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum SytemapRegistryError {
+    AlreadyMinted = 1,
+    PropertyAlreadyExists = 2,
+    InvalidPrice = 3,
+    PropertyNotFound = 4,
+    NftAddressAlreadyInUse = 5,
+    AlreadyInitialized = 6,
+    PaymentPlanAlreadyExists = 7,
+    PaymentPlanNotFound = 8,
+    InvalidPaymentPlan = 9,
+    InvalidPaymentAmount = 10,
+    PropertyNotTransferable = 11,
+    PaymentNotOverdue = 12,
+    PropertyNotInDefault = 13,
+    NoticePeriodNotElapsed = 14,
+    PropertyEncumbered = 15,
+    LienAlreadyExists = 16,
+    LienNotFound = 17,
+    InvalidLien = 18,
+    PropertyLocked = 19,
+    PropertyNotLocked = 20,
+    Unauthorized = 21,
+    PropertyFrozen = 22,
+    PropertyNotFrozen = 23,
+    MintApprovalRequired = 24,
+    MintProposalNotFound = 25,
+    MintProposalExpired = 26,
+    MintProposalAlreadyExists = 27,
+    AlreadyApproved = 28,
+    InvalidStateTransition = 29,
+    RecipientNotVerified = 30,
+    ContractPaused = 31,
+    InvalidPageLimit = 32,
+    PlotNoAlreadyInUse = 33,
+    InvalidSubdivision = 34,
+    InvalidMerge = 35,
+    LeaseAlreadyExists = 36,
+    LeaseNotFound = 37,
+    InvalidLease = 38,
+    TooManyPendingMints = 39,
+    TooManyDocuments = 40,
+    InvalidMintProposalTtl = 41,
+}
@@ -0,0 +1,31 @@
+//! Types shared by the `sytemap` registry contract and the services built around it.
+//!
+//! The crate is `no_std` so the contract can build it for Wasm. The `std` feature adds
+//! [`mirror`], plain copies of the contract structs that convert to and from the `ScVal`s
+//! the contract takes and returns without an `Env`, and `serde` makes them (de)serializable:
+//!
+//! ```ignore
+//! let payload: sytemap_types::mirror::PropertyInfoPayload = serde_json::from_str(&json)?;
+//! let arg = stellar_xdr::curr::ScVal::try_from(&payload)?;
+//! ```
+//!
+//! Services declare mirrors of further contract types, such as event payloads, with
+//! [`mirror_struct!`] and [`mirror_enum!`] so they decode the same way.
+#![no_std]
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod error;
+#[cfg(feature = "std")]
+pub mod mirror;
+#[cfg(feature = "std")]
+pub mod scval;
+pub mod types;
+
+pub use error::SytemapRegistryError;
+#[cfg(feature = "std")]
+pub use scval::{ScValError, ScValField};
+pub use types::{PropertyInfo, PropertyInfoPayload, PropertyStatus, TokenMetadata};
+
+mod test;
@@ -0,0 +1,262 @@
+//! Plain Rust copies of the contract types for off-chain services.
+//!
+//! Each mirror converts to and from the `ScVal` the contract encodes its counterpart as, so
+//! values can be built for invocations or decoded from return values and events without an
+//! `Env`. With the `serde` feature they also (de)serialize, field names unchanged.
+
+use std::{
+    fmt,
+    str::FromStr,
+    string::{String, ToString},
+};
+
+use stellar_xdr::curr::{ScAddress, ScVal};
+
+use crate::scval::{unexpected, ScValError, ScValField};
+
+/// A Stellar address in its strkey form (`G...` for accounts, `C...` for contracts).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Address(pub String);
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ScValField for Address {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::Address(address) => Ok(Address(address.to_string())),
+            other => Err(unexpected("Address", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        ScAddress::from_str(&self.0)
+            .map(ScVal::Address)
+            .map_err(|_| ScValError::InvalidAddress(self.0.clone()))
+    }
+}
+
+/// Declares a mirror of a `#[contracttype]` struct and its conversions to and from `ScVal`.
+/// Extra derives, such as serde's, are passed as attributes.
+#[macro_export]
+macro_rules! mirror_struct {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* pub $field:ident: $ty:ty,)*
+        }
+    ) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        $(#[$meta])*
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl TryFrom<&::stellar_xdr::curr::ScVal> for $name {
+            type Error = $crate::ScValError;
+
+            fn try_from(val: &::stellar_xdr::curr::ScVal) -> Result<Self, $crate::ScValError> {
+                let map = $crate::scval::expect_map(val)?;
+                Ok(Self {
+                    $($field: $crate::ScValField::from_sc_val(
+                        $crate::scval::field(map, stringify!($field))?,
+                    )?,)*
+                })
+            }
+        }
+
+        impl TryFrom<&$name> for ::stellar_xdr::curr::ScVal {
+            type Error = $crate::ScValError;
+
+            fn try_from(val: &$name) -> Result<Self, $crate::ScValError> {
+                $crate::scval::struct_to_sc_val(::std::vec![
+                    $((stringify!($field), $crate::ScValField::to_sc_val(&val.$field)?),)*
+                ])
+            }
+        }
+
+        impl $crate::ScValField for $name {
+            fn from_sc_val(val: &::stellar_xdr::curr::ScVal) -> Result<Self, $crate::ScValError> {
+                Self::try_from(val)
+            }
+
+            fn to_sc_val(&self) -> Result<::stellar_xdr::curr::ScVal, $crate::ScValError> {
+                ::stellar_xdr::curr::ScVal::try_from(self)
+            }
+        }
+    };
+}
+
+/// Declares a mirror of a `#[contracttype]` enum whose variants carry no data.
+/// Extra derives, such as serde's, are passed as attributes.
+#[macro_export]
+macro_rules! mirror_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident,)*
+        }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $(#[$meta])*
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl TryFrom<&::stellar_xdr::curr::ScVal> for $name {
+            type Error = $crate::ScValError;
+
+            fn try_from(val: &::stellar_xdr::curr::ScVal) -> Result<Self, $crate::ScValError> {
+                match $crate::scval::expect_unit_variant(val)?.as_str() {
+                    $(stringify!($variant) => Ok($name::$variant),)*
+                    other => Err($crate::ScValError::UnknownVariant {
+                        ty: stringify!($name),
+                        variant: ::std::string::ToString::to_string(other),
+                    }),
+                }
+            }
+        }
+
+        impl TryFrom<&$name> for ::stellar_xdr::curr::ScVal {
+            type Error = $crate::ScValError;
+
+            fn try_from(val: &$name) -> Result<Self, $crate::ScValError> {
+                $crate::scval::unit_variant(val.as_str())
+            }
+        }
+
+        impl $crate::ScValField for $name {
+            fn from_sc_val(val: &::stellar_xdr::curr::ScVal) -> Result<Self, $crate::ScValError> {
+                Self::try_from(val)
+            }
+
+            fn to_sc_val(&self) -> Result<::stellar_xdr::curr::ScVal, $crate::ScValError> {
+                ::stellar_xdr::curr::ScVal::try_from(self)
+            }
+        }
+    };
+}
+
+mirror_struct! {
+    /// Mirror of [`crate::TokenMetadata`]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct TokenMetadata {
+        pub sytemap_name: String,
+        pub sytemap_symbol: String,
+        pub metadata_uri: String,
+    }
+}
+
+mirror_enum! {
+    /// Mirror of [`crate::PropertyStatus`]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum PropertyStatus {
+        Draft,
+        PendingSurvey,
+        Verified,
+        Allocated,
+        Encumbered,
+        InPayment,
+        InDefault,
+        Frozen,
+        Revoked,
+        Subdivided,
+        Merged,
+    }
+}
+
+mirror_struct! {
+    /// Mirror of [`crate::PropertyInfo`]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct PropertyInfo {
+        pub price_of_plot: u64,
+        pub property_verification_no: u64,
+        pub timestamp: u64,
+        pub buyer_wallet_id: Address,
+        pub plot_no: String,
+        pub size_of_plot: String,
+        pub coordinate_of_plot: String,
+        pub token_url: String,
+        pub estate_name: String,
+        pub plot_url: String,
+        pub date_of_allocation: String,
+        pub estate_company_name: String,
+        pub nft_address: String,
+        pub status: PropertyStatus,
+    }
+}
+
+mirror_struct! {
+    /// Mirror of [`crate::PropertyInfoPayload`]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct PropertyInfoPayload {
+        pub price_of_plot: u64,
+        pub property_verification_no: u64,
+        pub buyer_wallet_id: Address,
+        pub plot_no: String,
+        pub size_of_plot: String,
+        pub coordinate_of_plot: String,
+        pub token_url: String,
+        pub estate_name: String,
+        pub plot_url: String,
+        pub date_of_allocation: String,
+        pub estate_company_name: String,
+        pub nft_address: String,
+    }
+}
+
+mirror_enum! {
+    /// Mirror of the contract's `DocumentType`
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum DocumentType {
+        SurveyPlan,
+        DeedOfAssignment,
+        CertificateOfOccupancy,
+        GovernorsConsent,
+        Receipt,
+    }
+}
+
+mirror_enum! {
+    /// Mirror of the contract's `Role`
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Role {
+        Arbitrator,
+        Verifier,
+        EstateCompany,
+        Compliance,
+        Pauser,
+    }
+}
+
+mirror_enum! {
+    /// Mirror of the contract's `IndexIssue`
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum IndexIssue {
+        DuplicateListing,
+        NotListed,
+        MissingPropertyInfo,
+        MissingTokenId,
+        MissingNftAddress,
+        NftAddressMismatch,
+        MissingNftAddressPvn,
+        NftAddressPvnMismatch,
+        MissingEstatePlot,
+        NotListedInEstate,
+        NotListedForEstateCompany,
+        NotListedForOwner,
+    }
+}
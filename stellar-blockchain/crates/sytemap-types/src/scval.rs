@@ -0,0 +1,229 @@
+//! Conversions between plain Rust values and the `ScVal`s the contract encodes them as,
+//! shared by the mirrors in this crate and by the off-chain services that declare their
+//! own with [`mirror_struct!`](crate::mirror_struct) and [`mirror_enum!`](crate::mirror_enum).
+
+use std::{string::String, vec::Vec};
+
+use soroban_sdk::Error;
+use stellar_xdr::curr::{
+    Int128Parts, ScBytes, ScError, ScMap, ScMapEntry, ScString, ScSymbol, ScVal, ScVec,
+};
+use thiserror::Error;
+
+use crate::error::SytemapRegistryError;
+
+/// Errors raised while converting between mirror types and contract `ScVal`s.
+#[derive(Debug, Error)]
+pub enum ScValError {
+    #[error("expected {expected}, found {found}")]
+    UnexpectedType {
+        expected: &'static str,
+        found: &'static str,
+    },
+    #[error("missing field `{0}`")]
+    MissingField(&'static str),
+    #[error("unknown {ty} variant `{variant}`")]
+    UnknownVariant { ty: &'static str, variant: String },
+    #[error("unknown registry error code {0}")]
+    UnknownErrorCode(u32),
+    #[error("`{0}` is not a valid Stellar address")]
+    InvalidAddress(String),
+    #[error("invalid UTF-8 in string value")]
+    InvalidUtf8,
+    #[error("value does not fit in XDR: {0}")]
+    Xdr(#[from] stellar_xdr::curr::Error),
+}
+
+/// A struct field that converts to and from its contract `ScVal`.
+pub trait ScValField: Sized {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError>;
+    fn to_sc_val(&self) -> Result<ScVal, ScValError>;
+}
+
+pub fn unexpected(expected: &'static str, found: &ScVal) -> ScValError {
+    ScValError::UnexpectedType {
+        expected,
+        found: found.name(),
+    }
+}
+
+/// The entries of a `#[contracttype]` struct.
+pub fn expect_map(val: &ScVal) -> Result<&ScMap, ScValError> {
+    match val {
+        ScVal::Map(Some(map)) => Ok(map),
+        other => Err(unexpected("Map", other)),
+    }
+}
+
+/// Looks up a struct field by name.
+pub fn field<'a>(map: &'a ScMap, name: &'static str) -> Result<&'a ScVal, ScValError> {
+    map.iter()
+        .find(|entry| {
+            matches!(&entry.key, ScVal::Symbol(symbol) if symbol.as_slice() == name.as_bytes())
+        })
+        .map(|entry| &entry.val)
+        .ok_or(ScValError::MissingField(name))
+}
+
+/// Encodes a struct the way `#[contracttype]` does: a map keyed by field name, sorted.
+pub fn struct_to_sc_val(fields: Vec<(&'static str, ScVal)>) -> Result<ScVal, ScValError> {
+    let entries = fields
+        .into_iter()
+        .map(|(name, val)| {
+            Ok(ScMapEntry {
+                key: ScVal::Symbol(ScSymbol(name.try_into()?)),
+                val,
+            })
+        })
+        .collect::<Result<Vec<_>, ScValError>>()?;
+    Ok(ScVal::Map(Some(ScMap::sorted_from(entries)?)))
+}
+
+/// The variant name of a `#[contracttype]` enum without payload, encoded as `[Symbol]`.
+pub fn expect_unit_variant(val: &ScVal) -> Result<String, ScValError> {
+    match val {
+        ScVal::Vec(Some(items)) if items.len() == 1 => symbol_to_string(&items[0]),
+        other => Err(unexpected("Vec[Symbol]", other)),
+    }
+}
+
+pub fn symbol_to_string(val: &ScVal) -> Result<String, ScValError> {
+    match val {
+        ScVal::Symbol(symbol) => symbol.to_utf8_string().map_err(|_| ScValError::InvalidUtf8),
+        other => Err(unexpected("Symbol", other)),
+    }
+}
+
+pub fn unit_variant(name: &'static str) -> Result<ScVal, ScValError> {
+    let symbol = ScVal::Symbol(ScSymbol(name.try_into()?));
+    Ok(ScVal::Vec(Some(ScVec(std::vec![symbol].try_into()?))))
+}
+
+impl ScValField for u32 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::U32(value) => Ok(*value),
+            other => Err(unexpected("U32", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        Ok(ScVal::U32(*self))
+    }
+}
+
+impl ScValField for u64 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::U64(value) => Ok(*value),
+            other => Err(unexpected("U64", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        Ok(ScVal::U64(*self))
+    }
+}
+
+impl ScValField for i128 {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+            other => Err(unexpected("I128", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        Ok(ScVal::I128(Int128Parts {
+            hi: (*self >> 64) as i64,
+            lo: *self as u64,
+        }))
+    }
+}
+
+/// Soroban `String` values.
+impl ScValField for String {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::String(value) => value.to_utf8_string().map_err(|_| ScValError::InvalidUtf8),
+            other => Err(unexpected("String", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        Ok(ScVal::String(ScString(self.as_str().try_into()?)))
+    }
+}
+
+/// `BytesN<32>` values such as document hashes.
+impl ScValField for [u8; 32] {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::Bytes(bytes) => bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| unexpected("BytesN<32>", val)),
+            other => Err(unexpected("Bytes", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        Ok(ScVal::Bytes(ScBytes(self.try_into()?)))
+    }
+}
+
+/// `Option`s, with `None` encoded as `Void`.
+impl<T: ScValField> ScValField for Option<T> {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::Void => Ok(None),
+            other => T::from_sc_val(other).map(Some),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        match self {
+            Some(value) => value.to_sc_val(),
+            None => Ok(ScVal::Void),
+        }
+    }
+}
+
+/// Soroban `Vec`s.
+impl<T: ScValField> ScValField for Vec<T> {
+    fn from_sc_val(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::Vec(Some(items)) => items.iter().map(T::from_sc_val).collect(),
+            other => Err(unexpected("Vec", other)),
+        }
+    }
+
+    fn to_sc_val(&self) -> Result<ScVal, ScValError> {
+        let items = self
+            .iter()
+            .map(ScValField::to_sc_val)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ScVal::Vec(Some(ScVec(items.try_into()?))))
+    }
+}
+
+/// Contract errors travel as `Error(Contract, code)`, the way a failed invocation reports them.
+impl TryFrom<&ScVal> for SytemapRegistryError {
+    type Error = ScValError;
+
+    fn try_from(val: &ScVal) -> Result<Self, ScValError> {
+        match val {
+            ScVal::Error(ScError::Contract(code)) => {
+                SytemapRegistryError::try_from(Error::from_contract_error(*code))
+                    .map_err(|_| ScValError::UnknownErrorCode(*code))
+            }
+            other => Err(unexpected("Error(Contract)", other)),
+        }
+    }
+}
+
+impl From<SytemapRegistryError> for ScVal {
+    fn from(err: SytemapRegistryError) -> Self {
+        ScVal::Error(ScError::Contract(err as u32))
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(all(test, feature = "serde"))]
+
+use std::{string::ToString, vec};
+
+use soroban_sdk::{Env, String, TryFromVal};
+use stellar_xdr::curr::{ScError, ScVal};
+
+use crate::{mirror, PropertyInfo, PropertyInfoPayload, PropertyStatus, SytemapRegistryError};
+
+const OWNER: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFCT4";
+
+fn mirror_payload() -> mirror::PropertyInfoPayload {
+    mirror::PropertyInfoPayload {
+        price_of_plot: 1_200_000,
+        property_verification_no: 12345,
+        buyer_wallet_id: mirror::Address(OWNER.to_string()),
+        plot_no: "A-12".to_string(),
+        size_of_plot: "600sqm".to_string(),
+        coordinate_of_plot: "6.4281,3.4219".to_string(),
+        token_url: "https://sytemap.example/tokens/12345".to_string(),
+        estate_name: "Palm Estate".to_string(),
+        plot_url: "https://sytemap.example/plots/12345".to_string(),
+        date_of_allocation: "2024-01-15".to_string(),
+        estate_company_name: "Palm Homes Ltd".to_string(),
+        nft_address: "nft-12345".to_string(),
+    }
+}
+
+fn contract_property(env: &Env) -> PropertyInfo {
+    let string = |value: &str| String::from_str(env, value);
+    PropertyInfo {
+        price_of_plot: 1_200_000,
+        property_verification_no: 12345,
+        timestamp: 1_700_000_000,
+        buyer_wallet_id: soroban_sdk::Address::from_string(&string(OWNER)),
+        plot_no: string("A-12"),
+        size_of_plot: string("600sqm"),
+        coordinate_of_plot: string("6.4281,3.4219"),
+        token_url: string("https://sytemap.example/tokens/12345"),
+        estate_name: string("Palm Estate"),
+        plot_url: string("https://sytemap.example/plots/12345"),
+        date_of_allocation: string("2024-01-15"),
+        estate_company_name: string("Palm Homes Ltd"),
+        nft_address: string("nft-12345"),
+        status: PropertyStatus::Encumbered,
+    }
+}
+
+#[test]
+fn test_payload_json_round_trip() {
+    let payload = mirror_payload();
+    let json = serde_json::to_value(&payload).unwrap();
+    assert_eq!(json["buyer_wallet_id"], OWNER);
+    assert_eq!(json["property_verification_no"], 12345);
+
+    let decoded: mirror::PropertyInfoPayload = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, payload);
+}
+
+#[test]
+fn test_property_json_round_trip() {
+    let env = Env::default();
+    let property =
+        mirror::PropertyInfo::try_from(&ScVal::try_from(&contract_property(&env)).unwrap())
+            .unwrap();
+    let json = serde_json::to_string(&property).unwrap();
+    assert!(json.contains(r#""status":"Encumbered""#));
+
+    let decoded: mirror::PropertyInfo = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, property);
+}
+
+#[test]
+fn test_mirror_matches_contract_encoding() {
+    let env = Env::default();
+    let contract = contract_property(&env);
+    let encoded = ScVal::try_from(&contract).unwrap();
+
+    let property = mirror::PropertyInfo::try_from(&encoded).unwrap();
+    assert_eq!(property.buyer_wallet_id.to_string(), OWNER);
+    assert_eq!(property.estate_name, "Palm Estate");
+    assert_eq!(property.status, mirror::PropertyStatus::Encumbered);
+    assert_eq!(ScVal::try_from(&property).unwrap(), encoded);
+
+    let payload = ScVal::try_from(&mirror_payload()).unwrap();
+    let decoded = PropertyInfoPayload::try_from_val(&env, &payload).unwrap();
+    assert_eq!(decoded.buyer_wallet_id, contract.buyer_wallet_id);
+    assert_eq!(decoded.coordinate_of_plot, contract.coordinate_of_plot);
+    assert_eq!(ScVal::try_from(&decoded).unwrap(), payload);
+}
+
+#[test]
+fn test_mirror_rejects_malformed_values() {
+    let mut payload = mirror_payload();
+    payload.buyer_wallet_id = mirror::Address("not-a-wallet".to_string());
+    assert!(matches!(
+        ScVal::try_from(&payload),
+        Err(crate::ScValError::InvalidAddress(value)) if value == "not-a-wallet"
+    ));
+
+    let status = ScVal::try_from(&mirror::PropertyStatus::Frozen).unwrap();
+    assert!(matches!(
+        mirror::PropertyInfoPayload::try_from(&status),
+        Err(crate::ScValError::UnexpectedType {
+            expected: "Map",
+            ..
+        })
+    ));
+
+    let unknown = ScVal::try_from(&mirror::TokenMetadata {
+        sytemap_name: "Sytemap".to_string(),
+        sytemap_symbol: "STM".to_string(),
+        metadata_uri: "ipfs://metadata".to_string(),
+    })
+    .unwrap();
+    assert!(matches!(
+        mirror::PropertyStatus::try_from(&unknown),
+        Err(crate::ScValError::UnexpectedType { .. })
+    ));
+}
+
+#[test]
+fn test_error_round_trip() {
+    let err = SytemapRegistryError::ContractPaused;
+    let encoded = ScVal::from(err);
+    assert_eq!(encoded, ScVal::Error(ScError::Contract(31)));
+    assert_eq!(SytemapRegistryError::try_from(&encoded).unwrap(), err);
+    assert!(matches!(
+        SytemapRegistryError::try_from(&ScVal::Error(ScError::Contract(999))),
+        Err(crate::ScValError::UnknownErrorCode(999))
+    ));
+
+    let json = serde_json::to_string(&vec![err, SytemapRegistryError::AlreadyMinted]).unwrap();
+    assert_eq!(json, r#"["ContractPaused","AlreadyMinted"]"#);
+    let decoded: std::vec::Vec<SytemapRegistryError> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, vec![err, SytemapRegistryError::AlreadyMinted]);
+}
+
+#[test]
+fn test_scval_fields_match_contract_encoding() {
+    use soroban_sdk::{BytesN, IntoVal, Val};
+
+    use crate::ScValField;
+
+    let env = Env::default();
+    let encode = |val: Val| ScVal::try_from_val(&env, &val).unwrap();
+
+    let amount: i128 = -1_200_000_000_000_000_000_000;
+    assert_eq!(amount.to_sc_val().unwrap(), encode(amount.into_val(&env)));
+    assert_eq!(
+        i128::from_sc_val(&encode(amount.into_val(&env))).unwrap(),
+        amount
+    );
+
+    let hash = [7u8; 32];
+    let contract_hash = encode(BytesN::from_array(&env, &hash).into_val(&env));
+    assert_eq!(hash.to_sc_val().unwrap(), contract_hash);
+    assert_eq!(<[u8; 32]>::from_sc_val(&contract_hash).unwrap(), hash);
+
+    let pvns = soroban_sdk::vec![&env, 1u64, 2, 3];
+    let contract_pvns = encode(pvns.into_val(&env));
+    assert_eq!(vec![1u64, 2, 3].to_sc_val().unwrap(), contract_pvns);
+    assert_eq!(
+        <std::vec::Vec<u64>>::from_sc_val(&contract_pvns).unwrap(),
+        vec![1, 2, 3]
+    );
+
+    let none: Option<u32> = None;
+    assert_eq!(none.to_sc_val().unwrap(), encode(none.into_val(&env)));
+    assert_eq!(Option::<u32>::from_sc_val(&ScVal::U32(5)).unwrap(), Some(5));
+    assert!(matches!(
+        <[u8; 32]>::from_sc_val(&ScVal::U32(5)),
+        Err(crate::ScValError::UnexpectedType {
+            expected: "Bytes",
+            ..
+        })
+    ));
+}
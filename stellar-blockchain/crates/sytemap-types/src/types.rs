@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, String};
+
+/// Object for storing token meta data
+#[contracttype]
+pub struct TokenMetadata {
+    pub sytemap_name: String,
+    pub sytemap_symbol: String,
+
+    /// The Asset Metadata is a URI where the metadata is hosted, this returns an String
+    pub metadata_uri: String,
+}
+
+/// Object for storing property info data
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PropertyInfo {
+    pub price_of_plot: u64,
+    pub property_verification_no: u64,
+    pub timestamp: u64,
+    pub buyer_wallet_id: Address,
+    pub plot_no: String,
+    pub size_of_plot: String,
+    pub coordinate_of_plot: String,
+    pub token_url: String,
+    pub estate_name: String,
+    pub plot_url: String,
+    pub date_of_allocation: String,
+    pub estate_company_name: String,
+    pub nft_address: String,
+    pub status: PropertyStatus,
+}
+
+/// Object for storing property info data
+#[derive(Debug, Clone)]
+#[contracttype]
+pub struct PropertyInfoPayload {
+    pub price_of_plot: u64,
+    pub property_verification_no: u64,
+    pub buyer_wallet_id: Address,
+    pub plot_no: String,
+    pub size_of_plot: String,
+    pub coordinate_of_plot: String,
+    pub token_url: String,
+    pub estate_name: String,
+    pub plot_url: String,
+    pub date_of_allocation: String,
+    pub estate_company_name: String,
+    pub nft_address: String,
+}
+
+/// Lifecycle state of a property in the registry
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[contracttype]
+pub enum PropertyStatus {
+    /// Registered but not yet sent for survey, or sent back after a failed survey
+    Draft,
+    /// Waiting for a verifier to confirm the survey
+    PendingSurvey,
+    /// Survey confirmed, waiting to be allocated
+    Verified,
+    /// Owned by `buyer_wallet_id` and free to move
+    Allocated,
+    /// Allocated but carrying at least one lien
+    Encumbered,
+    /// Sold on an installment plan; the title stays locked until it is fully paid
+    InPayment,
+    /// The buyer is past the grace period of an installment plan and the plot can be repossessed
+    InDefault,
+    /// The title is disputed and nothing may happen to the plot until an arbitrator lifts the freeze
+    Frozen,
+    /// The title has been cancelled for good
+    Revoked,
+    /// The plot has been split into child plots, which carry the title from now on
+    Subdivided,
+    /// The plot has been merged with its neighbours into a plot that carries the title from now on
+    Merged,
+}